@@ -0,0 +1,112 @@
+//! Measures the latency of each keystroke typed in the filter input of list
+//! prompts, for lists of 10k, 100k and 1M options.
+//!
+//! Run with `cargo bench --bench filter`, adding `--features parallel` to also
+//! measure the rayon-backed scorer.
+
+use std::time::{Duration, Instant};
+
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/prompts/scoring.rs"]
+mod scoring;
+
+use scoring::ScoreCache;
+
+const SIZES: [usize; 3] = [10_000, 100_000, 1_000_000];
+const QUERY: &str = "kelomi";
+const RUNS: usize = 3;
+
+const SYLLABLES: [&str; 16] = [
+    "ka", "lo", "mi", "ne", "ru", "sa", "te", "vo", "bi", "de", "fu", "go", "hi", "jo", "pa", "ze",
+];
+
+fn generate_options(len: usize) -> Vec<String> {
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    (0..len)
+        .map(|i| {
+            let words = (0..3)
+                .map(|_| {
+                    (0..3)
+                        .map(|_| SYLLABLES[(next() % SYLLABLES.len() as u64) as usize])
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>();
+
+            format!("{} #{}", words.join(" "), i)
+        })
+        .collect()
+}
+
+/// Every filter input the user goes through while typing the query and then
+/// deleting it back, one character at a time.
+fn keystrokes() -> Vec<&'static str> {
+    let typing = (0..=QUERY.len()).map(|i| &QUERY[..i]);
+    let deleting = (0..QUERY.len()).rev().map(|i| &QUERY[..i]);
+
+    typing.chain(deleting).collect()
+}
+
+fn measure<F>(label: &str, len: usize, mut keystroke: F)
+where
+    F: FnMut(&mut ScoreCache, &str) -> usize,
+{
+    let keystrokes = keystrokes();
+    let mut worst = Duration::ZERO;
+    let mut total = Duration::ZERO;
+    let mut matches = 0;
+
+    for _ in 0..RUNS {
+        let mut cache = ScoreCache::new(true);
+
+        for query in &keystrokes {
+            let start = Instant::now();
+            matches = keystroke(&mut cache, query);
+            let elapsed = start.elapsed();
+
+            worst = worst.max(elapsed);
+            total += elapsed;
+        }
+    }
+
+    let mean = total / (RUNS * keystrokes.len()) as u32;
+
+    println!(
+        "{:>9} options | {:<12} | mean {:>12.3?} | worst {:>12.3?} | {} final matches",
+        len, label, mean, worst, matches
+    );
+}
+
+fn main() {
+    let matcher = SkimMatcherV2::default().ignore_case();
+
+    for len in SIZES {
+        let options = generate_options(len);
+        let score = |query: &str, idx: usize| matcher.fuzzy_match(&options[idx], query);
+
+        measure("full rescore", len, |_, query| {
+            ScoreCache::new(true)
+                .score(query, len, |idx| score(query, idx))
+                .len()
+        });
+
+        measure("incremental", len, |cache, query| {
+            cache.score(query, len, |idx| score(query, idx)).len()
+        });
+
+        #[cfg(feature = "parallel")]
+        measure("parallel", len, |cache, query| {
+            cache
+                .score_parallel(query, len, |idx| score(query, idx))
+                .len()
+        });
+    }
+}
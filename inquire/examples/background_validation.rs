@@ -0,0 +1,24 @@
+use std::{thread, time::Duration};
+
+use inquire::{required, validator::Validation, Text};
+
+const TAKEN_NAMES: [&str; 3] = ["admin", "root", "ferris"];
+
+fn main() {
+    let username = Text::new("Username:")
+        .with_help_message("Try admin, root or ferris")
+        .with_validator(required!())
+        .with_background_validator(|name: &str| {
+            // Stands for a slow query to a local database or development server.
+            thread::sleep(Duration::from_secs(1));
+
+            match TAKEN_NAMES.contains(&name) {
+                true => Ok(Validation::Invalid("This name is already taken".into())),
+                false => Ok(Validation::Valid),
+            }
+        })
+        .prompt()
+        .unwrap();
+
+    println!("Welcome, {username}!");
+}
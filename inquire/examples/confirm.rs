@@ -1,4 +1,4 @@
-use inquire::{ui::RenderConfig, Confirm};
+use inquire::{ui::RenderConfig, Confirm, TriState};
 
 fn main() {
     let ans = Confirm::new("Do you live in Brazil?")
@@ -30,7 +30,9 @@ fn main() {
             "não" => Ok(false),
             _ => Err(()),
         },
+        rich_parser: None,
         error_message: "Reply with 'sim' or 'não'".into(),
+        instant: false,
         default_value_formatter: &|def| match def {
             true => String::from("sim"),
             false => String::from("não"),
@@ -41,4 +43,28 @@ fn main() {
     .unwrap();
 
     println!("Your answer: {ans}");
+
+    let ans = Confirm::new("Install the recommended extensions?")
+        .with_default(true)
+        .with_instant(true)
+        .prompt()
+        .unwrap();
+
+    println!("Your answer: {ans}");
+
+    for file in ["README.md", "Cargo.toml", "src/main.rs"] {
+        let message = format!("Overwrite {file}?");
+        match Confirm::new(&message).prompt_tristate().unwrap() {
+            TriState::Yes => println!("Overwriting {file}"),
+            TriState::No => println!("Skipping {file}"),
+            TriState::All => {
+                println!("Overwriting all remaining files");
+                break;
+            }
+            TriState::Cancel => {
+                println!("Leaving the remaining files untouched");
+                break;
+            }
+        }
+    }
 }
@@ -1,5 +1,10 @@
-use chrono::{NaiveDate, Weekday};
-use inquire::{formatter::DEFAULT_DATE_FORMATTER, validator::Validation, CustomType, DateSelect};
+use chrono::{Datelike, NaiveDate, Weekday};
+use inquire::{
+    formatter::DEFAULT_DATE_FORMATTER,
+    ui::{Color, StyleSheet},
+    validator::Validation,
+    CalendarLocale, CustomType, DateSelect,
+};
 
 fn main() {
     date_select_default();
@@ -7,6 +12,10 @@ fn main() {
     date_select_misc_options();
     date_select_with_validation();
     date_select_with_starting_date();
+    date_select_range();
+    date_select_with_disabled_dates();
+    date_select_localized();
+    date_select_multiple();
 }
 
 fn date_select_default() {
@@ -92,3 +101,103 @@ fn date_select_with_starting_date() {
     println!("We will be expecting you!");
     println!();
 }
+
+fn date_select_range() {
+    println!("-------> DateSelect in range mode");
+    println!();
+
+    let stay = DateSelect::new("When are you staying with us?")
+        .with_help_message("enter to pick check-in, then enter again to pick check-out")
+        .with_max_range_span(14)
+        .with_range_validator(|check_in: NaiveDate, check_out: NaiveDate| {
+            if check_in == check_out {
+                Ok(Validation::Invalid(
+                    "Stays must be at least one night long".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_range();
+
+    match stay {
+        Ok((check_in, check_out)) => println!(
+            "Booked {} nights. We will be expecting you!",
+            (check_out - check_in).num_days()
+        ),
+        Err(_) => println!("There was an error in the system."),
+    }
+    println!();
+}
+
+fn date_select_with_disabled_dates() {
+    println!("-------> DateSelect with disabled weekends and highlighted bookings");
+    println!();
+
+    let today = chrono::Local::now().date_naive();
+    let booked = [
+        today + chrono::Duration::days(3),
+        today + chrono::Duration::days(8),
+    ];
+
+    let is_weekend = |d: NaiveDate| matches!(d.weekday(), Weekday::Sat | Weekday::Sun);
+    let booking_style = |d: NaiveDate| {
+        if booked.contains(&d) {
+            Some(StyleSheet::new().with_fg(Color::LightRed))
+        } else {
+            None
+        }
+    };
+
+    let date = DateSelect::new("When should we visit?")
+        .with_disabled_dates(&is_weekend)
+        .with_disabled_date_error_message("We are closed on weekends")
+        .with_date_styler(&booking_style)
+        .with_help_message("dates in red already have visits booked")
+        .prompt();
+
+    match date {
+        Ok(_) => println!("Your visit was booked!"),
+        Err(_) => println!("There was an error in the system."),
+    }
+    println!();
+}
+
+fn date_select_localized() {
+    println!("-------> DateSelect in German, with week numbers");
+    println!();
+
+    let date = DateSelect::new("Wann möchten Sie reisen?")
+        .with_locale(CalendarLocale::GERMAN)
+        .with_week_numbers(true)
+        .with_help_message("Pfeiltasten zum Bewegen, Enter zum Auswählen")
+        .prompt()
+        .unwrap();
+
+    println!("Reisedatum: KW {}, {}", date.iso_week().week(), date);
+    println!();
+}
+
+fn date_select_multiple() {
+    println!("-------> DateSelect with multiple dates");
+    println!();
+
+    let shifts = DateSelect::new("Which days can you be on call?")
+        .with_min_selections(2)
+        .with_max_selections(5)
+        .with_disabled_dates(&|d| matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .with_multi_date_validator(|dates: &[NaiveDate]| {
+            if dates.windows(2).all(|w| w[0].month() == w[1].month()) {
+                Ok(Validation::Valid)
+            } else {
+                Ok(Validation::Invalid(
+                    "All shifts must be in the same month".into(),
+                ))
+            }
+        })
+        .prompt_multiple()
+        .unwrap();
+
+    println!("You are on call for {} days", shifts.len());
+    println!();
+}
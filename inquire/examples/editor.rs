@@ -1,6 +1,7 @@
 use inquire::{
     error::InquireResult,
     ui::{Color, RenderConfig, Styled},
+    validator::Validation,
     Editor, Text,
 };
 
@@ -20,6 +21,16 @@ fn main() -> InquireResult<()> {
                 substr
             }
         })
+        .with_validator(|description: &str| {
+            if description.contains("TODO") {
+                Ok(Validation::Invalid(
+                    "The description still has TODOs to fill in".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .with_reopen_on_error(true)
         .with_render_config(description_render_config())
         .prompt()?;
 
@@ -0,0 +1,45 @@
+use inquire::{Expand, ExpandChoice};
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Overwrite,
+    Skip,
+    OverwriteAll,
+    Diff,
+    Quit,
+}
+
+fn main() {
+    let files = ["src/main.rs", "src/lib.rs", "Cargo.toml"];
+
+    for file in files {
+        let message = format!("Conflict on {file}:");
+        let action = Expand::new(
+            &message,
+            vec![
+                ExpandChoice::new('y', "overwrite this file", Action::Overwrite),
+                ExpandChoice::new('n', "skip this file", Action::Skip),
+                ExpandChoice::new(
+                    'a',
+                    "overwrite this and all remaining files",
+                    Action::OverwriteAll,
+                ),
+                ExpandChoice::new('d', "show the differences", Action::Diff),
+                ExpandChoice::new('q', "quit", Action::Quit),
+            ],
+        )
+        .with_default('n')
+        .prompt();
+
+        match action {
+            Ok(Action::Overwrite) => println!("Overwriting {file}"),
+            Ok(Action::Skip) => println!("Skipping {file}"),
+            Ok(Action::OverwriteAll) => {
+                println!("Overwriting all remaining files");
+                break;
+            }
+            Ok(Action::Diff) => println!("No differences in {file}"),
+            Ok(Action::Quit) | Err(_) => break,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use inquire::{
+    min_length, required,
+    validator::{LiveValidation, Validation},
+    CustomType, Password, Text,
+};
+
+fn main() {
+    let username = Text::new("Username:")
+        .with_validator(required!())
+        .with_validator(
+            |input: &str| match input.chars().all(char::is_alphanumeric) {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(
+                    "Only letters and digits are allowed".into(),
+                )),
+            },
+        )
+        .with_live_validation(LiveValidation::new())
+        .prompt()
+        .unwrap();
+
+    let _password = Password::new("Password:")
+        .without_confirmation()
+        .with_validator(min_length!(8, "At least 8 characters"))
+        .with_live_validation(
+            LiveValidation::new()
+                .with_debounce(Duration::from_millis(300))
+                .with_only_after_submit(true),
+        )
+        .prompt()
+        .unwrap();
+
+    let port = CustomType::<u16>::new("Port:")
+        .with_rich_parser(&|input| match input.parse::<u16>() {
+            Ok(0) => Err("Expected a port between 1 and 65535"),
+            Ok(port) => Ok(port),
+            Err(_) => Err("Not a number"),
+        })
+        .with_live_validation(LiveValidation::new().with_debounce(Duration::from_millis(300)))
+        .prompt()
+        .unwrap();
+
+    println!("{username} will listen on port {port}");
+}
@@ -1,13 +1,11 @@
 use chrono::NaiveDate;
-use inquire::{formatter::DEFAULT_DATE_FORMATTER, CustomType};
+use inquire::DateSelect;
 
 fn main() {
-    let amount = CustomType::<NaiveDate>::new("When are you going to visit the office?")
-        .with_placeholder("dd/mm/yyyy")
-        .with_parser(&|i| NaiveDate::parse_from_str(i, "%d/%m/%Y").map_err(|_e| ()))
-        .with_formatter(DEFAULT_DATE_FORMATTER)
-        .with_error_message("Please type a valid date.")
-        .with_help_message("The necessary arrangements will be made")
+    let amount = DateSelect::new("When are you going to visit the office?")
+        .with_input_parser(&|i, _today| NaiveDate::parse_from_str(i, "%d/%m/%Y").map_err(|_e| ()))
+        .with_input_error_message("Please type a valid date, e.g. 14/03/2019.")
+        .with_help_message("Type a date as dd/mm/yyyy or pick one with the arrows")
         .prompt();
 
     match amount {
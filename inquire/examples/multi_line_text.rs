@@ -0,0 +1,33 @@
+use inquire::{
+    ui::{Key, KeyModifiers},
+    validator::{MinLengthValidator, Validation},
+    MultiLineText,
+};
+
+fn main() {
+    let message = MultiLineText::new("Commit message:")
+        .with_placeholder("Summary line, a blank line and then the details")
+        .with_submit_key(Key::Char('\n', KeyModifiers::ALT))
+        .with_help_message("enter to insert a new line, alt+enter to submit")
+        .with_max_lines(10)
+        .with_validator(MinLengthValidator::new(10))
+        .with_validator(|message: &str| {
+            let summary = message.lines().next().unwrap_or_default();
+
+            match summary.chars().count() {
+                0..=50 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid(
+                    "Keep the summary line under 50 characters".into(),
+                )),
+            }
+        })
+        .with_formatter(&|message| message.lines().next().unwrap_or_default().to_owned())
+        .prompt();
+
+    match message {
+        Ok(message) => println!("Committing with:\n\n{message}"),
+        Err(_) => {
+            println!("An error happened when asking for the commit message, try again later.");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use inquire::{validator::Validation, Number};
+
+fn main() {
+    let size = Number::<u32>::new("Cache size:")
+        .with_min(16)
+        .with_max(4096)
+        .with_step(16)
+        .with_page_step(256)
+        .with_default(512)
+        .with_unit("MB")
+        .with_validator(|val: &u32| match val.is_power_of_two() {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid(
+                "The cache size should be a power of two".into(),
+            )),
+        })
+        .prompt();
+
+    let ratio = Number::<f64>::new("Eviction ratio:")
+        .with_min(0.0)
+        .with_max(1.0)
+        .with_step(0.05)
+        .with_starting_value(0.25)
+        .prompt();
+
+    match (size, ratio) {
+        (Ok(size), Ok(ratio)) => println!("Allocating {size} MB, evicting at {ratio}"),
+        _ => println!("An error happened, try again later."),
+    }
+}
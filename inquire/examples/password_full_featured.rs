@@ -1,10 +1,19 @@
-use inquire::{min_length, Password, PasswordDisplayMode};
+use inquire::{
+    min_length,
+    validator::{CharacterClassValidator, CharacterClasses, NoWhitespaceValidator},
+    Password, PasswordDisplayMode,
+};
 
 fn main() {
     let name = Password::new("RSA Encryption Key:")
         .with_display_toggle_enabled()
         .with_display_mode(PasswordDisplayMode::Masked)
         .with_validator(min_length!(10))
+        .with_validator(NoWhitespaceValidator::default())
+        .with_validator(CharacterClassValidator::new(
+            CharacterClasses::UPPERCASE | CharacterClasses::DIGIT,
+        ))
+        .with_strength_meter()
         .with_formatter(&|_| String::from("Input received"))
         .with_help_message("It is recommended to generate a new one only for this purpose")
         .with_custom_confirmation_error_message("The keys don't match.")
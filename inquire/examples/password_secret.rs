@@ -0,0 +1,16 @@
+use inquire::Password;
+
+fn main() {
+    let key = Password::new("API token:")
+        .without_confirmation()
+        .prompt_secret();
+
+    match key {
+        Ok(key) => println!(
+            "Received {:?} with {} characters, wiped when dropped.",
+            key,
+            key.expose_secret().chars().count()
+        ),
+        Err(_) => println!("An error happened when asking for your token, try again later."),
+    }
+}
@@ -0,0 +1,16 @@
+use inquire::{validator::ValueRequiredValidator, Select, SelectAnswer};
+
+fn main() {
+    let options = vec!["Banana", "Apple", "Strawberry", "Grapes", "Lemon"];
+
+    let ans = Select::new("What's your favorite fruit?", options)
+        .with_other_option("Other...")
+        .with_other_validator(ValueRequiredValidator::default())
+        .prompt_with_other();
+
+    match ans {
+        Ok(SelectAnswer::Chosen(choice)) => println!("{choice}! That's mine too!"),
+        Ok(SelectAnswer::Custom(fruit)) => println!("{fruit}? I should try it some day."),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,26 @@
+use inquire::{RangeSlider, Slider};
+
+fn main() {
+    let replicas = Slider::<u8>::new("How many replicas?", 1, 20)
+        .with_starting_value(3)
+        .with_tick(1, "1")
+        .with_tick(10, "10")
+        .with_tick(20, "20")
+        .prompt();
+
+    let interval = RangeSlider::<u8>::new("Confidence interval:", 0, 100)
+        .with_step(5)
+        .with_starting_range(25, 75)
+        .with_tick(0, "0%")
+        .with_tick(50, "50%")
+        .with_tick(100, "100%")
+        .with_unit("%")
+        .prompt();
+
+    match (replicas, interval) {
+        (Ok(replicas), Ok((low, high))) => {
+            println!("Scaling to {replicas} replicas, alerting outside of {low}% to {high}%");
+        }
+        _ => println!("An error happened, try again later."),
+    }
+}
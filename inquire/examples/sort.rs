@@ -0,0 +1,29 @@
+use inquire::Sort;
+
+fn main() {
+    let options = vec![
+        "Write the proposal",
+        "Review pull requests",
+        "Answer emails",
+        "Lunch",
+        "Plan next sprint",
+        "Update the documentation",
+        "Fix flaky tests",
+        "1:1 with manager",
+        "Go home",
+    ];
+
+    let ans = Sort::new("In which order will you tackle today's tasks?", options)
+        .with_pinned(&[3, 8])
+        .with_page_size(6)
+        .prompt();
+
+    match ans {
+        Ok(tasks) => {
+            for (i, task) in tasks.iter().enumerate() {
+                println!("{}. {}", i + 1, task);
+            }
+        }
+        Err(_) => println!("The agenda could not be processed"),
+    }
+}
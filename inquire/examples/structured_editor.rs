@@ -0,0 +1,33 @@
+use inquire::{error::InquireResult, validator::Validation, StructuredEditor, StructuredFormat};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    name: String,
+    retries: u32,
+    endpoints: Vec<String>,
+}
+
+fn main() -> InquireResult<()> {
+    let current = Settings {
+        name: String::from("production"),
+        retries: 3,
+        endpoints: vec![String::from("https://example.com")],
+    };
+
+    let settings = StructuredEditor::new("Settings:", &current)
+        .with_format(StructuredFormat::Toml)
+        .with_validator(|settings: &Settings| {
+            if settings.endpoints.is_empty() {
+                Ok(Validation::Invalid("Add at least one endpoint".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .with_reopen_on_error(true)
+        .prompt()?;
+
+    println!("{settings:#?}");
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use inquire::{MultiTableSelect, SortOrder, TableColumn, TableSelect};
+
+struct Instance {
+    name: &'static str,
+    zone: &'static str,
+    status: &'static str,
+    age_in_days: u32,
+}
+
+fn instances() -> Vec<Instance> {
+    vec![
+        Instance {
+            name: "web-frontend-1",
+            zone: "us-east1-b",
+            status: "RUNNING",
+            age_in_days: 3,
+        },
+        Instance {
+            name: "web-frontend-2",
+            zone: "us-east1-c",
+            status: "RUNNING",
+            age_in_days: 3,
+        },
+        Instance {
+            name: "postgres-primary",
+            zone: "europe-west1-d",
+            status: "RUNNING",
+            age_in_days: 412,
+        },
+        Instance {
+            name: "postgres-replica",
+            zone: "europe-west1-b",
+            status: "STOPPED",
+            age_in_days: 97,
+        },
+        Instance {
+            name: "redis-cache",
+            zone: "asia-northeast1-a",
+            status: "TERMINATED",
+            age_in_days: 45,
+        },
+        Instance {
+            name: "batch-worker-東京",
+            zone: "asia-northeast1-b",
+            status: "RUNNING",
+            age_in_days: 12,
+        },
+    ]
+}
+
+fn main() {
+    let columns = vec![
+        TableColumn::new("NAME", &|i: &Instance| i.name.to_owned()),
+        TableColumn::new("ZONE", &|i: &Instance| i.zone.to_owned()),
+        TableColumn::new("STATUS", &|i: &Instance| i.status.to_owned()),
+        TableColumn::new("AGE", &|i: &Instance| format!("{}d", i.age_in_days))
+            .with_comparator(&|a, b| a.age_in_days.cmp(&b.age_in_days))
+            .without_filtering(),
+    ];
+
+    let ans = TableSelect::new(
+        "Which instance do you want to connect to?",
+        instances(),
+        columns.clone(),
+    )
+    .with_sort(3, SortOrder::Descending)
+    .prompt();
+
+    match ans {
+        Ok(instance) => println!("Connecting to {} in {}...", instance.name, instance.zone),
+        Err(_) => println!("The instance could not be processed"),
+    }
+
+    let ans = MultiTableSelect::new("Which instances do you want to stop?", instances(), columns)
+        .prompt();
+
+    match ans {
+        Ok(instances) => {
+            for instance in instances {
+                println!("Stopping {}...", instance.name);
+            }
+        }
+        Err(_) => println!("The instances could not be processed"),
+    }
+}
@@ -18,6 +18,8 @@ fn main() {
         help_message: None,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
+        live_validation: None,
+        background_validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
         autocompleter: None,
         render_config: RenderConfig::default(),
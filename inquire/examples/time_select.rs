@@ -0,0 +1,74 @@
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use inquire::{validator::Validation, ClockFormat, DateTimeSelect, TimeSelect};
+
+fn main() {
+    time_select_default();
+    time_select_misc_options();
+    date_time_select_with_validation();
+}
+
+fn time_select_default() {
+    println!("-------> Simple TimeSelect");
+    println!();
+
+    let time = TimeSelect::new("Wake-up alarm:").prompt().unwrap();
+    println!("Your alarm is set to {time}.");
+    println!();
+}
+
+fn time_select_misc_options() {
+    println!("-------> TimeSelect with 12-hour clock, minute step and bounds");
+    println!();
+
+    let time = TimeSelect::new("When should the meeting start?")
+        .with_starting_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+        .with_min_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+        .with_max_time(NaiveTime::from_hms_opt(17, 30, 0).unwrap())
+        .with_clock_format(ClockFormat::TwelveHour)
+        .with_minute_step(15)
+        .with_validator(|t: NaiveTime| {
+            if t.hour() == 12 {
+                Ok(Validation::Invalid("That's lunch time!".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt();
+
+    match time {
+        Ok(_) => println!("The meeting was scheduled."),
+        Err(_) => println!("We could not schedule the meeting."),
+    }
+    println!();
+}
+
+fn date_time_select_with_validation() {
+    println!("-------> DateTimeSelect with validation");
+    println!();
+
+    let departure = DateTimeSelect::new("When do you want to depart?")
+        .with_starting_datetime(
+            NaiveDate::from_ymd_opt(2021, 8, 1)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap(),
+        )
+        .with_week_start(chrono::Weekday::Mon)
+        .with_minute_step(30)
+        .with_validator(|d: chrono::NaiveDateTime| {
+            if d.hour() < 6 {
+                Ok(Validation::Invalid(
+                    "There are no trains before 6 AM".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt();
+
+    match departure {
+        Ok(_) => println!("No trains available at this time."),
+        Err(_) => println!("There was an error in the system."),
+    }
+    println!();
+}
@@ -0,0 +1,34 @@
+use inquire::{error::CustomUserError, TreeNode, TreeSelect};
+
+fn load_resources(path: &[&String]) -> Result<Vec<TreeNode<String>>, CustomUserError> {
+    // Simulates a call to the cluster, only made when a namespace is expanded.
+    let namespace = path[0];
+    let resources = ["pods", "services", "deployments"]
+        .iter()
+        .map(|kind| {
+            let names = (1..=3)
+                .map(|i| TreeNode::new(format!("{}-{}-{}", namespace, kind, i)))
+                .collect();
+            TreeNode::new((*kind).to_owned()).with_children(names)
+        })
+        .collect();
+
+    Ok(resources)
+}
+
+fn main() {
+    let namespaces = ["default", "kube-system", "monitoring"]
+        .iter()
+        .map(|ns| TreeNode::new((*ns).to_owned()).with_lazy_children())
+        .collect();
+
+    let ans = TreeSelect::new("Which resource do you want to inspect?", namespaces)
+        .with_children_loader(&load_resources)
+        .with_page_size(10)
+        .prompt();
+
+    match ans {
+        Ok(path) => println!("Inspecting {}", path.join("/")),
+        Err(_) => println!("The resource could not be inspected"),
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use inquire::{validator::Validation, CustomType, DateSelect};
+
+fn main() {
+    let port = CustomType::<u16>::new("Port:")
+        .with_validator(|port: &u16| match *port {
+            0 => Ok(Validation::Invalid("Port 0 is reserved".into())),
+            1..=1023 => Ok(Validation::Warning(
+                "Well-known ports require privileges, press enter again to use it anyway".into(),
+            )),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt()
+        .unwrap();
+
+    let date = DateSelect::new("Deploy date:")
+        .with_validator(|date: NaiveDate| match date.weekday() {
+            Weekday::Sat | Weekday::Sun => Ok(Validation::Warning(
+                "Deploying on a weekend, press enter again to confirm".into(),
+            )),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt()
+        .unwrap();
+
+    println!("Deploying on port {port} on {date}");
+}
@@ -0,0 +1,33 @@
+use inquire::{
+    in_range, min_length, one_of, required,
+    validator::{and, email, hostname, ip_address, not, or, when},
+    CustomType, Select, Text,
+};
+
+fn main() {
+    let host = Text::new("Server:")
+        .with_validator(and!(
+            required!(),
+            or!(hostname!(), ip_address!()),
+            not!(one_of!(["localhost"]), "Use a remote server")
+        ))
+        .prompt()
+        .unwrap();
+
+    let port = CustomType::<u16>::new("Port:")
+        .with_validator(in_range!(1024..=49151))
+        .prompt()
+        .unwrap();
+
+    let environment = Select::new("Environment:", vec!["staging", "production"])
+        .prompt()
+        .unwrap();
+
+    let contact = Text::new("On-call e-mail:")
+        .with_help_message("Leave empty to skip")
+        .with_validator(when!(min_length!(1), email!()))
+        .prompt()
+        .unwrap();
+
+    println!("Deploying to {host}:{port} ({environment}), contact: {contact}");
+}
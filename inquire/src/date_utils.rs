@@ -1,6 +1,8 @@
 use core::panic;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveTime, Timelike, Weekday};
+
+use crate::ClockFormat;
 
 pub fn get_current_date() -> NaiveDate {
     chrono::Local::now().date_naive()
@@ -28,10 +30,343 @@ pub fn get_month(month: u32) -> chrono::Month {
     }
 }
 
+/// Component of a time that can be adjusted in the time prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeField {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+pub fn get_current_time(with_seconds: bool) -> NaiveTime {
+    truncate_time(chrono::Local::now().time(), with_seconds)
+}
+
+/// Drops the fractional seconds of the time, along with the seconds
+/// themselves when they are not displayed.
+pub fn truncate_time(time: NaiveTime, with_seconds: bool) -> NaiveTime {
+    let second = if with_seconds { time.second() } else { 0 };
+    NaiveTime::from_hms_opt(time.hour(), time.minute(), second).unwrap()
+}
+
+pub fn get_time_fields(clock_format: ClockFormat, with_seconds: bool) -> Vec<TimeField> {
+    let mut fields = vec![TimeField::Hour, TimeField::Minute];
+    if with_seconds {
+        fields.push(TimeField::Second);
+    }
+    if clock_format == ClockFormat::TwelveHour {
+        fields.push(TimeField::Period);
+    }
+    fields
+}
+
+/// Displayed value of a single field of the time, e.g. "09" or "PM".
+pub fn format_time_field(time: NaiveTime, field: TimeField, clock_format: ClockFormat) -> String {
+    match (field, clock_format) {
+        (TimeField::Hour, ClockFormat::TwentyFourHour) => format!("{:02}", time.hour()),
+        (TimeField::Hour, ClockFormat::TwelveHour) => format!("{:02}", time.hour12().1),
+        (TimeField::Minute, _) => format!("{:02}", time.minute()),
+        (TimeField::Second, _) => format!("{:02}", time.second()),
+        (TimeField::Period, _) if time.hour12().0 => String::from("PM"),
+        (TimeField::Period, _) => String::from("AM"),
+    }
+}
+
+/// Formats the time the same way it is displayed by the time prompts.
+pub fn format_time(time: NaiveTime, clock_format: ClockFormat, with_seconds: bool) -> String {
+    let mut formatted = String::new();
+
+    for field in get_time_fields(clock_format, with_seconds) {
+        match field {
+            TimeField::Hour => {}
+            TimeField::Period => formatted.push(' '),
+            _ => formatted.push(':'),
+        }
+        formatted.push_str(&format_time_field(time, field, clock_format));
+    }
+
+    formatted
+}
+
+/// Moves the given field of the time one step forward or backward, wrapping
+/// around without carrying over to the other fields.
+///
+/// Minutes are moved to the next or previous multiple of `minute_step`.
+pub fn spin_time(time: NaiveTime, field: TimeField, forward: bool, minute_step: u32) -> NaiveTime {
+    let spin = |value: u32, step: u32, modulo: u32| {
+        if forward {
+            let next = (value / step + 1) * step;
+            if next >= modulo {
+                0
+            } else {
+                next
+            }
+        } else if value % step != 0 {
+            value / step * step
+        } else if value == 0 {
+            (modulo - 1) / step * step
+        } else {
+            value - step
+        }
+    };
+
+    let spun = match field {
+        TimeField::Hour => time.with_hour(spin(time.hour(), 1, 24)),
+        TimeField::Minute => time.with_minute(spin(time.minute(), minute_step, 60)),
+        TimeField::Second => time.with_second(spin(time.second(), 1, 60)),
+        TimeField::Period => time.with_hour((time.hour() + 12) % 24),
+    };
+
+    spun.expect("spun time fields are always within range")
+}
+
+/// Parses a date typed by the user, relative to `today` when applicable.
+///
+/// Accepts, case-insensitively, `today`, `tomorrow` and `yesterday`, ISO dates
+/// such as `2019-03-14`, offsets such as `+3d`, `-2w`, `+1m` or `-10y`, and
+/// weekdays such as `fri`, `next friday` or `last monday`.
+pub fn parse_date_input(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    match input.as_str() {
+        "today" => return Some(today),
+        "tomorrow" => return today.succ_opt(),
+        "yesterday" => return today.pred_opt(),
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    if let Some(offset) = input.strip_prefix('+') {
+        return shift_date(today, offset, true);
+    }
+    if let Some(offset) = input.strip_prefix('-') {
+        return shift_date(today, offset, false);
+    }
+
+    let (relative, weekday) = match input.split_once(' ') {
+        Some((relative, weekday)) => (Some(relative), weekday.trim_start()),
+        None => (None, input.as_str()),
+    };
+    let weekday = weekday.parse::<Weekday>().ok()?;
+
+    let days_until = |date: NaiveDate| {
+        (7 + weekday.num_days_from_monday() - date.weekday().num_days_from_monday()) % 7
+    };
+
+    match relative {
+        None => today.checked_add_days(Days::new(u64::from(days_until(today)))),
+        Some("next") => {
+            let tomorrow = today.succ_opt()?;
+            tomorrow.checked_add_days(Days::new(u64::from(days_until(tomorrow))))
+        }
+        Some("last") => {
+            let days_since =
+                (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday() - 1)
+                    % 7
+                    + 1;
+            today.checked_sub_days(Days::new(u64::from(days_since)))
+        }
+        Some(_) => None,
+    }
+}
+
+/// Shifts the date by an offset such as `3d`, `2w`, `1m` or `10y`, where a
+/// missing unit stands for days.
+fn shift_date(date: NaiveDate, offset: &str, forward: bool) -> Option<NaiveDate> {
+    let unit_idx = offset
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(offset.len());
+    let (qty, unit) = offset.split_at(unit_idx);
+    let qty = qty.parse::<u32>().ok()?;
+
+    let days = match unit.trim() {
+        "" | "d" => u64::from(qty),
+        "w" => u64::from(qty) * 7,
+        "m" | "y" => {
+            let months = match unit.trim() {
+                "y" => Months::new(qty.checked_mul(12)?),
+                _ => Months::new(qty),
+            };
+            return if forward {
+                date.checked_add_months(months)
+            } else {
+                date.checked_sub_months(months)
+            };
+        }
+        _ => return None,
+    };
+
+    if forward {
+        date.checked_add_days(Days::new(days))
+    } else {
+        date.checked_sub_days(Days::new(days))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn test_spin_time_wraps_without_carrying() {
+        assert_eq!(
+            spin_time(time(23, 59, 59), TimeField::Hour, true, 1),
+            time(0, 59, 59)
+        );
+        assert_eq!(
+            spin_time(time(0, 0, 0), TimeField::Hour, false, 1),
+            time(23, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 59, 0), TimeField::Minute, true, 1),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 0, 0), TimeField::Minute, false, 1),
+            time(10, 59, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 0, 59), TimeField::Second, true, 1),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 0, 0), TimeField::Second, false, 1),
+            time(10, 0, 59)
+        );
+    }
+
+    #[test]
+    fn test_spin_time_snaps_minutes_to_step() {
+        assert_eq!(
+            spin_time(time(10, 7, 0), TimeField::Minute, true, 15),
+            time(10, 15, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 7, 0), TimeField::Minute, false, 15),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 45, 0), TimeField::Minute, true, 15),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 0, 0), TimeField::Minute, false, 15),
+            time(10, 45, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 49, 0), TimeField::Minute, true, 7),
+            time(10, 56, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 56, 0), TimeField::Minute, true, 7),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            spin_time(time(10, 0, 0), TimeField::Minute, false, 7),
+            time(10, 56, 0)
+        );
+    }
+
+    #[test]
+    fn test_spin_time_toggles_period() {
+        assert_eq!(
+            spin_time(time(9, 30, 0), TimeField::Period, true, 1),
+            time(21, 30, 0)
+        );
+        assert_eq!(
+            spin_time(time(21, 30, 0), TimeField::Period, false, 1),
+            time(9, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        let t = time(0, 5, 9);
+        assert_eq!(format_time(t, ClockFormat::TwentyFourHour, false), "00:05");
+        assert_eq!(
+            format_time(t, ClockFormat::TwentyFourHour, true),
+            "00:05:09"
+        );
+        assert_eq!(format_time(t, ClockFormat::TwelveHour, false), "12:05 AM");
+        assert_eq!(
+            format_time(time(13, 5, 9), ClockFormat::TwelveHour, true),
+            "01:05:09 PM"
+        );
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_date_input_keywords_and_iso_dates() {
+        let today = date(2023, 3, 15);
+        assert_eq!(parse_date_input("today", today), Some(today));
+        assert_eq!(
+            parse_date_input(" Tomorrow ", today),
+            Some(date(2023, 3, 16))
+        );
+        assert_eq!(
+            parse_date_input("YESTERDAY", today),
+            Some(date(2023, 3, 14))
+        );
+        assert_eq!(
+            parse_date_input("2019-03-14", today),
+            Some(date(2019, 3, 14))
+        );
+        assert_eq!(parse_date_input("2019-02-30", today), None);
+        assert_eq!(parse_date_input("2019-03", today), None);
+        assert_eq!(parse_date_input("", today), None);
+    }
+
+    #[test]
+    fn test_parse_date_input_offsets() {
+        let today = date(2023, 1, 31);
+        assert_eq!(parse_date_input("+3d", today), Some(date(2023, 2, 3)));
+        assert_eq!(parse_date_input("+3", today), Some(date(2023, 2, 3)));
+        assert_eq!(parse_date_input("-2w", today), Some(date(2023, 1, 17)));
+        assert_eq!(parse_date_input("+1m", today), Some(date(2023, 2, 28)));
+        assert_eq!(parse_date_input("-10y", today), Some(date(2013, 1, 31)));
+        assert_eq!(parse_date_input("+", today), None);
+        assert_eq!(parse_date_input("+3x", today), None);
+        assert_eq!(parse_date_input("+d", today), None);
+    }
+
+    #[test]
+    fn test_parse_date_input_weekdays() {
+        // Wednesday
+        let today = date(2023, 3, 15);
+        assert_eq!(parse_date_input("fri", today), Some(date(2023, 3, 17)));
+        assert_eq!(parse_date_input("wednesday", today), Some(today));
+        assert_eq!(
+            parse_date_input("next friday", today),
+            Some(date(2023, 3, 17))
+        );
+        assert_eq!(parse_date_input("next wed", today), Some(date(2023, 3, 22)));
+        assert_eq!(
+            parse_date_input("last monday", today),
+            Some(date(2023, 3, 13))
+        );
+        assert_eq!(
+            parse_date_input("last wednesday", today),
+            Some(date(2023, 3, 8))
+        );
+        assert_eq!(
+            parse_date_input("last thursday", today),
+            Some(date(2023, 3, 9))
+        );
+        assert_eq!(parse_date_input("past friday", today), None);
+        assert_eq!(parse_date_input("next", today), None);
+    }
+
     #[test]
     fn test_get_current_date() {
         let current_date = get_current_date();
@@ -129,6 +129,44 @@ pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 /// ```
 pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 
+/// Type alias for formatters used in [`TreeSelect`](crate::TreeSelect) prompts.
+///
+/// Formatters receive the values of the nodes from the root of the tree down to
+/// the selected node, and return a [String] to be displayed to the user as the
+/// final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::TreePathFormatter;
+///
+/// let formatter: TreePathFormatter<str> = &|path| match path.last() {
+///     Some(node) => node.to_string(),
+///     None => String::new(),
+/// };
+/// assert_eq!(String::from("pods"), formatter(&["default", "pods"]));
+/// ```
+pub type TreePathFormatter<'a, T> = &'a dyn Fn(&[&T]) -> String;
+
+/// Type alias for formatters used in [`MultiTreeSelect`](crate::MultiTreeSelect) prompts.
+///
+/// Formatters receive the paths of the selected nodes, each one containing the
+/// values of the nodes from the root of the tree down to the selected node, and
+/// return a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::MultiTreePathFormatter;
+///
+/// let formatter: MultiTreePathFormatter<str> = &|paths| format!("{} nodes", paths.len());
+/// assert_eq!(
+///     String::from("2 nodes"),
+///     formatter(&[vec!["default", "pods"], vec!["default"]]),
+/// );
+/// ```
+pub type MultiTreePathFormatter<'a, T> = &'a dyn Fn(&[Vec<&T>]) -> String;
+
 /// Type alias for formatters used in [`CustomType`](crate::CustomType) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -148,8 +186,6 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
-#[cfg(feature = "date")]
-
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -168,8 +204,91 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 ///     formatter(NaiveDate::from_ymd(2021, 7, 25)),
 /// );
 /// ```
+#[cfg(feature = "date")]
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
+/// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts
+/// run in range mode, receiving the start and the end of the selected range.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DateRangeFormatter;
+///
+/// let formatter: DateRangeFormatter = &|start, end| {
+///     format!("{} nights from {}", (end - start).num_days(), start.format("%d/%m"))
+/// };
+///
+/// assert_eq!(
+///     String::from("3 nights from 25/07"),
+///     formatter(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap(), NaiveDate::from_ymd_opt(2021, 7, 28).unwrap()),
+/// );
+/// ```
+#[cfg(feature = "date")]
+pub type DateRangeFormatter<'a> = &'a dyn Fn(chrono::NaiveDate, chrono::NaiveDate) -> String;
+
+/// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts
+/// run in multiple mode, receiving the selected dates in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::MultiDateFormatter;
+///
+/// let formatter: MultiDateFormatter = &|dates| format!("{} days off", dates.len());
+///
+/// assert_eq!(
+///     String::from("2 days off"),
+///     formatter(&[NaiveDate::from_ymd_opt(2021, 7, 25).unwrap(), NaiveDate::from_ymd_opt(2021, 7, 28).unwrap()]),
+/// );
+/// ```
+#[cfg(feature = "date")]
+pub type MultiDateFormatter<'a> = &'a dyn Fn(&[chrono::NaiveDate]) -> String;
+
+/// Type alias for formatters used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use inquire::formatter::TimeFormatter;
+///
+/// let formatter: TimeFormatter = &|val| val.format("%l:%M %p").to_string();
+///
+/// assert_eq!(
+///     String::from(" 2:30 PM"),
+///     formatter(NaiveTime::from_hms_opt(14, 30, 0).unwrap()),
+/// );
+/// ```
+#[cfg(feature = "date")]
+pub type TimeFormatter<'a> = &'a dyn Fn(chrono::NaiveTime) -> String;
+
+/// Type alias for formatters used in [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DateTimeFormatter;
+///
+/// let formatter: DateTimeFormatter = &|val| val.format("%d/%m/%Y %H:%M").to_string();
+///
+/// assert_eq!(
+///     String::from("25/07/2021 14:30"),
+///     formatter(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap().and_hms_opt(14, 30, 0).unwrap()),
+/// );
+/// ```
+#[cfg(feature = "date")]
+pub type DateTimeFormatter<'a> = &'a dyn Fn(chrono::NaiveDateTime) -> String;
+
 /// String formatter used by default in inputs that return a `String` as input.
 /// Its behavior is to just echo the received input.
 ///
@@ -226,3 +345,53 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
 /// );
 /// ```
 pub const DEFAULT_DATE_FORMATTER: DateFormatter<'_> = &|val| val.format("%B %-e, %Y").to_string();
+
+#[cfg(feature = "date")]
+/// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts run in range mode.
+/// Prints both dates of the range in the format: Month Day, Year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DEFAULT_DATE_RANGE_FORMATTER;
+///
+/// let formatter = DEFAULT_DATE_RANGE_FORMATTER;
+///
+/// assert_eq!(
+///     String::from("July 25, 2021 - August 2, 2021"),
+///     formatter(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap(), NaiveDate::from_ymd_opt(2021, 8, 2).unwrap()),
+/// );
+/// ```
+pub const DEFAULT_DATE_RANGE_FORMATTER: DateRangeFormatter<'_> = &|start, end| {
+    format!(
+        "{} - {}",
+        DEFAULT_DATE_FORMATTER(start),
+        DEFAULT_DATE_FORMATTER(end)
+    )
+};
+
+#[cfg(feature = "date")]
+/// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts run in multiple mode.
+/// Prints the selected dates in the format Month Day, Year, separated by commas.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DEFAULT_MULTI_DATE_FORMATTER;
+///
+/// let formatter = DEFAULT_MULTI_DATE_FORMATTER;
+///
+/// assert_eq!(
+///     String::from("July 25, 2021, August 2, 2021"),
+///     formatter(&[NaiveDate::from_ymd_opt(2021, 7, 25).unwrap(), NaiveDate::from_ymd_opt(2021, 8, 2).unwrap()]),
+/// );
+/// ```
+pub const DEFAULT_MULTI_DATE_FORMATTER: MultiDateFormatter<'_> = &|dates| {
+    dates
+        .iter()
+        .map(|date| DEFAULT_DATE_FORMATTER(*date))
+        .collect::<Vec<String>>()
+        .join(", ")
+};
@@ -1,8 +1,13 @@
 pub mod action;
+mod multi_line;
 #[cfg(test)]
 mod test;
 
+pub use multi_line::MultiLineInput;
+
 use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 use crate::InputAction;
 
@@ -97,6 +102,9 @@ impl Input {
     }
 
     pub fn clear(&mut self) {
+        #[cfg(feature = "zeroize")]
+        self.content.zeroize();
+        #[cfg(not(feature = "zeroize"))]
         self.content.clear();
         self.cursor = 0;
         self.length = 0;
@@ -106,6 +114,13 @@ impl Input {
         &self.content
     }
 
+    /// Moves the content out of the input, leaving it empty.
+    pub fn take_content(&mut self) -> String {
+        self.cursor = 0;
+        self.length = 0;
+        std::mem::take(&mut self.content)
+    }
+
     pub fn length(&self) -> usize {
         self.length
     }
@@ -207,6 +222,7 @@ impl Input {
         let at = self.cursor;
 
         if at >= self.length {
+            self.reserve(c.len_utf8());
             self.content.push(c);
             if self.update_length() {
                 self.cursor = self.cursor.saturating_add(1);
@@ -214,7 +230,7 @@ impl Input {
             return InputActionResult::ContentChanged;
         }
 
-        let mut result = String::new();
+        let mut result = String::with_capacity(self.content.len() + c.len_utf8());
         for (index, grapheme) in self.content[..].graphemes(true).enumerate() {
             if index == at {
                 result.push(c);
@@ -222,7 +238,7 @@ impl Input {
             result.push_str(grapheme);
         }
 
-        self.content = result;
+        self.set_content(result);
         if self.update_length() {
             self.cursor = self.cursor.saturating_add(1);
         }
@@ -267,7 +283,7 @@ impl Input {
         let start = self.cursor;
         let end = start.saturating_add(qty);
 
-        let mut new_content: String = String::new();
+        let mut new_content = String::with_capacity(self.content.len());
         let mut length = 0;
         let mut result = InputActionResult::Clean;
 
@@ -281,11 +297,39 @@ impl Input {
         }
 
         self.length = length;
-        self.content = new_content;
+        self.set_content(new_content);
 
         result
     }
 
+    /// Replaces the content, wiping the previous buffer when the `zeroize` feature is enabled.
+    fn set_content(&mut self, content: String) {
+        #[cfg(feature = "zeroize")]
+        self.content.zeroize();
+
+        self.content = content;
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    fn reserve(&mut self, additional: usize) {
+        self.content.reserve(additional);
+    }
+
+    /// Grows the buffer by hand, as a reallocation by `String` would leave
+    /// the previous buffer behind without wiping it.
+    #[cfg(feature = "zeroize")]
+    fn reserve(&mut self, additional: usize) {
+        let len = self.content.len();
+        if self.content.capacity() - len >= additional {
+            return;
+        }
+
+        let capacity = (len + additional).max(self.content.capacity() * 2);
+        let mut content = String::with_capacity(capacity);
+        content.push_str(&self.content);
+        self.set_content(content);
+    }
+
     fn update_length(&mut self) -> bool {
         let new_len = self.content[..].graphemes(true).count();
         let old_len = self.length;
@@ -295,6 +339,13 @@ impl Input {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for Input {
+    fn drop(&mut self) {
+        self.content.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum InputActionResult {
     ContentChanged,
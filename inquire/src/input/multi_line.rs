@@ -0,0 +1,291 @@
+use std::cmp::min;
+
+use crate::InputAction;
+
+use super::{Input, InputActionResult, LineDirection, Magnitude};
+
+/// Text input spanning multiple lines, each one handled by an [Input].
+///
+/// Horizontal movements and deletions that reach the start or the end of a line
+/// continue on the adjacent line, joining both lines when deleting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiLineInput {
+    lines: Vec<Input>,
+    placeholder: Option<String>,
+    row: usize,
+    /// Column the cursor returns to when moving vertically through shorter lines.
+    preferred_col: Option<usize>,
+}
+
+impl MultiLineInput {
+    pub fn new_with(content: &str) -> Self {
+        let lines: Vec<Input> = content
+            .split('\n')
+            .map(|line| Input::new_with(line.strip_suffix('\r').unwrap_or(line)))
+            .collect();
+
+        Self {
+            row: lines.len() - 1,
+            lines,
+            placeholder: None,
+            preferred_col: None,
+        }
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(String::from(placeholder));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    pub fn placeholder(&self) -> Option<&str> {
+        self.placeholder.as_deref()
+    }
+
+    pub fn content(&self) -> String {
+        self.lines
+            .iter()
+            .map(Input::content)
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    pub fn lines(&self) -> &[Input] {
+        &self.lines
+    }
+
+    /// Index of the line where the cursor is.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn handle(&mut self, action: InputAction) -> InputActionResult {
+        self.preferred_col = None;
+
+        let at_line_start = self.current_line().cursor() == 0;
+        let at_line_end = self.current_line().cursor() == self.current_line().length();
+        let has_previous_line = self.row > 0;
+        let has_next_line = self.row + 1 < self.lines.len();
+
+        match action {
+            InputAction::Write('\n') => self.insert_new_line(),
+            InputAction::Write('\r') => InputActionResult::Clean,
+            InputAction::MoveCursor(Magnitude::Char | Magnitude::Word, LineDirection::Left)
+                if at_line_start && has_previous_line =>
+            {
+                self.row -= 1;
+                self.set_cursor(self.current_line().length());
+                InputActionResult::PositionChanged
+            }
+            InputAction::MoveCursor(Magnitude::Char | Magnitude::Word, LineDirection::Right)
+                if at_line_end && has_next_line =>
+            {
+                self.row += 1;
+                self.set_cursor(0);
+                InputActionResult::PositionChanged
+            }
+            InputAction::Delete(Magnitude::Char | Magnitude::Word, LineDirection::Left)
+                if at_line_start && has_previous_line =>
+            {
+                self.row -= 1;
+                self.join_next_line()
+            }
+            InputAction::Delete(Magnitude::Char | Magnitude::Word, LineDirection::Right)
+                if at_line_end && has_next_line =>
+            {
+                self.join_next_line()
+            }
+            action => self.lines[self.row].handle(action),
+        }
+    }
+
+    /// Splits the current line at the cursor, moving the cursor to the start of the new line.
+    pub fn insert_new_line(&mut self) -> InputActionResult {
+        self.preferred_col = None;
+
+        let line = &self.lines[self.row];
+        let (before, after) = line.content().split_at(line.pre_cursor().len());
+        let (before, after) = (
+            Input::new_with(before),
+            Input::new_with(after).with_cursor(0),
+        );
+
+        self.lines[self.row] = before;
+        self.lines.insert(self.row + 1, after);
+        self.row += 1;
+
+        InputActionResult::ContentChanged
+    }
+
+    pub fn move_up(&mut self) -> InputActionResult {
+        match self.row {
+            0 => InputActionResult::Clean,
+            row => self.move_to_row(row - 1),
+        }
+    }
+
+    pub fn move_down(&mut self) -> InputActionResult {
+        match self.row + 1 {
+            row if row < self.lines.len() => self.move_to_row(row),
+            _ => InputActionResult::Clean,
+        }
+    }
+
+    fn move_to_row(&mut self, row: usize) -> InputActionResult {
+        let col = self
+            .preferred_col
+            .unwrap_or_else(|| self.current_line().cursor());
+
+        self.row = row;
+        self.set_cursor(min(col, self.current_line().length()));
+        self.preferred_col = Some(col);
+
+        InputActionResult::PositionChanged
+    }
+
+    /// Appends the line below the cursor to the current one, keeping the cursor at the junction.
+    fn join_next_line(&mut self) -> InputActionResult {
+        let next = self.lines.remove(self.row + 1);
+        let line = &self.lines[self.row];
+        let cursor = line.length();
+
+        self.lines[self.row] =
+            Input::new_with(format!("{}{}", line.content(), next.content())).with_cursor(cursor);
+
+        InputActionResult::ContentChanged
+    }
+
+    fn current_line(&self) -> &Input {
+        &self.lines[self.row]
+    }
+
+    fn set_cursor(&mut self, cursor: usize) {
+        self.lines[self.row] = self.lines[self.row].clone().with_cursor(cursor);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MultiLineInput;
+    use crate::{
+        input::{InputActionResult, LineDirection, Magnitude},
+        InputAction,
+    };
+
+    fn cursor(input: &MultiLineInput) -> (usize, usize) {
+        (input.row(), input.lines()[input.row()].cursor())
+    }
+
+    #[test]
+    fn new_with_splits_content_into_lines() {
+        let input = MultiLineInput::new_with("first\r\nsecond\n");
+
+        assert_eq!(3, input.lines().len());
+        assert_eq!("first\nsecond\n", input.content());
+        assert_eq!((2, 0), cursor(&input));
+    }
+
+    #[test]
+    fn new_line_splits_line_at_cursor() {
+        let mut input = MultiLineInput::new_with("helloworld");
+        for _ in 0..5 {
+            input.handle(InputAction::MoveCursor(
+                Magnitude::Char,
+                LineDirection::Left,
+            ));
+        }
+
+        assert_eq!(InputActionResult::ContentChanged, input.insert_new_line());
+        assert_eq!("hello\nworld", input.content());
+        assert_eq!((1, 0), cursor(&input));
+
+        input.handle(InputAction::Write('\n'));
+        assert_eq!("hello\n\nworld", input.content());
+        assert_eq!((2, 0), cursor(&input));
+    }
+
+    #[test]
+    fn deleting_at_line_boundaries_joins_lines() {
+        let mut input = MultiLineInput::new_with("ab\ncd");
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+
+        input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+        assert_eq!("abcd", input.content());
+        assert_eq!((0, 2), cursor(&input));
+
+        input.insert_new_line();
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Left,
+        ));
+        assert_eq!((0, 2), cursor(&input));
+
+        input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Right));
+        assert_eq!("abcd", input.content());
+        assert_eq!((0, 2), cursor(&input));
+    }
+
+    #[test]
+    fn horizontal_movement_wraps_around_lines() {
+        let mut input = MultiLineInput::new_with("ab\ncd");
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Left,
+        ));
+        assert_eq!((0, 2), cursor(&input));
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Right,
+        ));
+        assert_eq!((1, 0), cursor(&input));
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Right,
+        ));
+        assert_eq!(
+            InputActionResult::Clean,
+            input.handle(InputAction::MoveCursor(
+                Magnitude::Char,
+                LineDirection::Right
+            ))
+        );
+    }
+
+    #[test]
+    fn vertical_movement_keeps_preferred_column() {
+        let mut input = MultiLineInput::new_with("long line\nab\nanother line");
+
+        assert_eq!(InputActionResult::PositionChanged, input.move_up());
+        assert_eq!((1, 2), cursor(&input));
+
+        assert_eq!(InputActionResult::PositionChanged, input.move_up());
+        assert_eq!((0, 9), cursor(&input));
+
+        input.move_down();
+        input.move_down();
+        assert_eq!((2, 12), cursor(&input));
+        assert_eq!(InputActionResult::Clean, input.move_down());
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+        input.move_up();
+        input.move_up();
+        assert_eq!((0, 0), cursor(&input));
+        assert_eq!(InputActionResult::Clean, input.move_up());
+    }
+}
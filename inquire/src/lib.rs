@@ -4,15 +4,26 @@
 //! for information via the CLI. With `inquire`, you can use:
 //!
 //! - [`Text`] to get text input from the user, with _built-in autocompletion support_;
+//! - [`MultiLineText`] to get text input spanning multiple lines, edited in place;
 //! - [`Editor`]* to get longer text inputs by opening a text editor for the user;
+//! - [`StructuredEditor`]** to edit a value as JSON, TOML or YAML in a text editor, parsing it back;
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
+//! - [`TimeSelect`]* and [`DateTimeSelect`]* to get a time, or a date and a time, input from the user by adjusting _hour and minute spinners_;
 //! - [`Select`] to ask the user to select one option from a given list;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
-//! - [`Confirm`] for simple yes/no confirmation prompts;
+//! - [`Sort`] to ask the user to put a given list of options in their preferred order;
+//! - [`TreeSelect`] and [`MultiTreeSelect`] to ask the user to select one or more nodes from a tree of options;
+//! - [`TableSelect`] and [`MultiTableSelect`] to ask the user to select one or more rows from a table with aligned columns;
+//! - [`Confirm`] for simple yes/no confirmation prompts, optionally answered with a single keypress;
+//! - [`Expand`] to ask the user to pick one of several lettered choices, as in `[y,n,a,d,q,?]`;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
+//! - [`Number`] to get a number within optional bounds, typed or stepped with the arrow keys;
+//! - [`Slider`] and [`RangeSlider`] to pick a number, or a range of numbers, between two bounds by moving handles on a bar;
 //! - [`Password`] for secretive text prompts.
 //!
-//! \* The Editor and DateSelect prompts are available by enabling the `editor` and `date` features, respectively.
+//! \* The Editor prompt is available by enabling the `editor` feature, and the DateSelect, TimeSelect and DateTimeSelect prompts by enabling the `date` feature.
+//!
+//! \*\* The StructuredEditor prompt is available by enabling the `serde` feature.
 //!
 //! Check out the [GitHub repository](https://github.com/mikaelmello/inquire) to see demos of what you can do with `inquire`.
 //!
@@ -54,18 +65,36 @@
 //! ```
 //!
 //! [`Text`]: crate::Text
+//! [`MultiLineText`]: crate::MultiLineText
 //! [`DateSelect`]: crate::DateSelect
+//! [`TimeSelect`]: crate::TimeSelect
+//! [`DateTimeSelect`]: crate::DateTimeSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
+//! [`Sort`]: crate::Sort
+//! [`TreeSelect`]: crate::TreeSelect
+//! [`MultiTreeSelect`]: crate::MultiTreeSelect
+//! [`TableSelect`]: crate::TableSelect
+//! [`MultiTableSelect`]: crate::MultiTableSelect
 //! [`Confirm`]: crate::Confirm
+//! [`Expand`]: crate::Expand
 //! [`CustomType`]: crate::CustomType
+//! [`Number`]: crate::Number
+//! [`Slider`]: crate::Slider
+//! [`RangeSlider`]: crate::RangeSlider
 //! [`Password`]: crate::Password
 //! [`Editor`]: crate::Editor
+//! [`StructuredEditor`]: crate::StructuredEditor
 
 #![warn(missing_docs)]
 #![deny(unused_crate_dependencies)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::bool_to_int_with_if)]
+
+// The serde dev-dependency is only used by examples and `serde` feature tests.
+#[cfg(all(test, not(feature = "serde")))]
+use serde as _;
+
 mod ansi;
 pub mod autocompletion;
 mod config;
@@ -5,6 +5,94 @@
 //! Parsers receive the user input to a given prompt and return either
 //! a successful result ([Ok]) containing the parsed value or an empty [Err]
 //! if a value could not be parsed.
+//!
+//! [Confirm](crate::Confirm) and [`CustomType`](crate::CustomType) prompts also accept
+//! rich parsers, set with `with_rich_parser`, whose error is displayed to the user: an
+//! [`ErrorMessage::Custom`] is shown as is, while [`ErrorMessage::Default`] and the empty
+//! `()` error show the `error_message` of the prompt.
+
+use crate::{error::CustomUserError, validator::ErrorMessage};
+
+/// Error of a parser, converted into the [`ErrorMessage`] displayed to the user.
+///
+/// Implemented for [`ErrorMessage`], [`String`], `&str` and [`CustomUserError`],
+/// whose messages are displayed as is, and for `()`, which displays the default
+/// error message of the prompt and keeps parsers returning empty errors working.
+pub trait IntoErrorMessage {
+    /// Converts the parse error into the message displayed to the user.
+    fn into_error_message(self) -> ErrorMessage;
+}
+
+impl IntoErrorMessage for () {
+    fn into_error_message(self) -> ErrorMessage {
+        ErrorMessage::Default
+    }
+}
+
+impl IntoErrorMessage for ErrorMessage {
+    fn into_error_message(self) -> ErrorMessage {
+        self
+    }
+}
+
+impl IntoErrorMessage for String {
+    fn into_error_message(self) -> ErrorMessage {
+        ErrorMessage::Custom(self)
+    }
+}
+
+impl IntoErrorMessage for &str {
+    fn into_error_message(self) -> ErrorMessage {
+        ErrorMessage::Custom(String::from(self))
+    }
+}
+
+impl IntoErrorMessage for CustomUserError {
+    fn into_error_message(self) -> ErrorMessage {
+        ErrorMessage::Custom(self.to_string())
+    }
+}
+
+/// Parser of user inputs, used in [Confirm](crate::Confirm) and
+/// [`CustomType`](crate::CustomType) prompts.
+///
+/// It is implemented for every function `Fn(&str) -> Result<T, E>` whose error
+/// implements [`IntoErrorMessage`], such as `()` or [`ErrorMessage`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::InputParser;
+/// use inquire::validator::ErrorMessage;
+///
+/// let parser = |input: &str| match input.parse::<u16>() {
+///     Ok(0) => Err("expected a port between 1 and 65535"),
+///     Ok(port) => Ok(port),
+///     Err(_) => Err("not a number"),
+/// };
+/// assert_eq!(Ok(8080), parser.parse_input("8080"));
+/// assert_eq!(
+///     Err(ErrorMessage::Custom("not a number".into())),
+///     parser.parse_input("http"),
+/// );
+///
+/// let legacy = |input: &str| input.parse::<u16>().map_err(|_| ());
+/// assert_eq!(Err(ErrorMessage::Default), legacy.parse_input("http"));
+/// ```
+pub trait InputParser<T> {
+    /// Parses the user input, returning the message to display when it fails.
+    fn parse_input(&self, input: &str) -> Result<T, ErrorMessage>;
+}
+
+impl<T, E, F> InputParser<T> for F
+where
+    F: Fn(&str) -> Result<T, E>,
+    E: IntoErrorMessage,
+{
+    fn parse_input(&self, input: &str) -> Result<T, ErrorMessage> {
+        (self)(input).map_err(IntoErrorMessage::into_error_message)
+    }
+}
 
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
@@ -52,6 +140,103 @@ pub type BoolParser<'a> = &'a dyn Fn(&str) -> Result<bool, ()>;
 /// ```
 pub type CustomTypeParser<'a, T> = &'a dyn Fn(&str) -> Result<T, ()>;
 
+/// Type alias for rich parsers used in [Confirm](crate::Confirm) prompts.
+///
+/// [`RichBoolParser`]s return either a successful result ([Ok]) containing the
+/// parsed `bool` or the error displayed to the user. See [`InputParser`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{InputParser, RichBoolParser};
+/// use inquire::validator::ErrorMessage;
+///
+/// let parser: RichBoolParser = &|ans: &str| match ans {
+///     "si" => Ok(true),
+///     "no" => Ok(false),
+///     "sim" => Err("try typing 'si' instead"),
+///     _ => Err("not a valid answer"),
+/// };
+/// assert_eq!(Ok(true), parser.parse_input("si"));
+/// assert_eq!(Ok(false), parser.parse_input("no"));
+/// assert_eq!(Err(ErrorMessage::Custom("try typing 'si' instead".into())), parser.parse_input("sim"));
+/// assert_eq!(Err(ErrorMessage::Custom("not a valid answer".into())), parser.parse_input("não"));
+/// ```
+pub type RichBoolParser<'a> = &'a dyn InputParser<bool>;
+
+/// Type alias for rich parsers used in [`CustomType`](crate::CustomType) prompts.
+///
+/// [`RichCustomTypeParser`]s return either a successful result ([Ok]) containing the
+/// parsed value or the error displayed to the user. See [`InputParser`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{InputParser, RichCustomTypeParser};
+/// use inquire::validator::ErrorMessage;
+///
+/// let parser: RichCustomTypeParser<u16> = &|val: &str| match val.parse::<u16>() {
+///     Ok(0) => Err(ErrorMessage::Custom("expected a port between 1 and 65535".into())),
+///     Ok(port) => Ok(port),
+///     Err(e) => Err(ErrorMessage::Custom(e.to_string())),
+/// };
+/// assert_eq!(Ok(8080), parser.parse_input("8080"));
+/// assert_eq!(
+///     Err(ErrorMessage::Custom("expected a port between 1 and 65535".into())),
+///     parser.parse_input("0"),
+/// );
+/// ```
+pub type RichCustomTypeParser<'a, T> = &'a dyn InputParser<T>;
+
+/// Type alias for parsers of dates typed in [DateSelect](crate::DateSelect) prompts.
+///
+/// [`DateInputParser`]s receive the user input and the current date, for relative
+/// inputs, and return either a successful result ([Ok]) containing the parsed date
+/// or an empty [Err] if a date could not be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::parser::DateInputParser;
+///
+/// let parser: DateInputParser = &|input, _today| {
+///     NaiveDate::parse_from_str(input, "%d/%m/%Y").map_err(|_| ())
+/// };
+/// let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+/// assert_eq!(NaiveDate::from_ymd_opt(2019, 3, 14).ok_or(()), parser("14/03/2019", today));
+/// assert_eq!(Err(()), parser("2019-03-14", today));
+/// ```
+#[cfg(feature = "date")]
+pub type DateInputParser<'a> = &'a dyn Fn(&str, chrono::NaiveDate) -> Result<chrono::NaiveDate, ()>;
+
+/// Date parser used by default in [DateSelect](crate::DateSelect) prompts
+/// with typed input enabled.
+///
+/// Accepts, case-insensitively, `today`, `tomorrow` and `yesterday`, ISO dates
+/// such as `2019-03-14`, offsets from the current date in days, weeks, months
+/// or years such as `+3d`, `-2w`, `+1m` or `-10y`, and weekdays such as `fri`,
+/// `next friday` or `last monday`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::parser::DEFAULT_DATE_INPUT_PARSER;
+///
+/// let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap(); // Wednesday
+/// let date = |d| NaiveDate::from_ymd_opt(2023, 3, d).ok_or(());
+///
+/// assert_eq!(date(16), DEFAULT_DATE_INPUT_PARSER("tomorrow", today));
+/// assert_eq!(date(18), DEFAULT_DATE_INPUT_PARSER("+3d", today));
+/// assert_eq!(date(17), DEFAULT_DATE_INPUT_PARSER("next friday", today));
+/// assert_eq!(date(3), DEFAULT_DATE_INPUT_PARSER("2023-03-03", today));
+/// assert_eq!(Err(()), DEFAULT_DATE_INPUT_PARSER("someday", today));
+/// ```
+#[cfg(feature = "date")]
+pub const DEFAULT_DATE_INPUT_PARSER: DateInputParser<'_> =
+    &|input, today| crate::date_utils::parse_date_input(input, today).ok_or(());
+
 /// Bool formatter used  by default in [Confirm](crate::Confirm) prompts.
 pub const DEFAULT_BOOL_PARSER: BoolParser<'_> = &|ans| {
     if ans.len() > 3 {
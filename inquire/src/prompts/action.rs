@@ -35,9 +35,13 @@ where
     where
         I: InnerAction<Config = C>,
     {
+        if let Some(action) = I::from_key_override(key, config) {
+            return Some(action);
+        }
+
         match key {
             Key::Enter
-            | Key::Char('\n', KeyModifiers::NONE)
+            | Key::Char('\n', KeyModifiers::NONE | KeyModifiers::ALT)
             | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Submit),
             Key::Escape | Key::Char('g' | 'd', KeyModifiers::CONTROL) => Some(Action::Cancel),
             Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
@@ -63,6 +67,16 @@ where
     fn from_key(key: Key, config: &Self::Config) -> Option<Self>
     where
         Self: Sized;
+
+    /// Derives a prompt action from a Key event before the standard keybindings
+    /// for submitting, cancelling and interrupting are considered.
+    ///
+    /// Prompts that need to rebind one of these keys, e.g. using Enter to insert
+    /// a new line instead of submitting, can override this method. By default,
+    /// no key is overridden.
+    fn from_key_override(_key: Key, _config: &Self::Config) -> Option<Action<Self>> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +151,51 @@ mod test {
         );
     }
 
+    #[test]
+    fn alt_enter_submits_by_default() {
+        assert_eq!(
+            Some(Action::<MockInnerAction>::Submit),
+            Action::from_key(Key::Char('\n', KeyModifiers::ALT), &())
+        );
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum MockOverridingAction {
+        NewLine,
+    }
+
+    impl InnerAction for MockOverridingAction {
+        type Config = ();
+
+        fn from_key(_key: Key, _config: &()) -> Option<Self> {
+            None
+        }
+
+        fn from_key_override(key: Key, _config: &()) -> Option<Action<Self>> {
+            match key {
+                Key::Enter => Some(Action::Inner(Self::NewLine)),
+                Key::Char('d', KeyModifiers::CONTROL) => Some(Action::Submit),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn overridden_keys_take_precedence_over_standard_keybindings() {
+        assert_eq!(
+            Some(Action::Inner(MockOverridingAction::NewLine)),
+            Action::from_key(Key::Enter, &())
+        );
+        assert_eq!(
+            Some(Action::<MockOverridingAction>::Submit),
+            Action::from_key(Key::Char('d', KeyModifiers::CONTROL), &())
+        );
+        assert_eq!(
+            Some(Action::<MockOverridingAction>::Cancel),
+            Action::from_key(Key::Escape, &())
+        );
+    }
+
     #[test]
     fn emacs_control_keybindings() {
         assert_eq!(
@@ -0,0 +1,245 @@
+//! Validation of submitted text answers on a background thread, while the prompt
+//! keeps handling keys and animating a spinner.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::CustomUserError,
+    validator::{validate_all, BackgroundStringValidator, Validation},
+};
+
+/// Time between two frames of the spinner, also used to poll for the result.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+
+type ValidationResult = Result<Validation, CustomUserError>;
+
+/// Answer sent to the worker thread, tagged with the generation it was submitted in.
+struct Request {
+    generation: u64,
+    answer: String,
+}
+
+/// Result of the validation of the answer submitted in `generation`.
+struct Response {
+    generation: u64,
+    result: ValidationResult,
+}
+
+/// Thread running the validators, one answer at a time.
+struct Worker {
+    requests: Sender<Request>,
+    responses: Receiver<Response>,
+    handle: JoinHandle<()>,
+}
+
+impl Worker {
+    fn spawn(
+        validators: Vec<Arc<dyn BackgroundStringValidator>>,
+        generation: Arc<AtomicU64>,
+    ) -> Self {
+        let (requests, request_receiver) = mpsc::channel::<Request>();
+        let (response_sender, responses) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            while let Ok(mut request) = request_receiver.recv() {
+                // Answers submitted while the previous one was validated are stale,
+                // except for the last one.
+                while let Ok(next) = request_receiver.try_recv() {
+                    request = next;
+                }
+
+                // The answer changed since it was submitted, nobody waits for it.
+                if request.generation != generation.load(Ordering::Acquire) {
+                    continue;
+                }
+
+                let result = validate_all(validators.iter().map(|v| v.validate(&request.answer)));
+                let response = Response {
+                    generation: request.generation,
+                    result,
+                };
+
+                if response_sender.send(response).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            requests,
+            responses,
+            handle,
+        }
+    }
+}
+
+/// Validation waiting for the worker thread.
+struct Pending {
+    answer: String,
+    generation: u64,
+    started_at: Instant,
+}
+
+/// Runs the background validators of a prompt on submitted answers and keeps the
+/// result of the last one.
+///
+/// At most one validation runs at a time, on a single worker thread spawned on the
+/// first submission. Every change of the answer starts a new generation, which
+/// cancels the validations of older answers that did not start yet and discards
+/// the results of the one that is running, as validators can not be interrupted.
+pub(crate) struct BackgroundValidator {
+    validators: Vec<Arc<dyn BackgroundStringValidator>>,
+    worker: Option<Worker>,
+    generation: Arc<AtomicU64>,
+    pending: Option<Pending>,
+    /// Last validated answer and its result, reused while the answer stays the same.
+    resolved: Option<(String, Validation)>,
+    /// Whether answers are validated on the calling thread, for backends that can
+    /// not wait for the result without blocking on the next key.
+    foreground: bool,
+}
+
+impl BackgroundValidator {
+    pub fn new(validators: Vec<Arc<dyn BackgroundStringValidator>>) -> Self {
+        Self {
+            validators,
+            worker: None,
+            generation: Arc::new(AtomicU64::new(0)),
+            pending: None,
+            resolved: None,
+            foreground: false,
+        }
+    }
+
+    /// Validates answers on the calling thread from now on.
+    pub fn run_in_foreground(&mut self) {
+        self.foreground = true;
+    }
+
+    /// Returns the result of the validation of the answer if it already resolved.
+    /// Otherwise, starts validating it in the background, unless it is already
+    /// being validated, or validates it right away when running in the foreground.
+    pub fn validate(&mut self, answer: &str) -> Result<Option<Validation>, CustomUserError> {
+        if let Some((resolved, validation)) = &self.resolved {
+            if resolved == answer {
+                return Ok(Some(validation.clone()));
+            }
+        }
+
+        if self.foreground {
+            let validation = validate_all(self.validators.iter().map(|v| v.validate(answer)))?;
+            self.resolved = Some((answer.to_owned(), validation.clone()));
+            return Ok(Some(validation));
+        }
+
+        if !matches!(&self.pending, Some(pending) if pending.answer == answer) {
+            self.start(answer)?;
+        }
+
+        Ok(None)
+    }
+
+    fn start(&mut self, answer: &str) -> Result<(), CustomUserError> {
+        let generation = self.generation.fetch_add(1, Ordering::AcqRel) + 1;
+
+        let (validators, current) = (&self.validators, &self.generation);
+        let worker = self
+            .worker
+            .get_or_insert_with(|| Worker::spawn(validators.clone(), current.clone()));
+
+        let request = Request {
+            generation,
+            answer: answer.to_owned(),
+        };
+        if worker.requests.send(request).is_err() {
+            return Err(stopped_unexpectedly());
+        }
+
+        self.pending = Some(Pending {
+            answer: answer.to_owned(),
+            generation,
+            started_at: Instant::now(),
+        });
+
+        Ok(())
+    }
+
+    /// Checks whether the pending validation resolved, returning `true` if the
+    /// answer can be submitted again to apply the result.
+    pub fn poll(&mut self) -> Result<bool, CustomUserError> {
+        let (pending, worker) = match (&self.pending, &self.worker) {
+            (Some(pending), Some(worker)) => (pending, worker),
+            _ => return Ok(false),
+        };
+
+        let result = loop {
+            match worker.responses.try_recv() {
+                Ok(response) if response.generation == pending.generation => break response.result,
+                // Result of an answer that changed in the meantime.
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => return Ok(false),
+                Err(TryRecvError::Disconnected) => break Err(stopped_unexpectedly()),
+            }
+        };
+
+        if let Some(pending) = self.pending.take() {
+            self.resolved = Some((pending.answer, result?));
+        }
+
+        Ok(true)
+    }
+
+    /// Discards the pending validation and the last result, as the answer changed.
+    pub fn answer_changed(&mut self) {
+        if self.pending.take().is_some() {
+            self.generation.fetch_add(1, Ordering::AcqRel);
+        }
+        self.resolved = None;
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// How long to wait before polling the pending validation, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.pending.as_ref().map(|_| SPINNER_INTERVAL)
+    }
+
+    /// Frame of the spinner to display while a validation is pending.
+    pub fn spinner_frame(&self) -> Option<usize> {
+        self.pending.as_ref().map(|pending| {
+            (pending.started_at.elapsed().as_millis() / SPINNER_INTERVAL.as_millis()) as usize
+        })
+    }
+}
+
+impl Drop for BackgroundValidator {
+    fn drop(&mut self) {
+        // Cancels the validations that did not start yet.
+        self.generation.fetch_add(1, Ordering::AcqRel);
+
+        if let Some(worker) = self.worker.take() {
+            // Closing the channel stops the worker once the running validation returns.
+            drop(worker.requests);
+
+            // The worker is detached instead of joined, as waiting for a slow validator
+            // would keep the prompt from returning, e.g. when it is canceled.
+            if worker.handle.is_finished() {
+                let _ = worker.handle.join();
+            }
+        }
+    }
+}
+
+fn stopped_unexpectedly() -> CustomUserError {
+    "The background validator stopped unexpectedly".into()
+}
@@ -1,4 +1,27 @@
-use crate::CustomTypePromptAction;
+use crate::{
+    ui::{Key, KeyModifiers},
+    CustomTypePromptAction, InnerAction,
+};
+
+use super::config::InstantConfirmConfig;
 
 /// Set of actions for a ConfirmPrompt.
 pub type ConfirmPromptAction = CustomTypePromptAction;
+
+/// Set of actions for a ConfirmPrompt in instant mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InstantConfirmPromptAction {
+    /// Answers the prompt with the value parsed from the pressed key.
+    Answer(char),
+}
+
+impl InnerAction for InstantConfirmPromptAction {
+    type Config = InstantConfirmConfig;
+
+    fn from_key(key: Key, _config: &InstantConfirmConfig) -> Option<Self> {
+        match key {
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) => Some(Self::Answer(c)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Confirm;
+
+/// Configuration settings used in the execution of a ConfirmPrompt in instant mode.
+pub struct InstantConfirmConfig {}
+
+impl From<&Confirm<'_>> for InstantConfirmConfig {
+    fn from(_value: &Confirm<'_>) -> Self {
+        Self {}
+    }
+}
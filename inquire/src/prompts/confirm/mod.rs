@@ -1,19 +1,55 @@
 mod action;
+mod config;
+mod prompt;
 #[cfg(test)]
 mod test;
 
 pub use action::*;
 
+use std::fmt::Display;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
-    parser::{BoolParser, DEFAULT_BOOL_PARSER},
+    parser::{BoolParser, IntoErrorMessage, RichBoolParser, DEFAULT_BOOL_PARSER},
+    prompts::prompt::Prompt,
     terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
-    CustomType,
+    ui::{Backend, CustomTypeBackend, ExpandBackend, RenderConfig},
+    CustomType, Expand, ExpandChoice,
 };
 
+use self::prompt::InstantConfirmPrompt;
+
+/// Answer of a [`Confirm`] prompt that also offers to apply a "yes" to all the
+/// following questions, returned by [`Confirm::prompt_tristate`].
+///
+/// Besides the three answers, pressing ESC is returned as [`Cancel`](Self::Cancel)
+/// instead of an [`OperationCanceled`](crate::InquireError::OperationCanceled) error,
+/// so that a loop over several questions can tell it apart from the other errors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TriState {
+    /// The user answered yes.
+    Yes,
+    /// The user answered no.
+    No,
+    /// The user answered yes, for this question and all the following ones.
+    All,
+    /// The user canceled the prompt by pressing ESC.
+    Cancel,
+}
+
+impl Display for TriState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Yes => write!(f, "Yes"),
+            Self::No => write!(f, "No"),
+            Self::All => write!(f, "Yes to all"),
+            Self::Cancel => write!(f, "Canceled"),
+        }
+    }
+}
+
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
 ///
 /// This prompt is basically a wrapper around the behavior of `CustomType` prompts, providing a sensible set of defaults to ask for simple `true/false` questions, such as confirming an action.
@@ -35,10 +71,16 @@ use crate::{
 ///   - Formats `true` to "Yes" and `false` to "No", by default.
 /// - **Parser**: Custom parser for user inputs.
 ///   - The default `bool` parser returns `true` if the input is either `"y"` or `"yes"`, in a case-insensitive comparison. Similarly, the parser returns `false` if the input is either `"n"` or `"no"`.
+///   - Parsers set with `with_rich_parser` can return the error message displayed to the user.
 /// - **Default value formatter**: Function that formats how the default value is displayed to the user.
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
 /// - **Error message**: Error message to display when a value could not be parsed from the input.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Instant mode**: Whether the prompt is answered as soon as a key is pressed, without enter.
+///   - The pressed key is parsed with the parser, and enter submits the default value, if any.
+///
+/// To also offer a "yes to all" answer, prompt with [`prompt_tristate`](Self::prompt_tristate).
+/// For other sets of lettered choices, see [`Expand`].
 ///
 /// # Example
 ///
@@ -85,12 +127,23 @@ pub struct Confirm<'a> {
     /// Function that parses the user input and returns the result value.
     pub parser: BoolParser<'a>,
 
+    /// Parser that returns the error message displayed when the user input can not be parsed.
+    ///
+    /// When set, it is used instead of the [`parser`](Self::parser).
+    pub rich_parser: Option<RichBoolParser<'a>>,
+
     /// Function that formats the default value to be presented to the user
     pub default_value_formatter: BoolFormatter<'a>,
 
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Whether the prompt is answered as soon as a key is pressed, without waiting for enter.
+    ///
+    /// The pressed key is parsed with the [`parser`](Self::parser), and enter submits
+    /// the default value, if any.
+    pub instant: bool,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -130,8 +183,10 @@ impl<'a> Confirm<'a> {
             help_message: None,
             formatter: Self::DEFAULT_FORMATTER,
             parser: Self::DEFAULT_PARSER,
+            rich_parser: None,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            instant: false,
             render_config: get_configuration(),
         }
     }
@@ -173,6 +228,22 @@ impl<'a> Confirm<'a> {
     /// Sets the parser.
     pub fn with_parser(mut self, parser: BoolParser<'a>) -> Self {
         self.parser = parser;
+        self.rich_parser = None;
+        self
+    }
+
+    /// Sets a parser that returns the error displayed to the user, such as an
+    /// [`ErrorMessage`](crate::validator::ErrorMessage) or a `&str`, replacing the
+    /// [`parser`](Self::parser).
+    ///
+    /// Parsers returning an empty `()` error or [`ErrorMessage::Default`](crate::validator::ErrorMessage::Default)
+    /// display the [`error_message`](Self::error_message) of the prompt.
+    pub fn with_rich_parser<F, E>(mut self, parser: &'a F) -> Self
+    where
+        F: Fn(&str) -> Result<bool, E> + 'a,
+        E: IntoErrorMessage,
+    {
+        self.rich_parser = Some(parser);
         self
     }
 
@@ -188,6 +259,13 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets whether the prompt is answered as soon as a key is pressed, as in `y` or `n`,
+    /// without waiting for enter.
+    pub fn with_instant(mut self, instant: bool) -> Self {
+        self.instant = instant;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -226,11 +304,57 @@ impl<'a> Confirm<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Prompts the CLI user for a yes, a no or a yes to all the following questions,
+    /// answered as soon as `y`, `n` or `a` is pressed. Pressing `?` lists the choices.
+    ///
+    /// The message, default value, help message and render config of the prompt are kept,
+    /// while the formatter, parser and error message are not used.
+    ///
+    /// Pressing ESC returns [`TriState::Cancel`] instead of an
+    /// [`OperationCanceled`](InquireError::OperationCanceled) error, while Ctrl-C still
+    /// interrupts the prompt with an [`OperationInterrupted`](InquireError::OperationInterrupted) error.
+    pub fn prompt_tristate(self) -> InquireResult<TriState> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_tristate_with_backend(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<bool> {
-        CustomType::from(self).prompt_with_backend(backend)
+        match self.instant {
+            true => InstantConfirmPrompt::from(self).prompt(backend),
+            false => CustomType::from(self).prompt_with_backend(backend),
+        }
+    }
+
+    pub(crate) fn prompt_tristate_with_backend<B: ExpandBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<TriState> {
+        let choices = vec![
+            ExpandChoice::new('y', "Yes", TriState::Yes),
+            ExpandChoice::new('n', "No", TriState::No),
+            ExpandChoice::new('a', "Yes to all", TriState::All),
+        ];
+
+        let expand = Expand {
+            message: self.message,
+            choices,
+            default: self.default.map(|default| match default {
+                true => 'y',
+                false => 'n',
+            }),
+            help_message: self.help_message,
+            error_message: String::from(Expand::<TriState>::DEFAULT_ERROR_MESSAGE),
+            render_config: self.render_config,
+        };
+
+        match expand.prompt_with_backend(backend) {
+            Err(InquireError::OperationCanceled) => Ok(TriState::Cancel),
+            result => result,
+        }
     }
 }
 
@@ -251,7 +375,9 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             help_message: co.help_message,
             formatter: co.formatter,
             parser: co.parser,
+            rich_parser: co.rich_parser,
             validators: vec![],
+            live_validation: None,
             error_message: co.error_message,
             render_config: co.render_config,
         }
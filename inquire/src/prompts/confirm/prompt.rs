@@ -0,0 +1,121 @@
+use crate::{
+    error::InquireResult,
+    formatter::BoolFormatter,
+    input::Input,
+    parser::{BoolParser, InputParser, RichBoolParser},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::ErrorMessage,
+    Confirm,
+};
+
+use super::{action::InstantConfirmPromptAction, config::InstantConfirmConfig};
+
+/// Confirm prompt answered as soon as a key is pressed.
+pub struct InstantConfirmPrompt<'a> {
+    message: &'a str,
+    config: InstantConfirmConfig,
+    default: Option<bool>,
+    help_message: Option<&'a str>,
+    formatter: BoolFormatter<'a>,
+    default_value_formatter: BoolFormatter<'a>,
+    parser: BoolParser<'a>,
+    rich_parser: Option<RichBoolParser<'a>>,
+    error_message: String,
+    error: Option<ErrorMessage>,
+    answer: Option<bool>,
+    /// Always empty, rendered for its placeholder and the position of the cursor.
+    input: Input,
+}
+
+impl<'a> From<Confirm<'a>> for InstantConfirmPrompt<'a> {
+    fn from(co: Confirm<'a>) -> Self {
+        let input = match co.placeholder {
+            Some(placeholder) => Input::new().with_placeholder(placeholder),
+            None => Input::new(),
+        };
+
+        Self {
+            message: co.message,
+            config: (&co).into(),
+            default: co.default,
+            help_message: co.help_message,
+            formatter: co.formatter,
+            default_value_formatter: co.default_value_formatter,
+            parser: co.parser,
+            rich_parser: co.rich_parser,
+            error_message: co.error_message,
+            error: None,
+            answer: None,
+            input,
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for InstantConfirmPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = InstantConfirmConfig;
+    type InnerAction = InstantConfirmPromptAction;
+    type Output = bool;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &InstantConfirmConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<bool>> {
+        let answer = self.answer.or(self.default);
+
+        if answer.is_none() {
+            self.error = Some(ErrorMessage::Custom(self.error_message.clone()));
+        }
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: InstantConfirmPromptAction) -> InquireResult<ActionResult> {
+        let InstantConfirmPromptAction::Answer(key) = action;
+
+        let input = key.to_string();
+        let parsed = match self.rich_parser {
+            Some(parser) => parser.parse_input(&input),
+            None => self.parser.parse_input(&input),
+        };
+
+        let message = match parsed {
+            Ok(answer) => {
+                self.answer = Some(answer);
+                return Ok(ActionResult::Submit);
+            }
+            Err(ErrorMessage::Default) => self.error_message.clone(),
+            Err(ErrorMessage::Custom(msg)) => msg,
+        };
+
+        self.error = Some(ErrorMessage::Custom(message));
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(self.default_value_formatter);
+        backend.render_prompt(self.message, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -8,7 +8,8 @@ use crate::{
         test::{FakeBackend, Token},
         Key, KeyModifiers,
     },
-    Confirm, InquireError,
+    validator::ErrorMessage,
+    Confirm, InquireError, TriState,
 };
 
 #[test]
@@ -548,3 +549,203 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[case('y', true)]
+#[case('Y', true)]
+#[case('n', false)]
+#[case('N', false)]
+fn instant_mode_answers_on_keypress(
+    #[case] key: char,
+    #[case] expected_result: bool,
+) -> InquireResult<()> {
+    // no enter: the key alone answers the prompt.
+    let mut backend = FakeBackend::new(vec![Key::Char(key, KeyModifiers::NONE)]);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(expected_result, result, "Answer was not the expected one");
+
+    let final_frame = backend.frames().last().unwrap();
+    let formatted = if expected_result { "Yes" } else { "No" };
+    assert!(final_frame.has_token(&Token::AnsweredPrompt("Question".into(), formatted.into())));
+
+    Ok(())
+}
+
+#[test]
+fn instant_mode_rejects_unparsable_keys() -> InquireResult<()> {
+    let keys = vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+    ];
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .prompt_with_backend(&mut backend)?;
+    assert!(!result, "Answer was not the expected one");
+
+    let error = ErrorMessage::Custom(Confirm::DEFAULT_ERROR_MESSAGE.into());
+    assert!(backend.frames()[1].has_token(&Token::ErrorMessage(error)));
+
+    Ok(())
+}
+
+#[test]
+fn instant_mode_uses_the_custom_parser() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('s', KeyModifiers::NONE)]);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .with_parser(&|ans| match ans {
+            "s" => Ok(true),
+            "n" => Ok(false),
+            _ => Err(()),
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the expected one");
+
+    Ok(())
+}
+
+#[test]
+fn with_parser_replaces_the_rich_parser() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('y', KeyModifiers::NONE), Key::Enter]);
+
+    let result = Confirm::new("Question")
+        .with_rich_parser(&|_| Err("Not an answer"))
+        .with_parser(Confirm::DEFAULT_PARSER)
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the expected one");
+
+    Ok(())
+}
+
+#[test]
+fn rich_parser_error_message_is_displayed() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char('i', KeyModifiers::NONE),
+        Key::Char('m', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let result = Confirm::new("Question")
+        .with_rich_parser(&|ans| match ans {
+            "si" => Ok(true),
+            "no" => Ok(false),
+            "sim" => Err("Try 'si' instead"),
+            _ => Err("Not an answer"),
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the expected one");
+
+    let error = ErrorMessage::Custom("Try 'si' instead".into());
+    assert!(backend.frames()[4].has_token(&Token::ErrorMessage(error)));
+
+    Ok(())
+}
+
+#[test]
+fn instant_mode_displays_rich_parser_error_message() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+    ]);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .with_rich_parser(&|ans| match ans {
+            "s" => Ok(true),
+            "n" => Ok(false),
+            _ => Err(ErrorMessage::Custom(format!("'{}' is not an answer", ans))),
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert!(!result, "Answer was not the expected one");
+
+    let error = ErrorMessage::Custom("'x' is not an answer".into());
+    assert!(backend.frames()[1].has_token(&Token::ErrorMessage(error)));
+
+    Ok(())
+}
+
+#[test]
+fn instant_mode_submits_default_on_enter() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Enter]);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .with_default(false)
+        .prompt_with_backend(&mut backend)?;
+    assert!(!result, "Answer was not the expected one");
+
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Char('y', KeyModifiers::NONE)]);
+
+    let result = Confirm::new("Question")
+        .with_instant(true)
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the expected one");
+    assert_eq!(3, backend.frames().len(), "Enter should have been rejected");
+
+    Ok(())
+}
+
+#[rstest]
+#[case('y', TriState::Yes)]
+#[case('n', TriState::No)]
+#[case('A', TriState::All)]
+fn tristate_answers_on_keypress(
+    #[case] key: char,
+    #[case] expected_result: TriState,
+) -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char(key, KeyModifiers::NONE)]);
+
+    let result = Confirm::new("Overwrite?").prompt_tristate_with_backend(&mut backend)?;
+    assert_eq!(expected_result, result, "Answer was not the expected one");
+
+    let final_frame = backend.frames().last().unwrap();
+    assert!(final_frame.has_token(&Token::AnsweredPrompt(
+        "Overwrite?".into(),
+        expected_result.to_string()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn tristate_escape_returns_cancel() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Escape]);
+
+    let result = Confirm::new("Overwrite?").prompt_tristate_with_backend(&mut backend)?;
+    assert_eq!(TriState::Cancel, result, "Answer was not the expected one");
+
+    let final_frame = backend.frames().last().unwrap();
+    assert!(final_frame.has_token(&Token::CanceledPrompt("Overwrite?".into())));
+
+    Ok(())
+}
+
+#[test]
+fn tristate_ctrl_c_interrupts_prompt() {
+    let mut backend = FakeBackend::new(vec![Key::Char('c', KeyModifiers::CONTROL)]);
+
+    let result = Confirm::new("Overwrite?").prompt_tristate_with_backend(&mut backend);
+    assert!(matches!(result, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn tristate_displays_the_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = Confirm::new("Overwrite?")
+        .with_default(false)
+        .prompt_tristate_with_backend(&mut backend)?;
+    assert_eq!(TriState::No, result, "Answer was not the expected one");
+    assert!(backend.frames()[0].has_token(&Token::ExpandKeys("y,N,a,?".into())));
+
+    Ok(())
+}
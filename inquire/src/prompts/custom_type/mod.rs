@@ -1,6 +1,8 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(test)]
+mod test;
 
 pub use action::*;
 
@@ -10,11 +12,11 @@ use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
-    parser::CustomTypeParser,
+    parser::{CustomTypeParser, IntoErrorMessage, RichCustomTypeParser},
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
     ui::{Backend, CustomTypeBackend, RenderConfig},
-    validator::CustomTypeValidator,
+    validator::{CustomTypeValidator, LiveValidation},
 };
 
 use self::prompt::CustomTypePrompt;
@@ -31,7 +33,7 @@ use self::prompt::CustomTypePrompt;
 ///
 /// The default parser calls the [`str.parse`](https://doc.rust-lang.org/stable/std/primitive.str.html#method.parse) method, which means that `T` must implement the `FromStr` trait. When the parsing fails for any reason, a default error message "Invalid input" is displayed to the user.
 ///
-/// After the user submits, the prompt handler tries to parse the input into the expected type. If the operation succeeds, the value is returned to the prompt caller. If it fails, the message defined in `error_message` is displayed to the user.
+/// After the user submits, the prompt handler tries to parse the input into the expected type. If the operation succeeds, the value is returned to the prompt caller. If it fails, the message defined in `error_message` is displayed to the user, unless the prompt has a rich parser, set with `with_rich_parser`, returning the error message to display.
 ///
 /// The default formatter simply calls `to_string()` on the parsed value, which means that `T` must implement the `ToString` trait, which normally happens implicitly when you implement the `Display` trait.
 ///
@@ -47,6 +49,7 @@ use self::prompt::CustomTypePrompt;
 ///     default_value_formatter: &|i| format!("${:.2}", i),
 ///     default: None,
 ///     validators: vec![],
+///     live_validation: None,
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
@@ -54,6 +57,7 @@ use self::prompt::CustomTypePrompt;
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     rich_parser: None,
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -106,6 +110,11 @@ pub struct CustomType<'a, T> {
     /// Function that parses the user input and returns the result value.
     pub parser: CustomTypeParser<'a, T>,
 
+    /// Parser that returns the error message displayed when the user input can not be parsed.
+    ///
+    /// When set, it is used instead of the [`parser`](Self::parser).
+    pub rich_parser: Option<RichCustomTypeParser<'a, T>>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -114,6 +123,11 @@ pub struct CustomType<'a, T> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
 
+    /// Options of live validation, in which the input is parsed and validated as the user types.
+    ///
+    /// Disabled by default.
+    pub live_validation: Option<LiveValidation>,
+
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
@@ -149,7 +163,9 @@ where
             formatter: &|val| val.to_string(),
             default_value_formatter: &|val| val.to_string(),
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
+            rich_parser: None,
             validators: Self::DEFAULT_VALIDATORS,
+            live_validation: None,
             error_message: "Invalid input".into(),
             render_config: get_configuration(),
         }
@@ -201,6 +217,36 @@ where
     /// Sets the parser.
     pub fn with_parser(mut self, parser: CustomTypeParser<'a, T>) -> Self {
         self.parser = parser;
+        self.rich_parser = None;
+        self
+    }
+
+    /// Sets a parser that returns the error displayed to the user, such as an
+    /// [`ErrorMessage`](crate::validator::ErrorMessage) or a `&str`, replacing the
+    /// [`parser`](Self::parser).
+    ///
+    /// Parsers returning an empty `()` error or [`ErrorMessage::Default`](crate::validator::ErrorMessage::Default)
+    /// display the [`error_message`](Self::error_message) of the prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let port = CustomType::<u16>::new("Port:")
+    ///     .with_rich_parser(&|i| match i.parse::<u16>() {
+    ///         Ok(0) => Err("Expected a port between 1 and 65535"),
+    ///         Ok(port) => Ok(port),
+    ///         Err(_) => Err("Not a number"),
+    ///     })
+    ///     .prompt();
+    /// ```
+    pub fn with_rich_parser<F, E>(mut self, parser: &'a F) -> Self
+    where
+        F: Fn(&str) -> Result<T, E> + 'a,
+        E: IntoErrorMessage,
+    {
+        self.rich_parser = Some(parser);
         self
     }
 
@@ -241,6 +287,13 @@ where
         self
     }
 
+    /// Enables live validation: the input is parsed and validated on every change, or once
+    /// it stops changing for the configured debounce time, instead of only on submit.
+    pub fn with_live_validation(mut self, live_validation: LiveValidation) -> Self {
+        self.live_validation = Some(live_validation);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -1,11 +1,16 @@
+use std::time::Duration;
+
 use crate::{
     error::InquireResult,
     formatter::CustomTypeFormatter,
-    input::Input,
-    parser::CustomTypeParser,
-    prompts::prompt::{ActionResult, Prompt},
+    input::{Input, InputActionResult},
+    parser::{CustomTypeParser, InputParser, RichCustomTypeParser},
+    prompts::{
+        live_validation::LiveValidator,
+        prompt::{ActionResult, Prompt},
+    },
     ui::CustomTypeBackend,
-    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    validator::{validate_all, CustomTypeValidator, ErrorMessage, Validation},
     CustomType, InquireError,
 };
 
@@ -15,6 +20,7 @@ pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
     config: CustomTypeConfig,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
     help_message: Option<&'a str>,
     default: Option<T>,
     input: Input,
@@ -22,6 +28,8 @@ pub struct CustomTypePrompt<'a, T> {
     default_value_formatter: CustomTypeFormatter<'a, T>,
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
+    rich_parser: Option<RichCustomTypeParser<'a, T>>,
+    live_validator: Option<LiveValidator>,
     error_message: String,
 }
 
@@ -41,12 +49,15 @@ where
             message: co.message,
             config: (&co).into(),
             error: None,
+            warning: None,
             default: co.default,
             help_message: co.help_message,
             formatter: co.formatter,
             default_value_formatter: co.default_value_formatter,
             validators: co.validators,
             parser: co.parser,
+            rich_parser: co.rich_parser,
+            live_validator: co.live_validation.map(LiveValidator::new),
             input,
             error_message: co.error_message,
         }
@@ -58,15 +69,8 @@ where
     T: Clone,
 {
     fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(value) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
-            }
-        }
-
-        Ok(Validation::Valid)
+        validate_all(self.validators.iter().map(|v| v.validate(value)))
+            .map_err(InquireError::Custom)
     }
 
     fn get_final_answer(&self) -> Result<T, String> {
@@ -75,11 +79,50 @@ where
             _ => {}
         }
 
-        match (self.parser)(self.input.content()) {
+        let parsed = match self.rich_parser {
+            Some(parser) => parser.parse_input(self.input.content()),
+            None => self.parser.parse_input(self.input.content()),
+        };
+
+        match parsed {
             Ok(val) => Ok(val),
-            Err(_) => Err(self.error_message.clone()),
+            Err(ErrorMessage::Default) => Err(self.error_message.clone()),
+            Err(ErrorMessage::Custom(msg)) => Err(msg),
         }
     }
+
+    /// Parses and validates the current input, returning the parsed value if possible.
+    fn validate_input(&self) -> InquireResult<(Option<T>, Validation)> {
+        match self.get_final_answer() {
+            Ok(answer) => {
+                let validation = self.validate_current_answer(&answer)?;
+                Ok((Some(answer), validation))
+            }
+            Err(message) => Ok((None, Validation::Invalid(message.into()))),
+        }
+    }
+
+    fn input_changed(&mut self) -> InquireResult<()> {
+        self.warning = None;
+
+        if let Some(live) = &mut self.live_validator {
+            if live.input_changed() {
+                self.validate_live()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_live(&mut self) -> InquireResult<()> {
+        let (_, validation) = self.validate_input()?;
+
+        if let Some(live) = &mut self.live_validator {
+            (self.error, self.warning) = live.update(validation);
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, Backend, T> Prompt<Backend> for CustomTypePrompt<'a, T>
@@ -103,28 +146,48 @@ where
         (self.formatter)((*answer).clone())
     }
 
+    fn disable_ticks(&mut self) {
+        if let Some(live) = &mut self.live_validator {
+            live.disable_debounce();
+        }
+    }
+
+    fn tick_timeout(&self) -> Option<Duration> {
+        self.live_validator
+            .as_ref()
+            .and_then(LiveValidator::timeout)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        self.validate_live()?;
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<T>> {
-        let answer = match self.get_final_answer() {
-            Ok(answer) => match self.validate_current_answer(&answer)? {
-                Validation::Valid => Some(answer),
-                Validation::Invalid(msg) => {
-                    self.error = Some(msg);
-                    None
-                }
-            },
-            Err(message) => {
-                self.error = Some(message.into());
-                None
-            }
-        };
+        let (answer, validation) = self.validate_input()?;
+
+        if let Some(live) = &mut self.live_validator {
+            live.submit(&validation);
+        }
 
-        Ok(answer)
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(answer);
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
     }
 
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.input_changed()?;
+                }
+
+                result.into()
             }
         };
 
@@ -136,6 +199,10 @@ where
 
         if let Some(error) = &self.error {
             backend.render_error_message(error)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        } else if matches!(&self.live_validator, Some(live) if live.shows_valid()) {
+            backend.render_valid_input_indicator()?;
         }
 
         let default_value_formatter = self.default_value_formatter;
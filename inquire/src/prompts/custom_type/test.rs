@@ -0,0 +1,228 @@
+use std::time::Duration;
+
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, LiveValidation, Validation},
+    CustomType,
+};
+
+fn key(c: char) -> Key {
+    Key::Char(c, KeyModifiers::NONE)
+}
+
+fn port<'a>() -> CustomType<'a, u16> {
+    CustomType::<u16>::new("Port:").with_validator(|port: &u16| match *port {
+        0 => Ok(Validation::Invalid("Port 0 is reserved".into())),
+        _ => Ok(Validation::Valid),
+    })
+}
+
+fn parse_error() -> Token {
+    Token::ErrorMessage(ErrorMessage::Custom("Invalid input".into()))
+}
+
+#[test]
+fn errors_are_only_displayed_on_submit_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        key('x'),
+        Key::Enter,
+        Key::Backspace,
+        key('8'),
+        Key::Enter,
+    ]);
+
+    let port = port().prompt_with_backend(&mut backend)?;
+    assert_eq!(8, port);
+
+    let frames = backend.frames();
+    assert!(!frames[1].has_token(&parse_error()));
+    assert!(frames[2].has_token(&parse_error()));
+    assert!(!frames[4].has_token(&Token::ValidInputIndicator));
+
+    Ok(())
+}
+
+#[test]
+fn live_validation_updates_the_result_as_the_user_types() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        key('0'),
+        Key::Backspace,
+        key('8'),
+        key('x'),
+        Key::Enter,
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let port = port()
+        .with_live_validation(LiveValidation::new())
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(8, port);
+
+    let frames = backend.frames();
+    let reserved = Token::ErrorMessage(ErrorMessage::Custom("Port 0 is reserved".into()));
+    assert!(frames[1].has_token(&reserved));
+    assert!(frames[2].has_token(&parse_error()));
+    assert!(frames[3].has_token(&Token::ValidInputIndicator));
+    assert!(frames[4].has_token(&parse_error()));
+    // Submitting the invalid input is refused.
+    assert!(frames[5].has_token(&parse_error()));
+    assert!(frames[6].has_token(&Token::ValidInputIndicator));
+
+    Ok(())
+}
+
+#[test]
+fn live_results_can_be_held_until_the_first_submit() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        key('x'),
+        Key::Backspace,
+        key('y'),
+        Key::Enter,
+        Key::Backspace,
+        key('8'),
+        Key::Enter,
+    ]);
+
+    let port = port()
+        .with_live_validation(LiveValidation::new().with_only_after_submit(true))
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(8, port);
+
+    let frames = backend.frames();
+    for frame in &frames[1..4] {
+        assert!(!frame.has_token(&parse_error()));
+    }
+    assert!(frames[4].has_token(&parse_error()));
+    assert!(frames[5].has_token(&parse_error()));
+    assert!(frames[6].has_token(&Token::ValidInputIndicator));
+
+    Ok(())
+}
+
+#[test]
+fn debounced_validation_waits_for_the_input_to_settle() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new(vec![key('x'), Key::Backspace, key('8'), Key::Enter]).with_pause_after(1);
+
+    let port = port()
+        .with_live_validation(LiveValidation::new().with_debounce(Duration::from_secs(3600)))
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(8, port);
+
+    let frames = backend.frames();
+    assert!(!frames[1].has_token(&parse_error()));
+    // The pause after the first key triggers the pending validation.
+    assert!(frames[2].has_token(&parse_error()));
+    // Later changes are still pending, keeping the previous result.
+    assert!(frames[3].has_token(&parse_error()));
+    assert!(frames[4].has_token(&parse_error()));
+
+    Ok(())
+}
+
+#[test]
+fn debounce_is_disabled_when_reads_can_not_time_out() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new(vec![key('x'), Key::Backspace, key('8'), Key::Enter]).without_timeouts();
+
+    let port = port()
+        .with_live_validation(LiveValidation::new().with_debounce(Duration::from_secs(3600)))
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(8, port);
+
+    let frames = backend.frames();
+    // Otherwise, the validation would only run once the next key is pressed.
+    assert!(frames[1].has_token(&parse_error()));
+    assert!(frames[3].has_token(&Token::ValidInputIndicator));
+
+    Ok(())
+}
+
+fn well_known_port<'a>() -> CustomType<'a, u16> {
+    CustomType::<u16>::new("Port:").with_validator(|port: &u16| match *port {
+        1..=1023 => Ok(Validation::Warning(
+            "Well-known ports need privileges".into(),
+        )),
+        _ => Ok(Validation::Valid),
+    })
+}
+
+fn well_known_warning() -> Token {
+    Token::WarningMessage(ErrorMessage::Custom(
+        "Well-known ports need privileges".into(),
+    ))
+}
+
+#[test]
+fn warning_is_accepted_on_the_second_submit() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![key('8'), key('0'), Key::Enter, Key::Enter]);
+
+    let port = well_known_port().prompt_with_backend(&mut backend)?;
+    assert_eq!(80, port);
+
+    let frames = backend.frames();
+    assert!(!frames[2].has_token(&well_known_warning()));
+    assert!(frames[3].has_token(&well_known_warning()));
+    assert!(
+        !frames[3].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Well-known ports need privileges".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn changing_the_input_discards_the_displayed_warning() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        key('8'),
+        key('0'),
+        Key::Enter,
+        Key::Backspace,
+        key('1'),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let port = well_known_port().prompt_with_backend(&mut backend)?;
+    assert_eq!(81, port);
+
+    let frames = backend.frames();
+    assert!(frames[3].has_token(&well_known_warning()));
+    assert!(!frames[4].has_token(&well_known_warning()));
+    assert!(!frames[5].has_token(&well_known_warning()));
+    // The new input is refused once to display the warning again.
+    assert!(frames[6].has_token(&well_known_warning()));
+
+    Ok(())
+}
+
+#[test]
+fn rich_parser_error_message_is_displayed() -> InquireResult<()> {
+    let mut keys = vec![key('s'), Key::Enter, Key::Backspace];
+    keys.extend(Key::char_keys_from_str("http"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let port = CustomType::<u16>::new("Port:")
+        .with_rich_parser(&|input| match input {
+            "http" => Ok(80),
+            _ => Err("Unknown service"),
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(80, port);
+
+    let frames = backend.frames();
+    assert!(
+        frames[2].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Unknown service".into()
+        )))
+    );
+
+    Ok(())
+}
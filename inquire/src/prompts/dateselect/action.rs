@@ -1,6 +1,6 @@
 use crate::{
     ui::{Key, KeyModifiers},
-    InnerAction,
+    InnerAction, InputAction,
 };
 
 use super::config::DateSelectConfig;
@@ -25,13 +25,33 @@ pub enum DateSelectPromptAction {
     GoToPrevYear,
     /// Move day cursor to the next year.
     GoToNextYear,
+    /// Toggle the date under the cursor, when selecting multiple dates.
+    ToggleDate,
+    /// Action on the date typed by the user, when typed input is enabled.
+    TextInput(InputAction),
 }
 
 impl InnerAction for DateSelectPromptAction {
     type Config = DateSelectConfig;
 
-    fn from_key(key: Key, _: &DateSelectConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &DateSelectConfig) -> Option<Self> {
+        // While typing a date, plain characters go to the input, which takes
+        // precedence over the vim bindings. Characters typed with a modifier
+        // keep their emacs bindings, and brackets keep navigating months and years.
+        if config.typed_input {
+            if let Key::Char(c, modifiers) = key {
+                let modified = modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::META);
+
+                if !modified && !matches!(c, '[' | ']' | '{' | '}') {
+                    return InputAction::from_key(key, &()).map(Self::TextInput);
+                }
+            }
+        }
+
         let action = match key {
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleDate,
+
             Key::Left(KeyModifiers::NONE) // standard
             | Key::Char('b', KeyModifiers::CONTROL) // emacs
             | Key::Char('h', KeyModifiers::NONE) // vim
@@ -77,6 +97,10 @@ impl InnerAction for DateSelectPromptAction {
             | Key::Down(_) // alternative 2, when the down above with no modifiers is not matched
             => Self::GoToNextYear,
 
+            Key::Char(..) => return None,
+
+            key if config.typed_input => return InputAction::from_key(key, &()).map(Self::TextInput),
+
             _ => return None,
         };
 
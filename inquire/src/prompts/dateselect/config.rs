@@ -13,6 +13,12 @@ pub struct DateSelectConfig {
 
     /// Weekday to start the week on.
     pub week_start: chrono::Weekday,
+
+    /// Whether the ISO week numbers are displayed.
+    pub show_week_numbers: bool,
+
+    /// Whether the user can type a date to jump to.
+    pub typed_input: bool,
 }
 
 impl From<&DateSelect<'_>> for DateSelectConfig {
@@ -21,6 +27,8 @@ impl From<&DateSelect<'_>> for DateSelectConfig {
             min_date: value.min_date,
             max_date: value.max_date,
             week_start: value.week_start,
+            show_week_numbers: value.show_week_numbers,
+            typed_input: value.typed_input,
         }
     }
 }
@@ -0,0 +1,212 @@
+use chrono::{Month, Weekday};
+
+/// Names and conventions used to display the calendar of date prompts in a given language.
+///
+/// A few locales are provided as constants, such as [`CalendarLocale::ENGLISH`] or
+/// [`CalendarLocale::GERMAN`]. Custom ones can be created with [`CalendarLocale::new`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use inquire::CalendarLocale;
+///
+/// let finnish = CalendarLocale::new(
+///     [
+///         "tammikuu", "helmikuu", "maaliskuu", "huhtikuu", "toukokuu", "kesäkuu",
+///         "heinäkuu", "elokuu", "syyskuu", "lokakuu", "marraskuu", "joulukuu",
+///     ],
+///     ["ma", "ti", "ke", "to", "pe", "la", "su"],
+///     Weekday::Mon,
+/// );
+///
+/// assert_eq!("maaliskuu", finnish.month_name(chrono::Month::March));
+/// assert_eq!("su", finnish.weekday_abbreviation(Weekday::Sun));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CalendarLocale<'a> {
+    /// Names of the months displayed in the calendar header, from January to December.
+    pub month_names: [&'a str; 12],
+
+    /// Abbreviations of the weekdays displayed in the week header, from Monday to Sunday.
+    ///
+    /// They should be two columns wide to be aligned with the dates below them.
+    pub weekday_abbreviations: [&'a str; 7],
+
+    /// First day of the week in the calendar, unless overridden by the prompt.
+    pub week_start: Weekday,
+}
+
+impl<'a> CalendarLocale<'a> {
+    /// Creates a locale with the given month names, from January to December,
+    /// weekday abbreviations, from Monday to Sunday, and first day of the week.
+    pub fn new(
+        month_names: [&'a str; 12],
+        weekday_abbreviations: [&'a str; 7],
+        week_start: Weekday,
+    ) -> Self {
+        Self {
+            month_names,
+            weekday_abbreviations,
+            week_start,
+        }
+    }
+
+    /// Name of the given month.
+    pub fn month_name(&self, month: Month) -> &'a str {
+        self.month_names[month.number_from_month() as usize - 1]
+    }
+
+    /// Abbreviation of the given weekday.
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &'a str {
+        self.weekday_abbreviations[weekday.num_days_from_monday() as usize]
+    }
+}
+
+impl CalendarLocale<'static> {
+    /// English locale, with weeks starting on Sunday.
+    pub const ENGLISH: Self = Self {
+        month_names: [
+            "january",
+            "february",
+            "march",
+            "april",
+            "may",
+            "june",
+            "july",
+            "august",
+            "september",
+            "october",
+            "november",
+            "december",
+        ],
+        weekday_abbreviations: ["mo", "tu", "we", "th", "fr", "sa", "su"],
+        week_start: Weekday::Sun,
+    };
+
+    /// German locale, with weeks starting on Monday.
+    pub const GERMAN: Self = Self {
+        month_names: [
+            "januar",
+            "februar",
+            "märz",
+            "april",
+            "mai",
+            "juni",
+            "juli",
+            "august",
+            "september",
+            "oktober",
+            "november",
+            "dezember",
+        ],
+        weekday_abbreviations: ["mo", "di", "mi", "do", "fr", "sa", "so"],
+        week_start: Weekday::Mon,
+    };
+
+    /// French locale, with weeks starting on Monday.
+    pub const FRENCH: Self = Self {
+        month_names: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        weekday_abbreviations: ["lu", "ma", "me", "je", "ve", "sa", "di"],
+        week_start: Weekday::Mon,
+    };
+
+    /// Spanish locale, with weeks starting on Monday.
+    pub const SPANISH: Self = Self {
+        month_names: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        weekday_abbreviations: ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+        week_start: Weekday::Mon,
+    };
+
+    /// Italian locale, with weeks starting on Monday.
+    pub const ITALIAN: Self = Self {
+        month_names: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        weekday_abbreviations: ["lu", "ma", "me", "gi", "ve", "sa", "do"],
+        week_start: Weekday::Mon,
+    };
+
+    /// Dutch locale, with weeks starting on Monday.
+    pub const DUTCH: Self = Self {
+        month_names: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        weekday_abbreviations: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        week_start: Weekday::Mon,
+    };
+
+    /// Swedish locale, with weeks starting on Monday.
+    pub const SWEDISH: Self = Self {
+        month_names: [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        weekday_abbreviations: ["må", "ti", "on", "to", "fr", "lö", "sö"],
+        week_start: Weekday::Mon,
+    };
+}
+
+impl Default for CalendarLocale<'static> {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
@@ -1,11 +1,13 @@
 mod action;
 mod config;
+mod locale;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use locale::*;
 
 use chrono::NaiveDate;
 
@@ -13,14 +15,16 @@ use crate::{
     config::get_configuration,
     date_utils::get_current_date,
     error::{InquireError, InquireResult},
-    formatter::{self, DateFormatter},
+    formatter::{self, DateFormatter, DateRangeFormatter, MultiDateFormatter},
+    parser::{self, DateInputParser},
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::{DatePredicate, DateStyler},
     ui::{date::DateSelectBackend, Backend, RenderConfig},
-    validator::DateValidator,
+    validator::{DateRangeValidator, DateValidator, MultiDateValidator},
 };
 
-use self::prompt::DateSelectPrompt;
+use self::prompt::{DateSelectPrompt, Multiple, Range, Single};
 
 /// Prompt that allows user to select a date (time not supported) from an interactive calendar. Available via the `date` feature.
 ///
@@ -43,7 +47,34 @@ use self::prompt::DateSelectPrompt;
 ///   - Formats to "Month Day, Year" by default.
 /// - **Validators**: Custom validators to the user's selected date, displaying an error message if the date does not pass the requirements.
 /// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
+/// - **Locale**: Month names, weekday abbreviations and default week start of the calendar, [English](CalendarLocale::ENGLISH) by default.
+/// - **Week numbers**: Whether the ISO week numbers are displayed in a column to the left of the calendar, hidden by default.
 /// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range.
+/// - **Disabled dates**: Predicate marking dates that can not be selected, such as weekends or holidays. Navigating the calendar skips them, and submitting one of them displays an error message.
+/// - **Date styler**: Function returning custom style sheets for specific dates, e.g. to highlight dates with existing bookings.
+/// - **Typed input**: Lets the user type a date, such as `2019-03-14`, `+3d`, `next friday` or `today`, moving the calendar to it as they type. Disabled by default.
+///   - **Input parser**: Custom parser of the typed dates, [DEFAULT_DATE_INPUT_PARSER](crate::parser::DEFAULT_DATE_INPUT_PARSER) by default.
+///   - **Input error message**: Error message displayed while the typed date can not be parsed.
+///
+/// The prompt can also be run in range mode with [`prompt_range`](DateSelect::prompt_range), where the first submission
+/// anchors the start of the range and the second one sets its end, returning both dates in order. Pressing ESC after
+/// anchoring the start discards it instead of cancelling the prompt. The range mode accepts a few extra options:
+///
+/// - **Max range span**: Maximum number of days between the start and the end of the range. Once the start is anchored, the user will not be able to move further than this from it.
+/// - **Range formatter**: Custom formatter for the final answer, formatting both dates as "Month Day, Year" separated by a dash by default.
+/// - **Range validators**: Custom validators to the selected range, run after the validators of each single date.
+///
+/// Several non-contiguous dates can be selected with [`prompt_multiple`](DateSelect::prompt_multiple), where the space
+/// key toggles the date under the cursor and the enter key submits all toggled dates, returned in ascending order.
+/// With typed input enabled, pressing enter after typing a date toggles it instead of submitting.
+/// Toggled dates must pass the date validators, and the multiple mode accepts a few extra options:
+///
+/// - **Minimum and maximum selections**: Limits on the number of toggled dates. Toggling more dates than the maximum is refused, while submitting less dates than the minimum displays an error.
+/// - **Multi-date formatter**: Custom formatter for the final answer, formatting the dates as "Month Day, Year" separated by commas by default.
+/// - **Multi-date validators**: Custom validators to the whole list of toggled dates.
+///
+/// The options of the range and multiple modes are only supported in their own mode: running a prompt
+/// with options of another mode returns an [`InvalidConfiguration`](crate::InquireError::InvalidConfiguration) error.
 ///
 /// # Example
 ///
@@ -72,6 +103,12 @@ pub struct DateSelect<'a> {
     /// First day of the week when displaying week rows.
     pub week_start: chrono::Weekday,
 
+    /// Month names and weekday abbreviations displayed in the calendar.
+    pub locale: CalendarLocale<'a>,
+
+    /// Whether the ISO week numbers are displayed to the left of each week.
+    pub show_week_numbers: bool,
+
     /// Starting date to be selected.
     pub starting_date: NaiveDate,
 
@@ -95,6 +132,66 @@ pub struct DateSelect<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn DateValidator>>,
 
+    /// Predicate returning whether a date is disabled, in which case it is
+    /// skipped when navigating and rejected when submitted.
+    pub disabled_dates: Option<DatePredicate<'a>>,
+
+    /// Error message displayed when the user submits a disabled date.
+    pub disabled_date_error_message: String,
+
+    /// Whether the user can type a date that the calendar moves to as they type.
+    ///
+    /// When enabled, characters typed without modifiers go to the input, so the vim
+    /// bindings are disabled. The arrow and page keys, the emacs bindings and the
+    /// `[`, `]`, `{` and `}` keys still navigate the calendar.
+    pub typed_input: bool,
+
+    /// Function that parses the date typed by the user, when typed input is enabled.
+    pub input_parser: DateInputParser<'a>,
+
+    /// Error message displayed when the date typed by the user can not be parsed
+    /// or is out of the allowed range.
+    pub input_error_message: String,
+
+    /// Function returning custom style sheets for specific dates of the calendar.
+    ///
+    /// The selected date and dates out of the allowed range keep their own styles.
+    pub date_styler: Option<DateStyler<'a>>,
+
+    /// Maximum number of days between the start and the end of a range,
+    /// when the prompt is run in range mode.
+    pub max_range_span: Option<u32>,
+
+    /// Function that formats the range selected in range mode and presents it to
+    /// the user as the final rendering of the prompt.
+    ///
+    /// When `None`, [DEFAULT_RANGE_FORMATTER](Self::DEFAULT_RANGE_FORMATTER) is used.
+    pub range_formatter: Option<DateRangeFormatter<'a>>,
+
+    /// Collection of validators to apply to the range selected in range mode.
+    ///
+    /// They are executed after the [validators](DateSelect::validators) of the
+    /// end date, in the order they are stored.
+    pub range_validators: Vec<Box<dyn DateRangeValidator>>,
+
+    /// Minimum number of dates toggled when the prompt is run in multiple mode.
+    pub min_selections: Option<usize>,
+
+    /// Maximum number of dates toggled when the prompt is run in multiple mode.
+    pub max_selections: Option<usize>,
+
+    /// Function that formats the dates selected in multiple mode and presents them
+    /// to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, [DEFAULT_MULTI_DATE_FORMATTER](Self::DEFAULT_MULTI_DATE_FORMATTER) is used.
+    pub multi_date_formatter: Option<MultiDateFormatter<'a>>,
+
+    /// Collection of validators to apply to the dates selected in multiple mode.
+    ///
+    /// Each date is checked by the [validators](DateSelect::validators) when toggled,
+    /// while these are executed over all of them on submission, in the order they are stored.
+    pub multi_date_validators: Vec<Box<dyn MultiDateValidator>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -117,18 +214,56 @@ impl<'a> DateSelect<'a> {
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("arrows to move, []{} move months and years, enter to select");
 
+    /// Default help message when typed input is enabled.
+    pub const DEFAULT_TYPED_INPUT_HELP_MESSAGE: Option<&'a str> =
+        Some("type a date like 2019-03-14, +3d or next friday, arrows and page keys to move");
+
+    /// Default value of typed input, disabled.
+    pub const DEFAULT_TYPED_INPUT: bool = false;
+
+    /// Default parser of typed dates, set to [DEFAULT_DATE_INPUT_PARSER](crate::parser::DEFAULT_DATE_INPUT_PARSER)
+    pub const DEFAULT_INPUT_PARSER: DateInputParser<'a> = parser::DEFAULT_DATE_INPUT_PARSER;
+
+    /// Default error message displayed when a typed date is invalid.
+    pub const DEFAULT_INPUT_ERROR_MESSAGE: &'a str =
+        "Invalid date, try e.g. 2019-03-14, +3d, next friday or today";
+
     /// Default validators added to the [DateSelect] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateValidator>> = vec![];
 
     /// Default week start.
     pub const DEFAULT_WEEK_START: chrono::Weekday = chrono::Weekday::Sun;
 
+    /// Default locale, [English](CalendarLocale::ENGLISH).
+    pub const DEFAULT_LOCALE: CalendarLocale<'a> = CalendarLocale::ENGLISH;
+
+    /// Default value of whether week numbers are displayed, false.
+    pub const DEFAULT_SHOW_WEEK_NUMBERS: bool = false;
+
     /// Default min date.
     pub const DEFAULT_MIN_DATE: Option<NaiveDate> = None;
 
     /// Default max date.
     pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
 
+    /// Default range formatter, set to [DEFAULT_DATE_RANGE_FORMATTER](crate::formatter::DEFAULT_DATE_RANGE_FORMATTER)
+    pub const DEFAULT_RANGE_FORMATTER: DateRangeFormatter<'a> =
+        formatter::DEFAULT_DATE_RANGE_FORMATTER;
+
+    /// Default error message displayed when a disabled date is submitted.
+    pub const DEFAULT_DISABLED_DATE_ERROR_MESSAGE: &'a str = "This date is not available";
+
+    /// Default help message when the prompt is run in multiple mode.
+    pub const DEFAULT_MULTIPLE_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, space to toggle, []{} move months and years, enter to submit");
+
+    /// Default multi-date formatter, set to [DEFAULT_MULTI_DATE_FORMATTER](crate::formatter::DEFAULT_MULTI_DATE_FORMATTER)
+    pub const DEFAULT_MULTI_DATE_FORMATTER: MultiDateFormatter<'a> =
+        formatter::DEFAULT_MULTI_DATE_FORMATTER;
+
+    /// Default max range span, none.
+    pub const DEFAULT_MAX_RANGE_SPAN: Option<u32> = None;
+
     /// Creates a [DateSelect] with the provided message, along with default configuration values.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -140,6 +275,21 @@ impl<'a> DateSelect<'a> {
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
+            locale: Self::DEFAULT_LOCALE,
+            show_week_numbers: Self::DEFAULT_SHOW_WEEK_NUMBERS,
+            disabled_dates: None,
+            disabled_date_error_message: String::from(Self::DEFAULT_DISABLED_DATE_ERROR_MESSAGE),
+            date_styler: None,
+            typed_input: Self::DEFAULT_TYPED_INPUT,
+            input_parser: Self::DEFAULT_INPUT_PARSER,
+            input_error_message: String::from(Self::DEFAULT_INPUT_ERROR_MESSAGE),
+            max_range_span: Self::DEFAULT_MAX_RANGE_SPAN,
+            range_formatter: None,
+            range_validators: vec![],
+            min_selections: None,
+            max_selections: None,
+            multi_date_formatter: None,
+            multi_date_validators: vec![],
             render_config: get_configuration(),
         }
     }
@@ -167,6 +317,21 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the locale of the calendar, along with the week start to the
+    /// locale's default one. Call [with_week_start](Self::with_week_start)
+    /// afterwards to override it.
+    pub fn with_locale(mut self, locale: CalendarLocale<'a>) -> Self {
+        self.locale = locale;
+        self.week_start = locale.week_start;
+        self
+    }
+
+    /// Sets whether the ISO week numbers are displayed to the left of each week.
+    pub fn with_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
+
     /// Sets the min date.
     pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
         self.min_date = Some(min_date);
@@ -221,6 +386,156 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the predicate marking dates that can not be selected, such as weekends
+    /// or holidays. Navigating the calendar skips disabled dates, and submitting one
+    /// of them displays the [disabled date error message](DateSelect::with_disabled_date_error_message).
+    pub fn with_disabled_dates(mut self, disabled_dates: DatePredicate<'a>) -> Self {
+        self.disabled_dates = Some(disabled_dates);
+        self
+    }
+
+    /// Sets the error message displayed when the user submits a disabled date.
+    pub fn with_disabled_date_error_message(mut self, message: &'a str) -> Self {
+        self.disabled_date_error_message = String::from(message);
+        self
+    }
+
+    /// Sets the function returning custom style sheets for specific dates of the
+    /// calendar, such as dates with existing bookings.
+    pub fn with_date_styler(mut self, date_styler: DateStyler<'a>) -> Self {
+        self.date_styler = Some(date_styler);
+        self
+    }
+
+    /// Enables or disables typed input, letting the user type a date that the
+    /// calendar moves to as they type, such as `2019-03-14`, `+3d`, `next friday`
+    /// or `today`.
+    ///
+    /// When enabled, characters typed without modifiers go to the input, so the vim
+    /// bindings are disabled. The arrow and page keys, the emacs bindings and the
+    /// `[`, `]`, `{` and `}` keys still navigate the calendar. The default help
+    /// message is also replaced by [DEFAULT_TYPED_INPUT_HELP_MESSAGE](Self::DEFAULT_TYPED_INPUT_HELP_MESSAGE),
+    /// while custom ones are kept.
+    pub fn with_typed_input(mut self, typed_input: bool) -> Self {
+        let (from, to) = if typed_input {
+            (
+                Self::DEFAULT_HELP_MESSAGE,
+                Self::DEFAULT_TYPED_INPUT_HELP_MESSAGE,
+            )
+        } else {
+            (
+                Self::DEFAULT_TYPED_INPUT_HELP_MESSAGE,
+                Self::DEFAULT_HELP_MESSAGE,
+            )
+        };
+        if self.help_message == from {
+            self.help_message = to;
+        }
+
+        self.typed_input = typed_input;
+        self
+    }
+
+    /// Sets the parser of the dates typed by the user, enabling typed input.
+    pub fn with_input_parser(self, input_parser: DateInputParser<'a>) -> Self {
+        let mut this = self.with_typed_input(true);
+        this.input_parser = input_parser;
+        this
+    }
+
+    /// Sets the error message displayed when the date typed by the user can not
+    /// be parsed or is out of the allowed range.
+    pub fn with_input_error_message(mut self, message: &'a str) -> Self {
+        self.input_error_message = String::from(message);
+        self
+    }
+
+    /// Sets the maximum number of days between the start and the end of a range
+    /// selected in range mode.
+    pub fn with_max_range_span(mut self, max_range_span: u32) -> Self {
+        self.max_range_span = Some(max_range_span);
+        self
+    }
+
+    /// Sets the formatter of the range selected in range mode.
+    pub fn with_range_formatter(mut self, range_formatter: DateRangeFormatter<'a>) -> Self {
+        self.range_formatter = Some(range_formatter);
+        self
+    }
+
+    /// Adds a validator to the collection of validators applied to the range
+    /// selected in range mode, such as requiring a minimum number of nights.
+    ///
+    /// The possible error is displayed to the user one line above the prompt,
+    /// keeping the start of the range anchored.
+    pub fn with_range_validator<V>(mut self, validator: V) -> Self
+    where
+        V: DateRangeValidator + 'static,
+    {
+        self.range_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators applied to the range
+    /// selected in range mode, in the order they are given.
+    ///
+    /// The possible error is displayed to the user one line above the prompt,
+    /// keeping the start of the range anchored.
+    pub fn with_range_validators(mut self, validators: &[Box<dyn DateRangeValidator>]) -> Self {
+        for validator in validators {
+            self.range_validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the minimum number of dates toggled in multiple mode, displaying an
+    /// error message when the user submits less dates than that.
+    pub fn with_min_selections(mut self, min_selections: usize) -> Self {
+        self.min_selections = Some(min_selections);
+        self
+    }
+
+    /// Sets the maximum number of dates toggled in multiple mode, refusing to
+    /// toggle more dates than that.
+    pub fn with_max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
+    /// Sets the formatter of the dates selected in multiple mode.
+    pub fn with_multi_date_formatter(mut self, formatter: MultiDateFormatter<'a>) -> Self {
+        self.multi_date_formatter = Some(formatter);
+        self
+    }
+
+    /// Adds a validator to the collection of validators applied to the dates
+    /// selected in multiple mode, such as requiring them to be in the same month.
+    ///
+    /// The possible error is displayed to the user one line above the prompt,
+    /// keeping the toggled dates.
+    pub fn with_multi_date_validator<V>(mut self, validator: V) -> Self
+    where
+        V: MultiDateValidator + 'static,
+    {
+        self.multi_date_validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators applied to the dates
+    /// selected in multiple mode, in the order they are given.
+    ///
+    /// The possible error is displayed to the user one line above the prompt,
+    /// keeping the toggled dates.
+    pub fn with_multi_date_validators(
+        mut self,
+        validators: &[Box<dyn MultiDateValidator>],
+    ) -> Self {
+        for validator in validators {
+            self.multi_date_validators.push(validator.clone());
+        }
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -259,10 +574,83 @@ impl<'a> DateSelect<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Prompts the CLI user for a range of dates, where the first submission
+    /// anchors the start of the range and the second one sets its end.
+    ///
+    /// Returns the start and the end of the range, in order, regardless of
+    /// the order the user selected them in.
+    pub fn prompt_range(self) -> InquireResult<(NaiveDate, NaiveDate)> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_range_with_backend(&mut backend)
+    }
+
+    /// Prompts the CLI user for a range of dates, where the first submission
+    /// anchors the start of the range and the second one sets its end.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit a range, the method wraps the return
+    /// type of [`prompt_range`](Self::prompt_range) with `Some`.
+    pub fn prompt_range_skippable(self) -> InquireResult<Option<(NaiveDate, NaiveDate)>> {
+        match self.prompt_range() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Prompts the CLI user for several dates, toggled with the space key and
+    /// submitted all at once with the enter key.
+    ///
+    /// Returns the toggled dates in ascending order.
+    pub fn prompt_multiple(self) -> InquireResult<Vec<NaiveDate>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_multiple_with_backend(&mut backend)
+    }
+
+    /// Prompts the CLI user for several dates, toggled with the space key and
+    /// submitted all at once with the enter key.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit the dates, the method wraps the return
+    /// type of [`prompt_multiple`](Self::prompt_multiple) with `Some`.
+    pub fn prompt_multiple_skippable(self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        match self.prompt_multiple() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(crate) fn prompt_with_backend<B: DateSelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<NaiveDate> {
-        DateSelectPrompt::new(self)?.prompt(backend)
+        DateSelectPrompt::<Single>::new(self)?.prompt(backend)
+    }
+
+    pub(crate) fn prompt_range_with_backend<B: DateSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(NaiveDate, NaiveDate)> {
+        DateSelectPrompt::<Range>::new(self)?.prompt(backend)
+    }
+
+    pub(crate) fn prompt_multiple_with_backend<B: DateSelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<NaiveDate>> {
+        if self.help_message == Self::DEFAULT_HELP_MESSAGE {
+            self.help_message = Self::DEFAULT_MULTIPLE_HELP_MESSAGE;
+        }
+
+        DateSelectPrompt::<Multiple>::new(self)?.prompt(backend)
     }
 }
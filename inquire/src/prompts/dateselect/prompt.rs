@@ -1,5 +1,7 @@
 use std::{
     cmp::{max, min, Ordering},
+    collections::BTreeSet,
+    marker::PhantomData,
     ops::Add,
 };
 
@@ -8,26 +10,155 @@ use chrono::{Datelike, Duration, Months, NaiveDate};
 use crate::{
     date_utils::{get_current_date, get_month},
     error::InquireResult,
-    formatter::DateFormatter,
+    formatter::{DateFormatter, DateRangeFormatter, MultiDateFormatter},
+    input::{Input, InputActionResult},
+    parser::DateInputParser,
     prompts::prompt::{ActionResult, Prompt},
-    ui::date::DateSelectBackend,
-    validator::{DateValidator, ErrorMessage, Validation},
-    DateSelect, InquireError,
+    type_aliases::{DatePredicate, DateStyler},
+    ui::date::{CalendarView, DateSelectBackend},
+    utils::{max_selections_message, min_selections_message},
+    validator::{
+        validate_all, DateRangeValidator, DateValidator, ErrorMessage, MultiDateValidator,
+        Validation,
+    },
+    CalendarLocale, DateSelect, InputAction, InquireError,
 };
 
 use super::{action::DateSelectPromptAction, config::DateSelectConfig};
 
-pub struct DateSelectPrompt<'a> {
+/// Max amount of consecutive disabled dates skipped when looking for an
+/// enabled date to move to, roughly ten years.
+const MAX_SKIPPED_DATES: usize = 3660;
+
+/// Kind of answer a DateSelectPrompt is run for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    Single,
+    Range,
+    Multiple,
+}
+
+/// Mode a DateSelectPrompt is run in, which sets the type of its answer.
+pub trait DateSelectionMode: Sized {
+    type Answer;
+
+    const MODE: SelectionMode;
+
+    /// Submits the current state of the prompt, returning the answer once it
+    /// is accepted.
+    fn submit(prompt: &mut DateSelectPrompt<'_, Self>) -> InquireResult<Option<Self::Answer>>;
+
+    fn format_answer(prompt: &DateSelectPrompt<'_, Self>, answer: &Self::Answer) -> String;
+}
+
+/// Answers with the submitted date.
+pub struct Single;
+
+/// Answers with the start and the end of the submitted range.
+pub struct Range;
+
+/// Answers with the toggled dates.
+pub struct Multiple;
+
+impl DateSelectionMode for Single {
+    type Answer = NaiveDate;
+
+    const MODE: SelectionMode = SelectionMode::Single;
+
+    fn submit(prompt: &mut DateSelectPrompt<'_, Self>) -> InquireResult<Option<NaiveDate>> {
+        if prompt.reject_unparsed_input() || !prompt.accept_submission()? {
+            return Ok(None);
+        }
+
+        Ok(Some(prompt.cur_answer()))
+    }
+
+    fn format_answer(prompt: &DateSelectPrompt<'_, Self>, answer: &NaiveDate) -> String {
+        (prompt.formatter)(*answer)
+    }
+}
+
+impl DateSelectionMode for Range {
+    type Answer = (NaiveDate, NaiveDate);
+
+    const MODE: SelectionMode = SelectionMode::Range;
+
+    fn submit(
+        prompt: &mut DateSelectPrompt<'_, Self>,
+    ) -> InquireResult<Option<(NaiveDate, NaiveDate)>> {
+        if prompt.reject_unparsed_input() || !prompt.accept_submission()? {
+            return Ok(None);
+        }
+
+        Ok(prompt.submit_range())
+    }
+
+    fn format_answer(
+        prompt: &DateSelectPrompt<'_, Self>,
+        (start, end): &(NaiveDate, NaiveDate),
+    ) -> String {
+        (prompt.range_formatter)(*start, *end)
+    }
+}
+
+impl DateSelectionMode for Multiple {
+    type Answer = Vec<NaiveDate>;
+
+    const MODE: SelectionMode = SelectionMode::Multiple;
+
+    fn submit(prompt: &mut DateSelectPrompt<'_, Self>) -> InquireResult<Option<Vec<NaiveDate>>> {
+        if prompt.reject_unparsed_input() {
+            return Ok(None);
+        }
+
+        // a typed date is toggled, leaving the submission for an empty input
+        if let Some(Ok(_)) = prompt.typed_date() {
+            prompt.clear_input();
+            let warning = prompt.warning.take();
+            prompt.toggle_current_date(warning.as_ref())?;
+            return Ok(None);
+        }
+
+        prompt.submit_multiple()
+    }
+
+    fn format_answer(prompt: &DateSelectPrompt<'_, Self>, answer: &Vec<NaiveDate>) -> String {
+        (prompt.multi_date_formatter)(answer)
+    }
+}
+
+pub struct DateSelectPrompt<'a, M> {
     message: &'a str,
     config: DateSelectConfig,
     current_date: NaiveDate,
     help_message: Option<&'a str>,
+    locale: CalendarLocale<'a>,
     formatter: DateFormatter<'a>,
     validators: Vec<Box<dyn DateValidator>>,
+    disabled_dates: Option<DatePredicate<'a>>,
+    disabled_date_error_message: String,
+    date_styler: Option<DateStyler<'a>>,
+    input: Option<Input>,
+    input_parser: DateInputParser<'a>,
+    input_error_message: String,
+    range_start: Option<NaiveDate>,
+    max_range_span: Option<u32>,
+    range_formatter: DateRangeFormatter<'a>,
+    range_validators: Vec<Box<dyn DateRangeValidator>>,
+    toggled_dates: BTreeSet<NaiveDate>,
+    min_selections: Option<usize>,
+    max_selections: Option<usize>,
+    multi_date_formatter: MultiDateFormatter<'a>,
+    multi_date_validators: Vec<Box<dyn MultiDateValidator>>,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
+    mode: PhantomData<M>,
 }
 
-impl<'a> DateSelectPrompt<'a> {
+impl<'a, M> DateSelectPrompt<'a, M>
+where
+    M: DateSelectionMode,
+{
     pub fn new(so: DateSelect<'a>) -> InquireResult<Self> {
         if let Some(min_date) = so.min_date {
             if min_date > so.starting_date {
@@ -43,15 +174,64 @@ impl<'a> DateSelectPrompt<'a> {
                 ));
             }
         }
+        if M::MODE != SelectionMode::Range
+            && (so.max_range_span.is_some()
+                || so.range_formatter.is_some()
+                || !so.range_validators.is_empty())
+        {
+            return Err(InquireError::InvalidConfiguration(
+                "Max range span, range formatter and range validators are only supported when selecting a range".into(),
+            ));
+        }
+        if M::MODE != SelectionMode::Multiple
+            && (so.min_selections.is_some()
+                || so.max_selections.is_some()
+                || so.multi_date_formatter.is_some()
+                || !so.multi_date_validators.is_empty())
+        {
+            return Err(InquireError::InvalidConfiguration(
+                "Min and max selections, multi-date formatter and multi-date validators are only supported when selecting multiple dates".into(),
+            ));
+        }
+        if let (Some(min), Some(max)) = (so.min_selections, so.max_selections) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum number of selections {} is greater than the maximum {}",
+                    min, max
+                )));
+            }
+        }
 
         Ok(Self {
             message: so.message,
             current_date: so.starting_date,
             config: (&so).into(),
             help_message: so.help_message,
+            locale: so.locale,
             formatter: so.formatter,
             validators: so.validators,
+            disabled_dates: so.disabled_dates,
+            disabled_date_error_message: so.disabled_date_error_message,
+            date_styler: so.date_styler,
+            input: so.typed_input.then(Input::new),
+            input_parser: so.input_parser,
+            input_error_message: so.input_error_message,
+            range_start: None,
+            max_range_span: so.max_range_span,
+            range_formatter: so
+                .range_formatter
+                .unwrap_or(DateSelect::DEFAULT_RANGE_FORMATTER),
+            range_validators: so.range_validators,
+            toggled_dates: BTreeSet::new(),
+            min_selections: so.min_selections,
+            max_selections: so.max_selections,
+            multi_date_formatter: so
+                .multi_date_formatter
+                .unwrap_or(DateSelect::DEFAULT_MULTI_DATE_FORMATTER),
+            multi_date_validators: so.multi_date_validators,
             error: None,
+            warning: None,
+            mode: PhantomData,
         })
     }
 
@@ -79,31 +259,307 @@ impl<'a> DateSelectPrompt<'a> {
     }
 
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        let input_cleared = self.clear_input();
+
         if self.current_date == new_date {
-            return ActionResult::Clean;
+            return if input_cleared {
+                ActionResult::NeedsRedraw
+            } else {
+                ActionResult::Clean
+            };
+        }
+
+        let forward = new_date > self.current_date;
+        let (min_date, max_date) = self.bounds();
+
+        let mut new_date = new_date;
+        if let Some(min_date) = min_date {
+            new_date = max(new_date, min_date);
+        }
+        if let Some(max_date) = max_date {
+            new_date = min(new_date, max_date);
         }
 
-        self.current_date = new_date;
-        if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
+        match self.nearest_enabled_date(new_date, forward, min_date, max_date) {
+            Some(date) => self.current_date = date,
+            None if input_cleared => return ActionResult::NeedsRedraw,
+            None => return ActionResult::Clean,
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn handle_input(&mut self, action: InputAction) -> ActionResult {
+        let result = match &mut self.input {
+            Some(input) => input.handle(action),
+            None => return ActionResult::Clean,
+        };
+
+        match result {
+            InputActionResult::ContentChanged => self.jump_to_typed_date(),
+            result => result.into(),
         }
-        if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
+    }
+
+    /// Moves the calendar to the date typed by the user, if it is valid.
+    fn jump_to_typed_date(&mut self) -> ActionResult {
+        match self.typed_date() {
+            Some(Ok(date)) => {
+                self.current_date = date;
+                self.error = None;
+            }
+            Some(Err(())) => {
+                self.error = Some(ErrorMessage::Custom(self.input_error_message.clone()));
+            }
+            None => self.error = None,
         }
 
         ActionResult::NeedsRedraw
     }
 
+    /// Date currently typed by the user, if any, failing when it can not be
+    /// parsed or is out of the allowed range.
+    ///
+    /// Disabled dates are not rejected here so the user can see them on the
+    /// calendar, they are rejected on submission instead.
+    fn typed_date(&self) -> Option<Result<NaiveDate, ()>> {
+        let content = self.input.as_ref()?.content().trim();
+        if content.is_empty() {
+            return None;
+        }
+
+        let (min_date, max_date) = self.bounds();
+        let typed_date = (self.input_parser)(content, get_current_date()).and_then(|date| {
+            let out_of_bounds = min_date.map(|d| date < d).unwrap_or(false)
+                || max_date.map(|d| date > d).unwrap_or(false);
+            if out_of_bounds {
+                Err(())
+            } else {
+                Ok(date)
+            }
+        });
+
+        Some(typed_date)
+    }
+
+    /// Clears the typed date, along with its possible error, once the user
+    /// moves away from it. Returns whether there was anything to clear.
+    fn clear_input(&mut self) -> bool {
+        match &mut self.input {
+            Some(input) if !input.is_empty() => {
+                input.clear();
+                self.error = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_disabled(&self, date: NaiveDate) -> bool {
+        self.disabled_dates
+            .map(|is_disabled| is_disabled(date))
+            .unwrap_or(false)
+    }
+
+    /// Closest enabled date to `date`, looking first in the direction of the
+    /// movement and then in the opposite one, without leaving the bounds.
+    fn nearest_enabled_date(
+        &self,
+        date: NaiveDate,
+        forward: bool,
+        min_date: Option<NaiveDate>,
+        max_date: Option<NaiveDate>,
+    ) -> Option<NaiveDate> {
+        let search = |forward: bool| {
+            let mut candidate = date;
+
+            for _ in 0..MAX_SKIPPED_DATES {
+                if !self.is_disabled(candidate) {
+                    return Some(candidate);
+                }
+
+                candidate = if forward {
+                    candidate.succ_opt()?
+                } else {
+                    candidate.pred_opt()?
+                };
+
+                let out_of_bounds = min_date.map(|d| candidate < d).unwrap_or(false)
+                    || max_date.map(|d| candidate > d).unwrap_or(false);
+                if out_of_bounds {
+                    return None;
+                }
+            }
+
+            None
+        };
+
+        search(forward).or_else(|| search(!forward))
+    }
+
+    /// Min and max dates that can currently be selected, narrowed down by
+    /// the max span of the range once its start is anchored.
+    fn bounds(&self) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        let (mut min_date, mut max_date) = (self.config.min_date, self.config.max_date);
+
+        if let (Some(start), Some(span)) = (self.range_start, self.max_range_span) {
+            let span = Duration::try_days(i64::from(span))
+                .expect("unexpected overflow when calculating duration of the range span");
+
+            let earliest = start.checked_sub_signed(span).unwrap_or(NaiveDate::MIN);
+            let latest = start.checked_add_signed(span).unwrap_or(NaiveDate::MAX);
+
+            min_date = Some(min_date.map(|d| max(d, earliest)).unwrap_or(earliest));
+            max_date = Some(max_date.map(|d| min(d, latest)).unwrap_or(latest));
+        }
+
+        (min_date, max_date)
+    }
+
+    /// Range between the anchored start and the currently selected date, in order.
+    fn cur_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.range_start
+            .map(|start| (min(start, self.current_date), max(start, self.current_date)))
+    }
+
+    /// Whether a space typed by the user toggles the current date instead of
+    /// being written, which is the case unless it follows some text, e.g. "next friday".
+    fn is_space_toggle(&self, action: DateSelectPromptAction) -> bool {
+        let input_empty = self.input.as_ref().map(Input::is_empty).unwrap_or(true);
+
+        M::MODE == SelectionMode::Multiple
+            && input_empty
+            && action == DateSelectPromptAction::TextInput(InputAction::Write(' '))
+    }
+
+    /// Toggles the current date in multiple mode. Dates with a warning are
+    /// only toggled in when the warning was already displayed.
+    fn toggle_current_date(
+        &mut self,
+        displayed_warning: Option<&ErrorMessage>,
+    ) -> InquireResult<ActionResult> {
+        if M::MODE != SelectionMode::Multiple {
+            return Ok(ActionResult::Clean);
+        }
+
+        let date = self.current_date;
+
+        if self.toggled_dates.remove(&date) {
+            self.error = None;
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        if let Some(max) = self.max_selections {
+            if self.toggled_dates.len() >= max {
+                self.error = Some(max_selections_message(max, "date"));
+                return Ok(ActionResult::NeedsRedraw);
+            }
+        }
+
+        let validation = self.validate_date(date)?;
+        if validation.accepts(displayed_warning) {
+            self.toggled_dates.insert(date);
+            self.error = None;
+        } else {
+            (self.error, self.warning) = validation.into_messages();
+        }
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.cur_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
+        self.validate_date(self.cur_answer())
+    }
+
+    fn validate_date(&self, date: NaiveDate) -> InquireResult<Validation> {
+        if self.is_disabled(date) {
+            return Ok(Validation::Invalid(ErrorMessage::Custom(
+                self.disabled_date_error_message.clone(),
+            )));
+        }
+
+        validate_all(self.validators.iter().map(|v| v.validate(date))).map_err(InquireError::Custom)
+    }
+
+    fn validate_range(&self, start: NaiveDate, end: NaiveDate) -> InquireResult<Validation> {
+        validate_all(self.range_validators.iter().map(|v| v.validate(start, end)))
+            .map_err(InquireError::Custom)
+    }
+
+    /// Validates the current date and, once a range is anchored, the range it ends.
+    fn validate_submission(&self) -> InquireResult<Validation> {
+        let validation = self.validate_current_answer()?;
+
+        match self.cur_range() {
+            Some((start, end)) if !matches!(validation, Validation::Invalid(_)) => {
+                let range_validation = self.validate_range(start, end)?;
+                validate_all(vec![Ok(validation), Ok(range_validation)])
+                    .map_err(InquireError::Custom)
+            }
+            _ => Ok(validation),
+        }
+    }
+
+    /// Displays an error for a typed date that could not be parsed, returning
+    /// whether there was one.
+    fn reject_unparsed_input(&mut self) -> bool {
+        if let Some(Err(())) = self.typed_date() {
+            self.error = Some(ErrorMessage::Custom(self.input_error_message.clone()));
+            return true;
+        }
+
+        false
+    }
+
+    /// Validates the submitted date, displaying the messages of a validation
+    /// that is not accepted.
+    fn accept_submission(&mut self) -> InquireResult<bool> {
+        let validation = self.validate_submission()?;
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(true);
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(false)
+    }
+
+    /// Anchors the start of the range on the first submission, returning the
+    /// range on the second one.
+    fn submit_range(&mut self) -> Option<(NaiveDate, NaiveDate)> {
+        if self.range_start.is_none() {
+            self.range_start = Some(self.current_date);
+            self.clear_input();
+            self.error = None;
+            self.warning = None;
+            return None;
+        }
+
+        self.cur_range()
+    }
+
+    fn submit_multiple(&mut self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        if let Some(min) = self.min_selections {
+            if self.toggled_dates.len() < min {
+                self.error = Some(min_selections_message(min, "date"));
+                return Ok(None);
             }
         }
 
-        Ok(Validation::Valid)
+        let dates: Vec<NaiveDate> = self.toggled_dates.iter().copied().collect();
+
+        let validation = validate_all(
+            self.multi_date_validators
+                .iter()
+                .map(|v| v.validate(&dates)),
+        )
+        .map_err(InquireError::Custom)?;
+
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(dates));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
     }
 
     fn cur_answer(&self) -> NaiveDate {
@@ -111,39 +567,50 @@ impl<'a> DateSelectPrompt<'a> {
     }
 }
 
-impl<'a, B> Prompt<B> for DateSelectPrompt<'a>
+impl<'a, B, M> Prompt<B> for DateSelectPrompt<'a, M>
 where
     B: DateSelectBackend,
+    M: DateSelectionMode,
 {
     type Config = DateSelectConfig;
     type InnerAction = DateSelectPromptAction;
-    type Output = NaiveDate;
+    type Output = M::Answer;
 
     fn message(&self) -> &str {
         self.message
     }
 
-    fn format_answer(&self, answer: &NaiveDate) -> String {
-        (self.formatter)(*answer)
+    fn format_answer(&self, answer: &M::Answer) -> String {
+        M::format_answer(self, answer)
     }
 
     fn config(&self) -> &DateSelectConfig {
         &self.config
     }
 
-    fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
-        let answer = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.cur_answer()),
-            Validation::Invalid(msg) => {
-                self.error = Some(msg);
-                None
-            }
-        };
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.range_start.is_some() {
+            self.range_start = None;
+            self.error = None;
+            self.warning = None;
+            return Ok(false);
+        }
 
-        Ok(answer)
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<M::Answer>> {
+        M::submit(self)
     }
 
     fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
+        // the warning is only kept while the answer it was given for is unchanged
+        let warning = self.warning.take();
+
+        if self.is_space_toggle(action) {
+            return self.toggle_current_date(warning.as_ref());
+        }
+
         let result = match action {
             DateSelectPromptAction::GoToPrevWeek => self.shift_date(
                 Duration::try_weeks(-1)
@@ -165,8 +632,14 @@ where
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
+            DateSelectPromptAction::ToggleDate => self.toggle_current_date(warning.as_ref())?,
+            DateSelectPromptAction::TextInput(input_action) => self.handle_input(input_action),
         };
 
+        if result == ActionResult::Clean {
+            self.warning = warning;
+        }
+
         Ok(result)
     }
 
@@ -175,19 +648,29 @@ where
 
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
         }
 
-        backend.render_calendar_prompt(prompt)?;
+        backend.render_calendar_prompt(prompt, self.input.as_ref())?;
 
-        backend.render_calendar(
-            get_month(self.current_date.month()),
-            self.current_date.year(),
-            self.config.week_start,
-            get_current_date(),
-            self.current_date,
-            self.config.min_date,
-            self.config.max_date,
-        )?;
+        let (min_date, max_date) = self.bounds();
+        let toggled_dates: Vec<NaiveDate> = self.toggled_dates.iter().copied().collect();
+        backend.render_calendar(CalendarView {
+            month: get_month(self.current_date.month()),
+            year: self.current_date.year(),
+            week_start: self.config.week_start,
+            locale: &self.locale,
+            show_week_numbers: self.config.show_week_numbers,
+            today: get_current_date(),
+            selected_date: self.current_date,
+            range: self.cur_range(),
+            toggled_dates: &toggled_dates,
+            min_date,
+            max_date,
+            disabled_dates: self.disabled_dates,
+            date_styler: self.date_styler,
+        })?;
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -8,7 +8,7 @@ use crate::{
         Key, KeyModifiers,
     },
     validator::{ErrorMessage, Validation},
-    DateSelect, InquireError,
+    CalendarLocale, DateSelect, InquireError,
 };
 use chrono::{Datelike, NaiveDate};
 
@@ -1017,3 +1017,917 @@ fn multiple_validators_are_respected() -> InquireResult<()> {
 
     Ok(())
 }
+
+fn range_date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 1, day).unwrap()
+}
+
+fn range_prompt<'a>() -> DateSelect<'a> {
+    DateSelect::new("Question").with_starting_date(range_date(15))
+}
+
+#[test]
+fn range_is_selected_with_two_submissions() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let range = range_prompt().prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(15), range_date(18)), range);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question".into(),
+            "January 15, 2023 - January 18, 2023".into()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn range_selected_backwards_is_returned_in_order() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Left(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let range = range_prompt().prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(13), range_date(15)), range);
+
+    Ok(())
+}
+
+#[test]
+fn range_is_sent_to_the_backend_once_anchored() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new(vec![Key::Enter, Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    range_prompt().prompt_range_with_backend(&mut backend)?;
+
+    let range_of = |frame: &crate::ui::test::Frame| {
+        frame.tokens().iter().find_map(|t| match t {
+            Token::Calendar { range, .. } => Some(*range),
+            _ => None,
+        })
+    };
+
+    let frames = backend.frames();
+    assert_eq!(Some(None), range_of(&frames[0]));
+    assert_eq!(
+        Some(Some((range_date(15), range_date(15)))),
+        range_of(&frames[1])
+    );
+    assert_eq!(
+        Some(Some((range_date(15), range_date(16)))),
+        range_of(&frames[2])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn max_range_span_limits_the_end_of_the_range() -> InquireResult<()> {
+    let mut moves = vec![Key::Enter];
+    moves.extend(vec![Key::Right(KeyModifiers::NONE); 10]);
+    moves.push(Key::Enter);
+    let mut backend = FakeBackend::new(moves);
+
+    let range = range_prompt()
+        .with_max_range_span(3)
+        .prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(15), range_date(18)), range);
+    assert!(backend.frames()[1].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar { min_date, max_date, .. }
+            if *min_date == Some(range_date(12)) && *max_date == Some(range_date(18))
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn range_validator_error_keeps_the_start_anchored() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let range = range_prompt()
+        .with_range_validator(|start: NaiveDate, end: NaiveDate| {
+            if start == end {
+                Ok(Validation::Invalid("At least one night".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(15), range_date(16)), range);
+    assert!(
+        backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "At least one night".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn date_validators_apply_to_both_ends_of_the_range() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let range = range_prompt()
+        .with_validator(|d: NaiveDate| {
+            if d.day() % 2 == 0 {
+                Ok(Validation::Invalid("Odd days only".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(15), range_date(17)), range);
+
+    Ok(())
+}
+
+#[test]
+fn escape_discards_the_start_of_the_range() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Escape,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let range = range_prompt().prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(17), range_date(17)), range);
+
+    Ok(())
+}
+
+#[test]
+fn escape_cancels_the_range_prompt_when_nothing_is_anchored() {
+    let mut backend = FakeBackend::new(vec![Key::Escape]);
+
+    let range = range_prompt().prompt_range_with_backend(&mut backend);
+
+    assert!(matches!(range, Err(InquireError::OperationCanceled)));
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+}
+
+#[test]
+fn navigation_skips_disabled_dates() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let answer = DateSelect::new("Question")
+        .with_starting_date(range_date(12))
+        .with_disabled_dates(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    // thursday -> friday -> (weekend) monday -> (weekend) friday
+    assert_eq!(range_date(13), answer);
+
+    Ok(())
+}
+
+#[test]
+fn weekly_navigation_moves_past_disabled_dates() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let holiday = range_date(20);
+    let answer = DateSelect::new("Question")
+        .with_starting_date(range_date(13))
+        .with_disabled_dates(&|d| d == holiday)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(21), answer);
+
+    Ok(())
+}
+
+#[test]
+fn disabled_dates_next_to_bounds_keep_the_current_date() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    let answer = DateSelect::new("Question")
+        .with_starting_date(range_date(13))
+        .with_max_date(range_date(15))
+        .with_disabled_dates(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(13), answer);
+
+    Ok(())
+}
+
+#[test]
+fn submitting_a_disabled_date_displays_an_error() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new(vec![Key::Enter, Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    let answer = DateSelect::new("Question")
+        .with_starting_date(range_date(14))
+        .with_disabled_dates(&is_weekend)
+        .with_disabled_date_error_message("We are closed on weekends")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(16), answer);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("We are closed on weekends".into()));
+    assert!(backend.frames()[1].has_token(&error));
+    assert!(!backend.frames().last().unwrap().has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn disabled_dates_of_the_month_are_sent_to_the_backend() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    DateSelect::new("Question")
+        .with_starting_date(range_date(13))
+        .with_disabled_dates(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    let expected: Vec<NaiveDate> = [1, 7, 8, 14, 15, 21, 22, 28, 29]
+        .iter()
+        .map(|&day| range_date(day))
+        .collect();
+
+    assert!(backend.frames()[0].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar { disabled_dates, .. } if *disabled_dates == expected
+    )));
+
+    Ok(())
+}
+
+fn typed_keys(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+fn typed_prompt<'a>() -> DateSelect<'a> {
+    DateSelect::new("Question")
+        .with_starting_date(range_date(13))
+        .with_typed_input(true)
+}
+
+#[test]
+fn typed_iso_date_is_selected() -> InquireResult<()> {
+    let mut keys = typed_keys("2019-03-14");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(NaiveDate::from_ymd_opt(2019, 3, 14).unwrap(), answer);
+
+    Ok(())
+}
+
+#[test]
+fn typed_relative_date_is_relative_to_today() -> InquireResult<()> {
+    let mut keys = typed_keys("+3d");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(get_current_date() + chrono::Days::new(3), answer);
+
+    Ok(())
+}
+
+#[test]
+fn calendar_follows_the_typed_date_live() -> InquireResult<()> {
+    let mut keys = typed_keys("2023-02-01");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    typed_prompt().prompt_with_backend(&mut backend)?;
+
+    let selected_dates: Vec<NaiveDate> = backend
+        .frames()
+        .iter()
+        .flat_map(|frame| frame.tokens())
+        .filter_map(|t| match t {
+            Token::Calendar { selected_date, .. } => Some(*selected_date),
+            _ => None,
+        })
+        .collect();
+
+    // "2023-02-0" is not a valid date yet, so the calendar only moves on the last key.
+    assert_eq!(Some(&range_date(13)), selected_dates.get(9));
+    assert_eq!(
+        Some(&NaiveDate::from_ymd_opt(2023, 2, 1).unwrap()),
+        selected_dates.last()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_typed_date_displays_an_error_and_blocks_submission() -> InquireResult<()> {
+    let mut keys = typed_keys("someday");
+    keys.push(Key::Enter);
+    keys.extend(std::iter::repeat(Key::Backspace).take(7));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt()
+        .with_input_error_message("Not a date")
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(13), answer);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Not a date".into()));
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&error));
+    assert!(frames[8].has_token(&error));
+    assert!(!frames.last().unwrap().has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn typed_date_out_of_range_is_rejected() -> InquireResult<()> {
+    let mut keys = typed_keys("2023-02-01");
+    keys.push(Key::Enter);
+    keys.push(Key::Left(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt()
+        .with_max_date(range_date(31))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(12), answer);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom(
+        DateSelect::DEFAULT_INPUT_ERROR_MESSAGE.into(),
+    ));
+    assert!(backend.frames()[11].has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn navigating_clears_the_typed_date() -> InquireResult<()> {
+    let mut keys = typed_keys("2023-01-20");
+    keys.push(Key::Left(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(19), answer);
+    assert!(backend.frames()[11].tokens().iter().any(|t| matches!(
+        t,
+        Token::Input(input) if input.is_empty()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn vim_keys_are_typed_when_typed_input_is_enabled() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(14), answer);
+
+    Ok(())
+}
+
+#[test]
+fn emacs_keys_navigate_when_typed_input_is_enabled() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('f', KeyModifiers::CONTROL),
+        Key::Char('n', KeyModifiers::CONTROL),
+        Key::Char('v', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(NaiveDate::from_ymd_opt(2023, 2, 21).unwrap(), answer);
+    assert!(backend
+        .frames()
+        .iter()
+        .flat_map(|frame| frame.tokens())
+        .all(|t| !matches!(t, Token::Input(input) if !input.is_empty())));
+
+    Ok(())
+}
+
+#[test]
+fn bracket_keys_navigate_when_typed_input_is_enabled() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Char('[', KeyModifiers::NONE),
+        Key::Char('}', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 13).unwrap(), answer);
+    assert!(backend
+        .frames()
+        .iter()
+        .flat_map(|frame| frame.tokens())
+        .all(|t| !matches!(t, Token::Input(input) if !input.is_empty())));
+
+    Ok(())
+}
+
+#[test]
+fn navigation_keys_keep_working_in_the_middle_of_a_typed_date() -> InquireResult<()> {
+    let mut keys = typed_keys("2023-01-2");
+    keys.push(Key::Char('b', KeyModifiers::CONTROL));
+    keys.push(Key::Char('v', KeyModifiers::ALT));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(NaiveDate::from_ymd_opt(2022, 12, 1).unwrap(), answer);
+
+    Ok(())
+}
+
+#[test]
+fn typed_input_is_disabled_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('l', KeyModifiers::NONE), Key::Enter]);
+
+    let answer = range_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(16), answer);
+    assert!(!backend.frames()[0]
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::Input(_))));
+
+    Ok(())
+}
+
+#[test]
+fn custom_input_parser_enables_typed_input() -> InquireResult<()> {
+    let mut keys = typed_keys("14/03/2019");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = DateSelect::new("Question")
+        .with_input_parser(&|input, _| {
+            NaiveDate::parse_from_str(input, "%d/%m/%Y").map_err(|_e| ())
+        })
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(NaiveDate::from_ymd_opt(2019, 3, 14).unwrap(), answer);
+
+    Ok(())
+}
+
+#[test]
+fn typed_dates_select_both_ends_of_a_range() -> InquireResult<()> {
+    let mut keys = typed_keys("2023-01-20");
+    keys.push(Key::Enter);
+    keys.extend(typed_keys("2023-01-05"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(5), range_date(20)), answer);
+
+    Ok(())
+}
+
+#[test]
+fn locale_sets_month_names_and_week_start() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    DateSelect::new("Question")
+        .with_starting_date(NaiveDate::from_ymd_opt(2023, 3, 13).unwrap())
+        .with_locale(CalendarLocale::GERMAN)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[0].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar { month_name, week_start: chrono::Weekday::Mon, .. } if month_name == "märz"
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn week_start_can_be_overridden_after_setting_the_locale() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    DateSelect::new("Question")
+        .with_locale(CalendarLocale::FRENCH)
+        .with_week_start(chrono::Weekday::Sun)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[0].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar {
+            week_start: chrono::Weekday::Sun,
+            ..
+        }
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn week_numbers_are_hidden_by_default() -> InquireResult<()> {
+    for (prompt, expected) in [
+        (DateSelect::new("Question"), false),
+        (DateSelect::new("Question").with_week_numbers(true), true),
+    ] {
+        let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+        prompt.prompt_with_backend(&mut backend)?;
+
+        assert!(backend.frames()[0].tokens().iter().any(|t| matches!(
+            t,
+            Token::Calendar { week_numbers, .. } if *week_numbers == expected
+        )));
+    }
+
+    Ok(())
+}
+
+fn space() -> Key {
+    Key::Char(' ', KeyModifiers::NONE)
+}
+
+#[test]
+fn multiple_dates_are_returned_sorted() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Left(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        space(),
+        Key::Down(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+    ]);
+
+    let answer = range_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(13), range_date(15), range_date(20)], answer);
+
+    Ok(())
+}
+
+#[test]
+fn toggling_a_date_twice_removes_it() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        space(),
+        Key::Enter,
+    ]);
+
+    let answer = range_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(15)], answer);
+
+    Ok(())
+}
+
+#[test]
+fn toggled_dates_are_sent_to_the_backend() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+    ]);
+
+    range_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    let expected = vec![range_date(15), range_date(16)];
+    assert!(backend.frames()[3].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar { toggled_dates, .. } if *toggled_dates == expected
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn empty_list_of_dates_can_be_submitted() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let answer = range_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    assert!(answer.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn toggling_more_dates_than_the_maximum_is_refused() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+    ]);
+
+    let answer = range_prompt()
+        .with_max_selections(2)
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(15), range_date(16)], answer);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom(
+        "You can select at most 2 dates".into(),
+    ));
+    assert!(backend.frames()[5].has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn submitting_less_dates_than_the_minimum_displays_an_error() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, space(), Key::Enter]);
+
+    let answer = range_prompt()
+        .with_min_selections(1)
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(15)], answer);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Please select at least 1 date".into()));
+    assert!(backend.frames()[1].has_token(&error));
+    assert!(!backend.frames()[2].has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn min_selections_greater_than_max_is_invalid() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let answer = range_prompt()
+        .with_min_selections(3)
+        .with_max_selections(2)
+        .prompt_multiple_with_backend(&mut backend);
+
+    assert!(matches!(answer, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn range_options_are_invalid_outside_of_range_mode() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let single = range_prompt()
+        .with_max_range_span(3)
+        .prompt_with_backend(&mut backend);
+    assert!(matches!(single, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let multiple = range_prompt()
+        .with_range_formatter(&|start, _| start.to_string())
+        .prompt_multiple_with_backend(&mut backend);
+    assert!(matches!(
+        multiple,
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn multiple_options_are_invalid_outside_of_multiple_mode() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let single = range_prompt()
+        .with_min_selections(1)
+        .prompt_with_backend(&mut backend);
+    assert!(matches!(single, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Enter]);
+    let range = range_prompt()
+        .with_multi_date_validator(|_: &[NaiveDate]| Ok(Validation::Valid))
+        .prompt_range_with_backend(&mut backend);
+    assert!(matches!(range, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn date_validators_are_applied_when_toggling() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+    ]);
+
+    let answer = range_prompt()
+        .with_validator(|d: NaiveDate| match d.day() {
+            15 => Ok(Validation::Invalid("Taken".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(16)], answer);
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom("Taken".into())))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn multi_date_validators_are_applied_on_submission() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+        space(),
+        Key::Enter,
+    ]);
+
+    let answer = range_prompt()
+        .with_multi_date_validator(|dates: &[NaiveDate]| {
+            if dates
+                .windows(2)
+                .any(|w| w[1] - w[0] == chrono::Duration::days(1))
+            {
+                Ok(Validation::Invalid("No consecutive shifts".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(15)], answer);
+    assert!(
+        backend.frames()[4].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "No consecutive shifts".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn typed_dates_are_toggled_on_enter_in_multiple_mode() -> InquireResult<()> {
+    let mut keys = vec![space()];
+    keys.extend(typed_keys("next friday"));
+    keys.push(Key::Enter);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let answer = typed_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    let next_friday =
+        crate::parser::DEFAULT_DATE_INPUT_PARSER("next friday", get_current_date()).unwrap();
+    assert_eq!(2, answer.len());
+    assert!(answer.contains(&range_date(13)));
+    assert!(answer.contains(&next_friday));
+
+    Ok(())
+}
+
+#[test]
+fn space_does_nothing_in_single_mode() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![space(), Key::Enter]);
+
+    let answer = range_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(15), answer);
+
+    Ok(())
+}
+
+fn weekend_warning_prompt<'a>() -> DateSelect<'a> {
+    range_prompt().with_validator(|date: NaiveDate| match date.weekday() {
+        chrono::Weekday::Sat | chrono::Weekday::Sun => {
+            Ok(Validation::Warning("That's a weekend".into()))
+        }
+        _ => Ok(Validation::Valid),
+    })
+}
+
+fn weekend_warning() -> Token {
+    Token::WarningMessage(ErrorMessage::Custom("That's a weekend".into()))
+}
+
+#[test]
+fn date_with_a_warning_is_accepted_on_the_second_submission() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Enter]);
+
+    let answer = weekend_warning_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(15), answer);
+    assert!(backend.frames()[1].has_token(&weekend_warning()));
+
+    Ok(())
+}
+
+#[test]
+fn moving_to_another_date_discards_the_displayed_warning() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let answer = weekend_warning_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(range_date(14), answer);
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&weekend_warning()));
+    assert!(!frames[2].has_token(&weekend_warning()));
+    assert!(frames[3].has_token(&weekend_warning()));
+
+    Ok(())
+}
+
+#[test]
+fn range_warnings_are_accepted_on_the_second_submission() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let range = range_prompt()
+        .with_range_validator(
+            |start: NaiveDate, end: NaiveDate| match (end - start).num_days() {
+                0..=6 => Ok(Validation::Warning("Less than a week".into())),
+                _ => Ok(Validation::Valid),
+            },
+        )
+        .prompt_range_with_backend(&mut backend)?;
+
+    assert_eq!((range_date(15), range_date(16)), range);
+    assert!(
+        backend.frames()[3].has_token(&Token::WarningMessage(ErrorMessage::Custom(
+            "Less than a week".into()
+        )))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dates_with_a_warning_are_toggled_in_the_second_time() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![space(), space(), Key::Enter]);
+
+    let answer = weekend_warning_prompt().prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![range_date(15)], answer);
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&weekend_warning()));
+    assert!(!frames[2].has_token(&weekend_warning()));
+
+    Ok(())
+}
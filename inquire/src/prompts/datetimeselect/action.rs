@@ -0,0 +1,90 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::DateTimeSelectConfig;
+
+/// Set of actions for a DateTimeSelectPrompt.
+///
+/// Arrow actions move through the calendar when it is focused, and move between
+/// or adjust the time fields when the time is focused.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateTimeSelectPromptAction {
+    /// Move the focus between the calendar and the time.
+    SwitchFocus,
+    /// Move to the previous day, or to the previous time field.
+    MoveLeft,
+    /// Move to the next day, or to the next time field.
+    MoveRight,
+    /// Move to the previous week, or increase the focused time field.
+    MoveUp,
+    /// Move to the next week, or decrease the focused time field.
+    MoveDown,
+    /// Move day cursor to the previous month.
+    GoToPrevMonth,
+    /// Move day cursor to the next month.
+    GoToNextMonth,
+    /// Move day cursor to the previous year.
+    GoToPrevYear,
+    /// Move day cursor to the next year.
+    GoToNextYear,
+}
+
+impl InnerAction for DateTimeSelectPromptAction {
+    type Config = DateTimeSelectConfig;
+
+    fn from_key(key: Key, _: &DateTimeSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Tab => Self::SwitchFocus,
+
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::MoveLeft,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::MoveRight,
+
+            Key::Up(KeyModifiers::NONE) // standard
+            | Key::Char('p', KeyModifiers::CONTROL) // emacs
+            | Key::Char('k', KeyModifiers::NONE) // vim
+            => Self::MoveUp,
+
+            Key::Down(KeyModifiers::NONE) // standard
+            | Key::Char('n', KeyModifiers::CONTROL) // emacs
+            | Key::Char('j', KeyModifiers::NONE) // vim
+            => Self::MoveDown,
+
+            Key::PageUp(KeyModifiers::NONE) // standard
+            | Key::Char('[', KeyModifiers::NONE) // alternative when page up is not available
+            | Key::Left(_) // alternative 2, when the left above with no modifiers is not matched
+            | Key::Char('v' | 'V', KeyModifiers::ALT | KeyModifiers::META) // emacs
+            | Key::Char('b' | 'B', _) // vim, ideally ctrl-b should be used, but it's not available due to emacs
+            => Self::GoToPrevMonth,
+
+            Key::PageDown(KeyModifiers::NONE) // standard
+            | Key::Char(']', KeyModifiers::NONE) // alternative when page down is not available
+            | Key::Right(_) // alternative 2, when the right above with no modifiers is not matched
+            | Key::Char('v' | 'V', KeyModifiers::CONTROL) // emacs
+            | Key::Char('f' | 'F', _) // vim, ideally ctrl-f should be used, but it's not available due to emacs
+            => Self::GoToNextMonth,
+
+            Key::PageUp(_) // standard, when the above with no modifiers is not matched
+            | Key::Char('{' | '[', _) // alternative when page up is not available
+            | Key::Up(_) // alternative 2, when the up above with no modifiers is not matched
+            => Self::GoToPrevYear,
+
+            Key::PageDown(_) // standard, when the above with no modifiers is not matched
+            | Key::Char('}' | ']', _) // alternative when page down is not available
+            | Key::Down(_) // alternative 2, when the down above with no modifiers is not matched
+            => Self::GoToNextYear,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+
+use crate::{ClockFormat, DateTimeSelect};
+
+/// Configuration settings used in the execution of a DateTimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct DateTimeSelectConfig {
+    /// Min date and time allowed to be selected.
+    pub min_datetime: Option<NaiveDateTime>,
+
+    /// Max date and time allowed to be selected.
+    pub max_datetime: Option<NaiveDateTime>,
+
+    /// Weekday to start the week on.
+    pub week_start: chrono::Weekday,
+
+    /// Whether the ISO week numbers are displayed.
+    pub show_week_numbers: bool,
+
+    /// Whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub clock_format: ClockFormat,
+
+    /// Whether the seconds can be adjusted.
+    pub show_seconds: bool,
+
+    /// Number of minutes moved at a time when adjusting the minutes.
+    pub minute_step: u32,
+}
+
+impl From<&DateTimeSelect<'_>> for DateTimeSelectConfig {
+    fn from(value: &DateTimeSelect<'_>) -> Self {
+        Self {
+            min_datetime: value.min_datetime,
+            max_datetime: value.max_datetime,
+            week_start: value.week_start,
+            show_week_numbers: value.show_week_numbers,
+            clock_format: value.clock_format,
+            show_seconds: value.show_seconds,
+            minute_step: value.minute_step,
+        }
+    }
+}
@@ -0,0 +1,343 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::NaiveDateTime;
+
+use crate::{
+    config::get_configuration,
+    date_utils::{get_current_date, get_current_time},
+    error::{InquireError, InquireResult},
+    formatter::DateTimeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{
+        date::{DateSelectBackend, TimeSelectBackend},
+        Backend, RenderConfig,
+    },
+    validator::DateTimeValidator,
+    CalendarLocale, ClockFormat,
+};
+
+use self::prompt::DateTimeSelectPrompt;
+
+/// Prompt that allows user to select a date from an interactive calendar along with a time of the day. Available via the `date` feature.
+///
+/// The calendar works just like the one in [`DateSelect`](crate::DateSelect) prompts, with the time fields
+/// of a [`TimeSelect`](crate::TimeSelect) prompt displayed right below it. By default, the initial selection is the current date and time.
+///
+/// The calendar is focused first, and the user can press tab to move the focus between the calendar and the time:
+/// - When the calendar is focused, the arrows move through the days and weeks of the calendar.
+/// - When the time is focused, left and right arrows move between the time fields and up and down arrows adjust the focused field.
+/// - In both cases, `[]{}` or page up and down move through months and years.
+///
+/// Finally, the user selects a date and time by pressing the enter key.
+///
+/// `DateTimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Default value selected when the prompt is displayed and the one selected if the user submits without any previous actions. Current date and time by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats to "Month Day, Year" followed by the time as displayed by the prompt by default.
+/// - **Validators**: Custom validators to the user's selected date and time, displaying an error message if it does not pass the requirements.
+/// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
+/// - **Locale** and **week numbers**: Same as in [DateSelect](crate::DateSelect), setting the language of the calendar and whether ISO week numbers are displayed.
+/// - **Clock format**: Whether hours are displayed in the 24-hour clock, the default, or in the 12-hour clock with an AM/PM field.
+/// - **Seconds**: Whether the seconds can be adjusted, hidden and set to zero by default.
+/// - **Minute step**: Number of minutes moved at a time when adjusting the minutes, 1 by default.
+/// - **Min and max datetime**: Inclusive boundaries of allowed dates and times. If any boundary is set, the user will not be able to move past them.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use inquire::DateTimeSelect;
+///
+/// let now = NaiveDate::from_ymd_opt(2021, 8, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+///
+/// let departure = DateTimeSelect::new("When do you want to depart?")
+///     .with_starting_datetime(now)
+///     .with_min_datetime(now)
+///     .with_minute_step(15)
+///     .prompt();
+///
+/// match departure {
+///     Ok(_) => println!("No trains available at this time."),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DateTimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// First day of the week when displaying week rows.
+    pub week_start: chrono::Weekday,
+
+    /// Month names and weekday abbreviations displayed in the calendar.
+    pub locale: CalendarLocale<'a>,
+
+    /// Whether the ISO week numbers are displayed to the left of each week.
+    pub show_week_numbers: bool,
+
+    /// Starting date and time to be selected.
+    pub starting_datetime: NaiveDateTime,
+
+    /// Min date and time allowed to be selected.
+    pub min_datetime: Option<NaiveDateTime>,
+
+    /// Max date and time allowed to be selected.
+    pub max_datetime: Option<NaiveDateTime>,
+
+    /// Whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub clock_format: ClockFormat,
+
+    /// Whether the seconds can be adjusted. When false, seconds are always zero.
+    pub show_seconds: bool,
+
+    /// Number of minutes moved at a time when adjusting the minutes, between 1 and 59.
+    pub minute_step: u32,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, the date is formatted as "Month Day, Year" followed by the time as displayed by the prompt.
+    pub formatter: Option<DateTimeFormatter<'a>>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn DateTimeValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> DateTimeSelect<'a> {
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some(
+        "arrows to move, []{} move months and years, tab to switch date/time, enter to select",
+    );
+
+    /// Default validators added to the [DateTimeSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateTimeValidator>> = vec![];
+
+    /// Default week start.
+    pub const DEFAULT_WEEK_START: chrono::Weekday = chrono::Weekday::Sun;
+
+    /// Default locale, [English](CalendarLocale::ENGLISH).
+    pub const DEFAULT_LOCALE: CalendarLocale<'a> = CalendarLocale::ENGLISH;
+
+    /// Default value of whether week numbers are displayed, false.
+    pub const DEFAULT_SHOW_WEEK_NUMBERS: bool = false;
+
+    /// Default clock format, the 24-hour clock.
+    pub const DEFAULT_CLOCK_FORMAT: ClockFormat = ClockFormat::TwentyFourHour;
+
+    /// Default value of whether seconds are displayed, false.
+    pub const DEFAULT_SHOW_SECONDS: bool = false;
+
+    /// Default minute step.
+    pub const DEFAULT_MINUTE_STEP: u32 = 1;
+
+    /// Default min datetime.
+    pub const DEFAULT_MIN_DATETIME: Option<NaiveDateTime> = None;
+
+    /// Default max datetime.
+    pub const DEFAULT_MAX_DATETIME: Option<NaiveDateTime> = None;
+
+    /// Creates a [DateTimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            week_start: Self::DEFAULT_WEEK_START,
+            locale: Self::DEFAULT_LOCALE,
+            show_week_numbers: Self::DEFAULT_SHOW_WEEK_NUMBERS,
+            starting_datetime: get_current_date()
+                .and_time(get_current_time(Self::DEFAULT_SHOW_SECONDS)),
+            min_datetime: Self::DEFAULT_MIN_DATETIME,
+            max_datetime: Self::DEFAULT_MAX_DATETIME,
+            clock_format: Self::DEFAULT_CLOCK_FORMAT,
+            show_seconds: Self::DEFAULT_SHOW_SECONDS,
+            minute_step: Self::DEFAULT_MINUTE_STEP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default date and time of the prompt. Equivalent to [DateTimeSelect::with_starting_datetime](DateTimeSelect::with_starting_datetime).
+    pub fn with_default(self, default: NaiveDateTime) -> Self {
+        self.with_starting_datetime(default)
+    }
+
+    /// Sets the starting date and time. Equivalent to [DateTimeSelect::with_default](DateTimeSelect::with_default).
+    pub fn with_starting_datetime(mut self, starting_datetime: NaiveDateTime) -> Self {
+        self.starting_datetime = starting_datetime;
+        self
+    }
+
+    /// Sets the week start.
+    pub fn with_week_start(mut self, week_start: chrono::Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the locale of the calendar, along with the week start to the
+    /// locale's default one. Call [with_week_start](Self::with_week_start)
+    /// afterwards to override it.
+    pub fn with_locale(mut self, locale: CalendarLocale<'a>) -> Self {
+        self.locale = locale;
+        self.week_start = locale.week_start;
+        self
+    }
+
+    /// Sets whether the ISO week numbers are displayed to the left of each week.
+    pub fn with_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
+
+    /// Sets the min date and time.
+    pub fn with_min_datetime(mut self, min_datetime: NaiveDateTime) -> Self {
+        self.min_datetime = Some(min_datetime);
+        self
+    }
+
+    /// Sets the max date and time.
+    pub fn with_max_datetime(mut self, max_datetime: NaiveDateTime) -> Self {
+        self.max_datetime = Some(max_datetime);
+        self
+    }
+
+    /// Sets whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub fn with_clock_format(mut self, clock_format: ClockFormat) -> Self {
+        self.clock_format = clock_format;
+        self
+    }
+
+    /// Sets whether the seconds can be adjusted.
+    pub fn with_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the number of minutes moved at a time when adjusting the minutes,
+    /// e.g. 15 to move between quarters of an hour.
+    pub fn with_minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as business hours on weekdays.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: DateTimeValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as business hours on weekdays.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn DateTimeValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: DateTimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveDateTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveDateTime> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B>(self, backend: &mut B) -> InquireResult<NaiveDateTime>
+    where
+        B: DateSelectBackend + TimeSelectBackend,
+    {
+        DateTimeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
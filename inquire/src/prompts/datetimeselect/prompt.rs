@@ -0,0 +1,284 @@
+use std::cmp::{max, min, Ordering};
+
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime};
+
+use crate::{
+    date_utils::{
+        format_time, get_current_date, get_month, get_time_fields, spin_time, truncate_time,
+        TimeField,
+    },
+    error::InquireResult,
+    formatter::{DateTimeFormatter, DEFAULT_DATE_FORMATTER},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::date::{CalendarView, DateSelectBackend, TimeSelectBackend},
+    validator::{validate_all, DateTimeValidator, ErrorMessage, Validation},
+    CalendarLocale, DateTimeSelect, InquireError,
+};
+
+use super::{action::DateTimeSelectPromptAction, config::DateTimeSelectConfig};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Focus {
+    Calendar,
+    Time,
+}
+
+pub struct DateTimeSelectPrompt<'a> {
+    message: &'a str,
+    config: DateTimeSelectConfig,
+    current: NaiveDateTime,
+    focus: Focus,
+    fields: Vec<TimeField>,
+    focused_field: usize,
+    help_message: Option<&'a str>,
+    locale: CalendarLocale<'a>,
+    formatter: Option<DateTimeFormatter<'a>>,
+    validators: Vec<Box<dyn DateTimeValidator>>,
+    error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
+}
+
+impl<'a> DateTimeSelectPrompt<'a> {
+    pub fn new(ds: DateTimeSelect<'a>) -> InquireResult<Self> {
+        if ds.minute_step == 0 || ds.minute_step >= 60 {
+            return Err(InquireError::InvalidConfiguration(
+                "Minute step must be between 1 and 59".into(),
+            ));
+        }
+
+        let starting_datetime = ds
+            .starting_datetime
+            .date()
+            .and_time(truncate_time(ds.starting_datetime.time(), ds.show_seconds));
+
+        if let Some(min_datetime) = ds.min_datetime {
+            if min_datetime > starting_datetime {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min datetime can not be greater than starting datetime".into(),
+                ));
+            }
+        }
+        if let Some(max_datetime) = ds.max_datetime {
+            if max_datetime < starting_datetime {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max datetime can not be smaller than starting datetime".into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            message: ds.message,
+            current: starting_datetime,
+            focus: Focus::Calendar,
+            fields: get_time_fields(ds.clock_format, ds.show_seconds),
+            focused_field: 0,
+            config: (&ds).into(),
+            help_message: ds.help_message,
+            locale: ds.locale,
+            formatter: ds.formatter,
+            validators: ds.validators,
+            error: None,
+            warning: None,
+        })
+    }
+
+    fn switch_focus(&mut self) -> ActionResult {
+        self.focus = match self.focus {
+            Focus::Calendar => Focus::Time,
+            Focus::Time => Focus::Calendar,
+        };
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn shift_date(&mut self, duration: Duration) -> ActionResult {
+        let new_date = self
+            .current
+            .date()
+            .checked_add_signed(duration)
+            .unwrap_or(self.current.date());
+
+        self.update_date(new_date)
+    }
+
+    fn shift_months(&mut self, qty: i32) -> ActionResult {
+        let date = self.current.date();
+        let new_date = match qty.cmp(&0) {
+            Ordering::Greater | Ordering::Equal => date
+                .checked_add_months(Months::new(qty as u32))
+                .unwrap_or(NaiveDate::MAX),
+            Ordering::Less => date
+                .checked_sub_months(Months::new((-qty) as u32))
+                .unwrap_or(NaiveDate::MIN),
+        };
+
+        self.update_date(new_date)
+    }
+
+    fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        self.update(new_date.and_time(self.current.time()))
+    }
+
+    fn move_field_focus(&mut self, forward: bool) -> ActionResult {
+        let new_focus = if forward {
+            min(self.focused_field + 1, self.fields.len() - 1)
+        } else {
+            self.focused_field.saturating_sub(1)
+        };
+
+        if new_focus == self.focused_field {
+            return ActionResult::Clean;
+        }
+
+        self.focused_field = new_focus;
+        ActionResult::NeedsRedraw
+    }
+
+    fn spin(&mut self, forward: bool) -> ActionResult {
+        let field = self.fields[self.focused_field];
+        let new_time = spin_time(self.current.time(), field, forward, self.config.minute_step);
+
+        self.update(self.current.date().and_time(new_time))
+    }
+
+    fn update(&mut self, mut new_datetime: NaiveDateTime) -> ActionResult {
+        if let Some(min_datetime) = self.config.min_datetime {
+            new_datetime = max(new_datetime, min_datetime);
+        }
+        if let Some(max_datetime) = self.config.max_datetime {
+            new_datetime = min(new_datetime, max_datetime);
+        }
+
+        if new_datetime == self.current {
+            return ActionResult::Clean;
+        }
+
+        self.current = new_datetime;
+        self.warning = None;
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let answer = self.cur_answer();
+
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
+    }
+
+    fn cur_answer(&self) -> NaiveDateTime {
+        self.current
+    }
+}
+
+impl<'a, B> Prompt<B> for DateTimeSelectPrompt<'a>
+where
+    B: DateSelectBackend + TimeSelectBackend,
+{
+    type Config = DateTimeSelectConfig;
+    type InnerAction = DateTimeSelectPromptAction;
+    type Output = NaiveDateTime;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &NaiveDateTime) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(*answer),
+            None => format!(
+                "{} {}",
+                DEFAULT_DATE_FORMATTER(answer.date()),
+                format_time(
+                    answer.time(),
+                    self.config.clock_format,
+                    self.config.show_seconds
+                )
+            ),
+        }
+    }
+
+    fn config(&self) -> &DateTimeSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveDateTime>> {
+        let validation = self.validate_current_answer()?;
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(self.cur_answer()));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: DateTimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let day =
+            Duration::try_days(1).expect("unexpected overflow when calculating duration of 1 day");
+        let week = Duration::try_weeks(1)
+            .expect("unexpected overflow when calculating duration of 1 week");
+
+        let result = match (action, self.focus) {
+            (DateTimeSelectPromptAction::SwitchFocus, _) => self.switch_focus(),
+            (DateTimeSelectPromptAction::MoveLeft, Focus::Calendar) => self.shift_date(-day),
+            (DateTimeSelectPromptAction::MoveRight, Focus::Calendar) => self.shift_date(day),
+            (DateTimeSelectPromptAction::MoveUp, Focus::Calendar) => self.shift_date(-week),
+            (DateTimeSelectPromptAction::MoveDown, Focus::Calendar) => self.shift_date(week),
+            (DateTimeSelectPromptAction::MoveLeft, Focus::Time) => self.move_field_focus(false),
+            (DateTimeSelectPromptAction::MoveRight, Focus::Time) => self.move_field_focus(true),
+            (DateTimeSelectPromptAction::MoveUp, Focus::Time) => self.spin(true),
+            (DateTimeSelectPromptAction::MoveDown, Focus::Time) => self.spin(false),
+            (DateTimeSelectPromptAction::GoToPrevYear, _) => self.shift_months(-12),
+            (DateTimeSelectPromptAction::GoToNextYear, _) => self.shift_months(12),
+            (DateTimeSelectPromptAction::GoToPrevMonth, _) => self.shift_months(-1),
+            (DateTimeSelectPromptAction::GoToNextMonth, _) => self.shift_months(1),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        }
+
+        backend.render_calendar_prompt(prompt, None)?;
+
+        let current_date = self.current.date();
+        backend.render_calendar(CalendarView {
+            month: get_month(current_date.month()),
+            year: current_date.year(),
+            week_start: self.config.week_start,
+            locale: &self.locale,
+            show_week_numbers: self.config.show_week_numbers,
+            today: get_current_date(),
+            selected_date: current_date,
+            range: None,
+            toggled_dates: &[],
+            min_date: self.config.min_datetime.map(|d| d.date()),
+            max_date: self.config.max_datetime.map(|d| d.date()),
+            disabled_dates: None,
+            date_styler: None,
+        })?;
+
+        let focused_field = match self.focus {
+            Focus::Calendar => None,
+            Focus::Time => Some(self.fields[self.focused_field]),
+        };
+        backend.render_time(
+            self.current.time(),
+            &self.fields,
+            focused_field,
+            self.config.clock_format,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::{
+    date_utils::TimeField,
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    ClockFormat, DateTimeSelect, InquireError,
+};
+
+fn datetime(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2023, 1, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn default<'a>() -> DateTimeSelect<'a> {
+    DateTimeSelect::new("Question?").with_starting_datetime(datetime(15, 14, 30))
+}
+
+macro_rules! datetime_test {
+    ($name:ident,$input:expr,$output:expr) => {
+        datetime_test! {$name, $input, $output, default()}
+    };
+
+    ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+        #[test]
+        fn $name() -> InquireResult<()> {
+            let mut backend = FakeBackend::new($input);
+
+            let ans = $prompt.prompt_with_backend(&mut backend)?;
+
+            assert_eq!($output, ans);
+
+            Ok(())
+        }
+    };
+}
+
+const UP: Key = Key::Up(KeyModifiers::NONE);
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+const LEFT: Key = Key::Left(KeyModifiers::NONE);
+const RIGHT: Key = Key::Right(KeyModifiers::NONE);
+
+datetime_test!(starting_datetime, vec![Key::Enter], datetime(15, 14, 30));
+
+datetime_test!(
+    calendar_navigation_keeps_the_time,
+    vec![RIGHT, RIGHT, DOWN, LEFT, Key::Enter],
+    datetime(23, 14, 30)
+);
+
+datetime_test!(
+    arrows_adjust_the_time_when_it_is_focused,
+    vec![Key::Tab, UP, RIGHT, DOWN, DOWN, Key::Enter],
+    datetime(15, 15, 0),
+    default().with_minute_step(15)
+);
+
+datetime_test!(
+    focus_goes_back_to_the_calendar,
+    vec![Key::Tab, UP, Key::Tab, UP, Key::Enter],
+    datetime(8, 15, 30)
+);
+
+datetime_test!(
+    months_can_be_changed_while_the_time_is_focused,
+    vec![Key::Tab, Key::PageDown(KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2023, 2, 15)
+        .unwrap()
+        .and_hms_opt(14, 30, 0)
+        .unwrap()
+);
+
+datetime_test!(
+    hours_do_not_carry_over_to_the_date,
+    vec![Key::Tab, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, Key::Enter],
+    datetime(15, 0, 30)
+);
+
+datetime_test!(
+    bounds_consider_date_and_time,
+    vec![LEFT, Key::Tab, DOWN, DOWN, DOWN, Key::Enter],
+    datetime(14, 12, 0),
+    default().with_min_datetime(datetime(14, 12, 0))
+);
+
+datetime_test!(
+    moving_past_the_max_date_clamps_the_time,
+    vec![DOWN, Key::Enter],
+    datetime(20, 9, 0),
+    default().with_max_datetime(datetime(20, 9, 0))
+);
+
+#[test]
+fn starting_datetime_out_of_bounds_is_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_max_datetime(datetime(15, 14, 0))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn calendar_and_time_are_rendered_with_the_focus() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Tab, Key::Enter]);
+
+    default()
+        .with_clock_format(ClockFormat::TwelveHour)
+        .prompt_with_backend(&mut backend)?;
+
+    let frames = backend.frames();
+    let time = |focused| Token::Time {
+        time: NaiveTime::from_hms_opt(14, 30, 0).unwrap(),
+        focused,
+        clock_format: ClockFormat::TwelveHour,
+        with_seconds: false,
+    };
+
+    assert!(frames[0]
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::Calendar { selected_date, .. } if *selected_date == datetime(15, 0, 0).date())));
+    assert!(frames[0].has_token(&time(None)));
+    assert!(frames[1].has_token(&time(Some(TimeField::Hour))));
+
+    Ok(())
+}
+
+#[test]
+fn validator_error_is_displayed_until_a_valid_datetime_is_submitted() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, RIGHT, Key::Enter]);
+
+    let validator = |d: NaiveDateTime| {
+        if d.date() == datetime(15, 0, 0).date() {
+            Ok(Validation::Invalid("Fully booked".into()))
+        } else {
+            Ok(Validation::Valid)
+        }
+    };
+
+    let ans = default()
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(datetime(16, 14, 30), ans);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Fully booked".into()));
+    assert!(backend.frames()[1].has_token(&error));
+    assert!(!backend.frames().last().unwrap().has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn answer_is_formatted_with_date_and_time_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    default()
+        .with_clock_format(ClockFormat::TwelveHour)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question?".into(),
+            "January 15, 2023 02:30 PM".into()
+        )));
+
+    Ok(())
+}
@@ -1,8 +1,15 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(feature = "serde")]
+mod structured;
+#[cfg(test)]
+#[cfg(unix)]
+mod test;
 
 pub use action::*;
+#[cfg(feature = "serde")]
+pub use structured::*;
 
 use std::{
     env,
@@ -44,6 +51,8 @@ static DEFAULT_EDITOR: Lazy<OsString> = Lazy::new(get_default_editor_command);
 /// - **File extension**: Custom extension for the temporary file, useful as a proxy for proper syntax highlighting for example.
 /// - **Predefined text**: Pre-defined text to be written to the temporary file before the user is allowed to edit it.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Reopen on error**: Whether the editor is reopened when the input fails validation, with the error written at the top of the file as comment lines. Disabled by default.
+/// - **Comment prefix**: Prefix of the lines with the error written at the top of the file when reopening on errors, `#` by default. Only these lines are removed from the submitted text.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, a successfully submitted answer is displayed to the user simply as `<received>`.
 #[derive(Clone)]
@@ -79,6 +88,24 @@ pub struct Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Whether the editor is reopened when the submitted text fails validation.
+    ///
+    /// The error is written at the top of the file as lines starting with the
+    /// [`comment_prefix`], and the editor is reopened until the text is valid or
+    /// the user closes it without changes, going back to the prompt.
+    ///
+    /// [`comment_prefix`]: Self::comment_prefix
+    pub reopen_on_error: bool,
+
+    /// Prefix of the comment lines with the error written at the top of the file when
+    /// [`reopen_on_error`] is enabled.
+    ///
+    /// Only the lines written by the prompt are removed from the submitted text, other
+    /// lines starting with the prefix are kept.
+    ///
+    /// [`reopen_on_error`]: Self::reopen_on_error
+    pub comment_prefix: &'a str,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -100,6 +127,12 @@ impl<'a> Editor<'a> {
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
+    /// Default value of whether the editor is reopened on validation errors, false.
+    pub const DEFAULT_REOPEN_ON_ERROR: bool = false;
+
+    /// Default prefix of comment lines, `#` as in git commit messages.
+    pub const DEFAULT_COMMENT_PREFIX: &'a str = "#";
+
     /// Creates a [Editor] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -111,6 +144,8 @@ impl<'a> Editor<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
+            reopen_on_error: Self::DEFAULT_REOPEN_ON_ERROR,
+            comment_prefix: Self::DEFAULT_COMMENT_PREFIX,
             render_config: RenderConfig::default(),
         }
     }
@@ -183,6 +218,22 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets whether the editor is reopened when the submitted text fails validation,
+    /// with the error written at the top of the file as comment lines.
+    ///
+    /// The comment lines written by the prompt are removed from the submitted text
+    /// before it is validated, while the user's own lines are kept as they are.
+    pub fn with_reopen_on_error(mut self, reopen_on_error: bool) -> Self {
+        self.reopen_on_error = reopen_on_error;
+        self
+    }
+
+    /// Sets the prefix of comment lines, e.g. `//` when editing text where `#` is meaningful.
+    pub fn with_comment_prefix(mut self, comment_prefix: &'a str) -> Self {
+        self.comment_prefix = comment_prefix;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
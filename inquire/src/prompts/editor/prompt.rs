@@ -7,7 +7,7 @@ use crate::{
     formatter::StringFormatter,
     prompts::prompt::{ActionResult, Prompt},
     ui::EditorBackend,
-    validator::{ErrorMessage, StringValidator, Validation},
+    validator::{validate_all, ErrorMessage, StringValidator, Validation},
     Editor, InquireError,
 };
 
@@ -20,7 +20,14 @@ pub struct EditorPrompt<'a> {
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
     tmp_file: NamedTempFile,
+    reopen_on_error: bool,
+    comment_prefix: &'a str,
+    /// Message written to the file for [`ErrorMessage::Default`] errors.
+    default_error_message: &'a str,
+    /// Comment lines last inserted at the top of the file, replaced on every new error.
+    annotation: Option<String>,
 }
 
 impl<'a> From<&'a str> for Editor<'a> {
@@ -38,7 +45,12 @@ impl<'a> EditorPrompt<'a> {
             formatter: so.formatter,
             validators: so.validators,
             error: None,
+            warning: None,
             tmp_file: Self::create_file(so.file_extension, so.predefined_text)?,
+            reopen_on_error: so.reopen_on_error,
+            comment_prefix: so.comment_prefix,
+            default_error_message: so.render_config.error_message.default_message,
+            annotation: None,
         })
     }
 
@@ -70,30 +82,63 @@ impl<'a> EditorPrompt<'a> {
         Ok(())
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
-        if self.validators.is_empty() {
-            return Ok(Validation::Valid);
-        }
-
-        let cur_answer = self.cur_answer()?;
-        for validator in &self.validators {
-            match validator.validate(&cur_answer) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
-            }
-        }
-
-        Ok(Validation::Valid)
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
     }
 
     fn cur_answer(&self) -> InquireResult<String> {
-        let mut submission = fs::read_to_string(self.tmp_file.path())?;
+        let content = fs::read_to_string(self.tmp_file.path())?;
+
+        let mut submission = String::from(self.strip_annotation(&content));
+
         let len = submission.trim_end_matches(&['\n', '\r'][..]).len();
         submission.truncate(len);
 
         Ok(submission)
     }
+
+    /// Removes the annotation lines last inserted at the top of the file, leaving
+    /// the ones the user edited, and any other comment line, untouched.
+    fn strip_annotation<'c>(&self, mut content: &'c str) -> &'c str {
+        if let Some(annotation) = &self.annotation {
+            for line in annotation.split_inclusive('\n') {
+                if let Some(rest) = content.strip_prefix(line) {
+                    content = rest;
+                }
+            }
+        }
+
+        content
+    }
+
+    /// Writes the validation error as comment lines at the top of the file,
+    /// replacing the ones written for the previous error, and returns the new
+    /// content of the file.
+    fn annotate_file(&mut self, error: &ErrorMessage) -> InquireResult<String> {
+        let content = fs::read_to_string(self.tmp_file.path())?;
+        let content = self.strip_annotation(&content);
+
+        let message = match error {
+            ErrorMessage::Default => self.default_error_message,
+            ErrorMessage::Custom(msg) => msg,
+        };
+
+        let prefix = self.comment_prefix;
+        let mut annotation = String::new();
+        for line in message.lines() {
+            annotation.push_str(&format!("{prefix} {line}\n"));
+        }
+        annotation.push_str(&format!(
+            "{prefix} These lines are removed from the submitted text.\n"
+        ));
+
+        let content = format!("{annotation}{content}");
+        fs::write(self.tmp_file.path(), &content)?;
+        self.annotation = Some(annotation);
+
+        Ok(content)
+    }
 }
 
 impl<'a, Backend> Prompt<Backend> for EditorPrompt<'a>
@@ -117,21 +162,47 @@ where
     }
 
     fn submit(&mut self) -> InquireResult<Option<String>> {
-        let answer = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.cur_answer()?),
-            Validation::Invalid(msg) => {
-                self.error = Some(msg);
-                None
+        loop {
+            let answer = self.cur_answer()?;
+
+            let validation = self.validate_answer(&answer)?;
+            if validation.accepts(self.warning.as_ref()) {
+                return Ok(Some(answer));
             }
-        };
 
-        Ok(answer)
+            // Warnings are displayed in the prompt without reopening the editor,
+            // the next submission accepts the answer.
+            let msg = match validation.into_messages() {
+                (Some(msg), _) => msg,
+                (None, warning) => {
+                    self.error = None;
+                    self.warning = warning;
+                    return Ok(None);
+                }
+            };
+
+            if self.reopen_on_error {
+                let annotated = self.annotate_file(&msg)?;
+                self.run_editor()?;
+
+                // Reopening the editor stops once the user closes it without
+                // changes, going back to the prompt where they can cancel.
+                if fs::read_to_string(self.tmp_file.path())? != annotated {
+                    continue;
+                }
+            }
+
+            self.error = Some(msg);
+            self.warning = None;
+            return Ok(None);
+        }
     }
 
     fn handle(&mut self, action: EditorPromptAction) -> InquireResult<ActionResult> {
         match action {
             EditorPromptAction::OpenEditor => {
                 self.run_editor()?;
+                self.warning = None;
                 Ok(ActionResult::NeedsRedraw)
             }
         }
@@ -142,6 +213,8 @@ where
 
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
         }
 
         let path = Path::new(&self.config.editor_command);
@@ -0,0 +1,335 @@
+use std::ffi::OsStr;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    error::{CustomUserError, InquireError, InquireResult},
+    formatter::StringFormatter,
+    terminal::get_default_terminal,
+    ui::{Backend, EditorBackend, RenderConfig},
+    validator::{validate_all, CustomTypeValidator, ErrorMessage, Validation},
+    Editor,
+};
+
+/// Text format of the content edited in a [`StructuredEditor`] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StructuredFormat {
+    /// JSON, pretty-printed when serialized.
+    Json,
+    /// TOML.
+    Toml,
+    /// YAML.
+    Yaml,
+}
+
+impl StructuredFormat {
+    /// Extension of the temporary file, used by editors for syntax highlighting.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Json => ".json",
+            Self::Toml => ".toml",
+            Self::Yaml => ".yaml",
+        }
+    }
+
+    /// Serializes the value to text in this format.
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, CustomUserError> {
+        let text = match self {
+            Self::Json => serde_json::to_string_pretty(value)?,
+            Self::Toml => toml::to_string_pretty(value)?,
+            Self::Yaml => serde_yaml::to_string(value)?,
+        };
+
+        Ok(text)
+    }
+
+    /// Parses text in this format, returning a message with the line and column
+    /// of the error when it fails.
+    pub fn parse<T: DeserializeOwned>(&self, text: &str) -> Result<T, String> {
+        let (message, location) = match self {
+            Self::Json => match serde_json::from_str(text) {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let location = (err.line(), err.column());
+                    (without_location(err.to_string()), Some(location))
+                }
+            },
+            Self::Toml => match toml::from_str(text) {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let location = err.span().map(|span| line_and_column(text, span.start));
+                    (err.message().to_owned(), location)
+                }
+            },
+            Self::Yaml => match serde_yaml::from_str(text) {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let location = err.location().map(|l| (l.line(), l.column()));
+                    (without_location(err.to_string()), location)
+                }
+            },
+        };
+
+        let message = match location {
+            Some((line, column)) => format!("{message} (line {line}, column {column})"),
+            None => message,
+        };
+
+        Err(message)
+    }
+}
+
+/// Removes the " at line X column Y" suffix of serde_json and serde_yaml messages.
+fn without_location(message: String) -> String {
+    match message.rfind(" at line ") {
+        Some(idx) => message[..idx].to_owned(),
+        None => message,
+    }
+}
+
+/// 1-based line and column of the given byte offset of the text.
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+/// [`Editor`] prompt that edits a value as structured text, such as JSON, TOML or YAML,
+/// returning the parsed value.
+///
+/// The temporary file is pre-filled with the serialized value and named with the extension of
+/// the format. When the user submits text that can not be parsed, the parse error is displayed
+/// with its line and column and the user can reopen the editor to fix it, or have it reopened
+/// automatically with [`with_reopen_on_error`](Self::with_reopen_on_error).
+///
+/// This prompt is gated via the `serde` feature, which also enables the `editor` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{StructuredEditor, StructuredFormat};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     retries: u32,
+///     endpoint: String,
+/// }
+///
+/// let current = Settings {
+///     retries: 3,
+///     endpoint: String::from("https://example.com"),
+/// };
+///
+/// let settings = StructuredEditor::new("Settings:", &current)
+///     .with_format(StructuredFormat::Toml)
+///     .prompt();
+/// ```
+#[derive(Clone)]
+pub struct StructuredEditor<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Current value, serialized to pre-fill the edited file.
+    pub value: &'a T,
+
+    /// Format of the edited text.
+    pub format: StructuredFormat,
+
+    /// Command to open the editor.
+    pub editor_command: &'a OsStr,
+
+    /// Args to pass to the editor.
+    pub editor_command_args: &'a [&'a OsStr],
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the submitted text and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the parsed value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Whether the editor is reopened when the submitted text can not be parsed or fails validation.
+    ///
+    /// See [`Editor::reopen_on_error`].
+    pub reopen_on_error: bool,
+
+    /// Prefix of comment lines, which are removed from the submitted text when
+    /// [`reopen_on_error`](Self::reopen_on_error) is enabled.
+    pub comment_prefix: &'a str,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> StructuredEditor<'a, T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    /// Default format, JSON.
+    pub const DEFAULT_FORMAT: StructuredFormat = StructuredFormat::Json;
+
+    /// Creates a [StructuredEditor] with the provided message, current value and default options.
+    pub fn new(message: &'a str, value: &'a T) -> Self {
+        let editor = Editor::new(message);
+
+        Self {
+            message,
+            value,
+            format: Self::DEFAULT_FORMAT,
+            editor_command: editor.editor_command,
+            editor_command_args: editor.editor_command_args,
+            help_message: editor.help_message,
+            formatter: editor.formatter,
+            validators: vec![],
+            reopen_on_error: editor.reopen_on_error,
+            comment_prefix: editor.comment_prefix,
+            render_config: editor.render_config,
+        }
+    }
+
+    /// Sets the format of the edited text.
+    pub fn with_format(mut self, format: StructuredFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the command to open the editor.
+    pub fn with_editor_command(mut self, editor_command: &'a OsStr) -> Self {
+        self.editor_command = editor_command;
+        self
+    }
+
+    /// Sets the args for the command to open the editor.
+    pub fn with_args(mut self, args: &'a [&'a OsStr]) -> Self {
+        self.editor_command_args = args;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators, called with the parsed value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets whether the editor is reopened when the submitted text can not be parsed or fails
+    /// validation, with the error written at the top of the file as comment lines.
+    ///
+    /// Lines and columns of parse errors then refer to the text without its comment lines.
+    pub fn with_reopen_on_error(mut self, reopen_on_error: bool) -> Self {
+        self.reopen_on_error = reopen_on_error;
+        self
+    }
+
+    /// Sets the prefix of comment lines, `#` by default.
+    pub fn with_comment_prefix(mut self, comment_prefix: &'a str) -> Self {
+        self.comment_prefix = comment_prefix;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: EditorBackend>(self, backend: &mut B) -> InquireResult<T> {
+        let format = self.format;
+        let text = format.serialize(self.value).map_err(InquireError::Custom)?;
+
+        let validators = self.validators;
+        let validator = move |input: &str| {
+            let value: T = match format.parse(input) {
+                Ok(value) => value,
+                Err(message) => return Ok(Validation::Invalid(ErrorMessage::Custom(message))),
+            };
+
+            validate_all(validators.iter().map(|v| v.validate(&value)))
+        };
+
+        let editor = Editor {
+            message: self.message,
+            editor_command: self.editor_command,
+            editor_command_args: self.editor_command_args,
+            file_extension: format.file_extension(),
+            predefined_text: Some(&text),
+            help_message: self.help_message,
+            formatter: self.formatter,
+            validators: vec![Box::new(validator)],
+            reopen_on_error: self.reopen_on_error,
+            comment_prefix: self.comment_prefix,
+            render_config: self.render_config,
+        };
+
+        let answer = editor.prompt_with_backend(backend)?;
+
+        format
+            .parse(&answer)
+            .map_err(|message| InquireError::Custom(message.into()))
+    }
+}
@@ -0,0 +1,239 @@
+use std::ffi::OsStr;
+
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::{ErrorMessage, Validation},
+    Editor, InquireError,
+};
+
+fn sh() -> &'static OsStr {
+    OsStr::new("sh")
+}
+
+/// Editor command running the given shell script, with the path of the file as `$0`.
+fn script_args(script: &'static str) -> Vec<&'static OsStr> {
+    vec![OsStr::new("-c"), OsStr::new(script)]
+}
+
+fn at_least_two_lines(ans: &str) -> Result<Validation, crate::CustomUserError> {
+    match ans.lines().count() {
+        0 | 1 => Ok(Validation::Invalid("Write at least two lines".into())),
+        _ => Ok(Validation::Valid),
+    }
+}
+
+fn at_least_three_lines(ans: &str) -> Result<Validation, crate::CustomUserError> {
+    match ans.lines().count() {
+        0..=2 => Ok(Validation::Invalid("Write at least three lines".into())),
+        _ => Ok(Validation::Valid),
+    }
+}
+
+#[test]
+fn invalid_text_is_returned_to_the_prompt_by_default() {
+    let args = script_args("printf 'a\\n' >> \"$0\"");
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape]);
+
+    let ans = Editor::new("Question")
+        .with_editor_command(sh())
+        .with_args(&args)
+        .with_validator(at_least_two_lines)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Write at least two lines".into()
+        )))
+    );
+}
+
+#[test]
+fn editor_is_reopened_until_text_is_valid() {
+    let args = script_args("printf 'line\\n' >> \"$0\"");
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Editor::new("Question")
+        .with_editor_command(sh())
+        .with_args(&args)
+        .with_validator(at_least_two_lines)
+        .with_reopen_on_error(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("line\nline", ans);
+}
+
+#[test]
+fn error_is_written_as_comment_lines_replacing_previous_ones() {
+    // Appends the number of comment lines in the file, so the answer tells
+    // how many annotation lines the editor saw each time it was reopened.
+    let args = script_args("grep -c '^//' \"$0\" >> \"$0\"");
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Editor::new("Question")
+        .with_editor_command(sh())
+        .with_args(&args)
+        .with_predefined_text("// keep this comment\n")
+        .with_comment_prefix("//")
+        .with_validator(at_least_three_lines)
+        .with_reopen_on_error(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("// keep this comment\n3\n3", ans);
+}
+
+#[test]
+fn lines_of_the_user_starting_with_the_comment_prefix_are_kept() {
+    let args = script_args("printf '# not a heading\\nbody\\n' >> \"$0\"");
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Editor::new("Question")
+        .with_editor_command(sh())
+        .with_args(&args)
+        .with_predefined_text("# Title\n")
+        .with_validator(at_least_three_lines)
+        .with_reopen_on_error(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("# Title\n# not a heading\nbody", ans);
+}
+
+#[test]
+fn closing_the_reopened_editor_without_changes_returns_to_the_prompt() {
+    let args = script_args("true");
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape]);
+
+    let ans = Editor::new("Question")
+        .with_editor_command(sh())
+        .with_args(&args)
+        .with_validator(at_least_two_lines)
+        .with_reopen_on_error(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert!(
+        backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Write at least two lines".into()
+        )))
+    );
+}
+
+#[cfg(feature = "serde")]
+mod structured {
+    use serde::{Deserialize, Serialize};
+
+    use super::{script_args, sh};
+    use crate::{
+        ui::{
+            test::{FakeBackend, Token},
+            Key, KeyModifiers,
+        },
+        validator::{ErrorMessage, Validation},
+        InquireError, StructuredEditor, StructuredFormat,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        retries: u32,
+        endpoint: String,
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            retries: 3,
+            endpoint: String::from("localhost"),
+        }
+    }
+
+    fn open_editor() -> Key {
+        Key::Char('e', KeyModifiers::NONE)
+    }
+
+    fn parse_error(message: &str) -> Token {
+        Token::ErrorMessage(ErrorMessage::Custom(message.into()))
+    }
+
+    #[test]
+    fn file_is_prefilled_with_the_serialized_value_and_parsed_back() {
+        let args = script_args("sed 's/3/5/' \"$0\" > \"$0.new\" && mv \"$0.new\" \"$0\"");
+        let mut backend = FakeBackend::new(vec![open_editor(), Key::Enter]);
+        let current = settings();
+
+        let ans = StructuredEditor::new("Settings", &current)
+            .with_format(StructuredFormat::Toml)
+            .with_editor_command(sh())
+            .with_args(&args)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(5, ans.retries);
+        assert_eq!("localhost", ans.endpoint);
+    }
+
+    #[test]
+    fn file_extension_matches_the_format() {
+        let args = script_args(
+            "case \"$0\" in *.yaml) sed 's/3/7/' \"$0\" > \"$0.new\" && mv \"$0.new\" \"$0\";; esac",
+        );
+        let mut backend = FakeBackend::new(vec![open_editor(), Key::Enter]);
+        let current = settings();
+
+        let ans = StructuredEditor::new("Settings", &current)
+            .with_format(StructuredFormat::Yaml)
+            .with_editor_command(sh())
+            .with_args(&args)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(7, ans.retries);
+    }
+
+    #[test]
+    fn parse_errors_are_displayed_with_line_and_column() {
+        let args = script_args("printf '{\\n  \"retries\": ,\\n}' > \"$0\"");
+        let mut backend = FakeBackend::new(vec![open_editor(), Key::Enter, Key::Escape]);
+        let current = settings();
+
+        let ans = StructuredEditor::new("Settings", &current)
+            .with_editor_command(sh())
+            .with_args(&args)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        assert!(backend.frames()[2].has_token(&parse_error("expected value (line 2, column 14)")));
+    }
+
+    #[test]
+    fn validators_receive_the_parsed_value() {
+        let args = script_args("true");
+        let mut backend = FakeBackend::new(vec![Key::Enter, Key::Escape]);
+        let current = settings();
+
+        let ans = StructuredEditor::new("Settings", &current)
+            .with_editor_command(sh())
+            .with_args(&args)
+            .with_validator(|settings: &Settings| match settings.retries {
+                0..=3 => Ok(Validation::Invalid("Use more retries".into())),
+                _ => Ok(Validation::Valid),
+            })
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        assert!(backend.frames()[1].has_token(&parse_error("Use more retries")));
+    }
+
+    #[test]
+    fn toml_errors_are_located_from_their_span() {
+        let err = StructuredFormat::Toml
+            .parse::<Settings>("retries = 3\nendpoint = \n")
+            .unwrap_err();
+
+        assert!(err.ends_with("(line 2, column 12)"), "{}", err);
+    }
+}
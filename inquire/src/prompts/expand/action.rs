@@ -0,0 +1,24 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::ExpandConfig;
+
+/// Set of actions for an ExpandPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExpandPromptAction {
+    /// Picks the choice of the key, or toggles the list of choices if the key is `?`.
+    Press(char),
+}
+
+impl InnerAction for ExpandPromptAction {
+    type Config = ExpandConfig;
+
+    fn from_key(key: Key, _config: &ExpandConfig) -> Option<Self> {
+        match key {
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) => Some(Self::Press(c)),
+            _ => None,
+        }
+    }
+}
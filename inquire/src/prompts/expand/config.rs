@@ -0,0 +1,10 @@
+use crate::Expand;
+
+/// Configuration settings used in the execution of an ExpandPrompt.
+pub struct ExpandConfig {}
+
+impl<T> From<&Expand<'_, T>> for ExpandConfig {
+    fn from(_value: &Expand<'_, T>) -> Self {
+        Self {}
+    }
+}
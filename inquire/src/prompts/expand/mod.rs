@@ -0,0 +1,200 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, ExpandBackend, RenderConfig},
+};
+
+use self::prompt::ExpandPrompt;
+
+/// Key that toggles the list of choices of [`Expand`] prompts.
+const HELP_KEY: char = '?';
+
+/// Choice of an [`Expand`] prompt, picked by pressing its key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpandChoice<'a, T> {
+    /// Key that picks the choice, matched case-insensitively.
+    pub key: char,
+
+    /// Description of the choice, displayed in the list of choices and as the final answer.
+    pub label: &'a str,
+
+    /// Value returned when the choice is picked.
+    pub value: T,
+}
+
+impl<'a, T> ExpandChoice<'a, T> {
+    /// Creates a choice picked by the key.
+    pub fn new(key: char, label: &'a str, value: T) -> Self {
+        Self { key, label, value }
+    }
+}
+
+/// Prompt that offers lettered choices, answered as soon as the key of a choice is pressed,
+/// commonly known by displaying the keys as in `[y,n,a,d,q,?]`.
+///
+/// Pressing `?` expands the list of choices with their descriptions, and pressing it again
+/// collapses it. Keys are matched case-insensitively, and the key of the default choice,
+/// returned when the user presses enter, is displayed in uppercase.
+///
+/// The prompt returns the value of the picked choice, typically a variant of an enum
+/// describing the possible actions.
+///
+/// - If the list of choices is empty, if two choices share a key, if a choice uses the `?`
+///   key or if the default key does not match any choice, the prompt operation will fail
+///   with an `InquireError::InvalidConfiguration` error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Expand, ExpandChoice};
+///
+/// #[derive(Clone, Copy, Debug)]
+/// enum Action {
+///     Overwrite,
+///     Skip,
+///     OverwriteAll,
+///     Diff,
+///     Quit,
+/// }
+///
+/// let action = Expand::new(
+///     "Conflict on src/main.rs:",
+///     vec![
+///         ExpandChoice::new('y', "overwrite this file", Action::Overwrite),
+///         ExpandChoice::new('n', "skip this file", Action::Skip),
+///         ExpandChoice::new('a', "overwrite this and all remaining files", Action::OverwriteAll),
+///         ExpandChoice::new('d', "show the differences", Action::Diff),
+///         ExpandChoice::new('q', "quit", Action::Quit),
+///     ],
+/// )
+/// .with_default('n')
+/// .prompt();
+///
+/// match action {
+///     Ok(action) => println!("{:?}", action),
+///     Err(_) => println!("An error happened, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Expand<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Choices displayed to the user, picked by their keys.
+    pub choices: Vec<ExpandChoice<'a, T>>,
+
+    /// Key of the choice returned when the user presses enter.
+    pub default: Option<char>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Error message displayed when the user presses a key that does not match any
+    /// choice, or presses enter without a default choice.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Expand<'a, T> {
+    /// Default error message.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Invalid choice, press '?' for help";
+
+    /// Creates an [Expand] with the provided message and choices, along with default configuration values.
+    pub fn new(message: &'a str, choices: Vec<ExpandChoice<'a, T>>) -> Self {
+        Self {
+            message,
+            choices,
+            default: None,
+            help_message: None,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Adds a choice picked by the key.
+    pub fn with_choice(mut self, key: char, label: &'a str, value: T) -> Self {
+        self.choices.push(ExpandChoice::new(key, label, value));
+        self
+    }
+
+    /// Sets the key of the choice returned when the user presses enter.
+    pub fn with_default(mut self, key: char) -> Self {
+        self.default = Some(key);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the error message displayed when the user presses a key that does not match
+    /// any choice, or presses enter without a default choice.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: ExpandBackend>(self, backend: &mut B) -> InquireResult<T> {
+        let choice = ExpandPrompt::new(self)?.prompt(backend)?;
+        Ok(choice.value)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    error::InquireResult,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::ExpandBackend,
+    validator::ErrorMessage,
+    Expand, ExpandChoice, InquireError,
+};
+
+use super::{action::ExpandPromptAction, config::ExpandConfig, HELP_KEY};
+
+pub struct ExpandPrompt<'a, T> {
+    message: &'a str,
+    config: ExpandConfig,
+    choices: Vec<ExpandChoice<'a, T>>,
+    /// Keys of the choices separated by commas, with the default one in uppercase.
+    keys: String,
+    default: Option<usize>,
+    chosen: Option<usize>,
+    expanded: bool,
+    help_message: Option<&'a str>,
+    error_message: String,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> ExpandPrompt<'a, T> {
+    pub fn new(eo: Expand<'a, T>) -> InquireResult<Self> {
+        if eo.choices.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available choices can not be empty".into(),
+            ));
+        }
+
+        for (idx, choice) in eo.choices.iter().enumerate() {
+            let key = choice.key.to_ascii_lowercase();
+
+            if key == HELP_KEY {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "The key '{HELP_KEY}' is reserved for the list of choices"
+                )));
+            }
+
+            let duplicated = eo.choices[..idx]
+                .iter()
+                .any(|other| other.key.to_ascii_lowercase() == key);
+            if duplicated {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "The key '{key}' is used by more than one choice"
+                )));
+            }
+        }
+
+        let default = match eo.default {
+            Some(key) => match Self::find(&eo.choices, key) {
+                Some(idx) => Some(idx),
+                None => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "The default key '{key}' does not match any choice"
+                    )))
+                }
+            },
+            None => None,
+        };
+
+        let mut keys: Vec<String> = eo
+            .choices
+            .iter()
+            .enumerate()
+            .map(|(idx, choice)| match default == Some(idx) {
+                true => choice.key.to_ascii_uppercase().to_string(),
+                false => choice.key.to_ascii_lowercase().to_string(),
+            })
+            .collect();
+        keys.push(HELP_KEY.to_string());
+
+        Ok(Self {
+            message: eo.message,
+            config: (&eo).into(),
+            choices: eo.choices,
+            keys: keys.join(","),
+            default,
+            chosen: None,
+            expanded: false,
+            help_message: eo.help_message,
+            error_message: eo.error_message,
+            error: None,
+        })
+    }
+
+    /// Index of the choice of the key, matched case-insensitively.
+    fn find(choices: &[ExpandChoice<'a, T>], key: char) -> Option<usize> {
+        choices
+            .iter()
+            .position(|choice| choice.key.eq_ignore_ascii_case(&key))
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for ExpandPrompt<'a, T>
+where
+    Backend: ExpandBackend,
+{
+    type Config = ExpandConfig;
+    type InnerAction = ExpandPromptAction;
+    type Output = ExpandChoice<'a, T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ExpandConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ExpandChoice<'a, T>) -> String {
+        answer.label.to_string()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ExpandChoice<'a, T>>> {
+        if let Some(idx) = self.chosen.or(self.default) {
+            return Ok(Some(self.choices.swap_remove(idx)));
+        }
+
+        self.error = Some(ErrorMessage::Custom(self.error_message.clone()));
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: ExpandPromptAction) -> InquireResult<ActionResult> {
+        let ExpandPromptAction::Press(key) = action;
+
+        if key == HELP_KEY {
+            self.expanded = !self.expanded;
+            self.error = None;
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        if let Some(idx) = Self::find(&self.choices, key) {
+            self.chosen = Some(idx);
+            return Ok(ActionResult::Submit);
+        }
+
+        self.error = Some(ErrorMessage::Custom(self.error_message.clone()));
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        backend.render_expand_prompt(self.message, &self.keys)?;
+
+        if self.expanded {
+            let choices: Vec<(char, &str)> = self
+                .choices
+                .iter()
+                .map(|choice| (choice.key.to_ascii_lowercase(), choice.label))
+                .chain(std::iter::once((HELP_KEY, "print help")))
+                .collect();
+            backend.render_expand_choices(&choices)?;
+        }
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    Expand, ExpandChoice, InquireError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Overwrite,
+    Skip,
+    Diff,
+    Quit,
+}
+
+fn conflict_prompt() -> Expand<'static, Action> {
+    Expand::new(
+        "Conflict:",
+        vec![
+            ExpandChoice::new('y', "overwrite", Action::Overwrite),
+            ExpandChoice::new('n', "skip", Action::Skip),
+            ExpandChoice::new('d', "show the differences", Action::Diff),
+        ],
+    )
+    .with_choice('q', "quit", Action::Quit)
+}
+
+fn char_key(c: char) -> Key {
+    Key::Char(c, KeyModifiers::NONE)
+}
+
+#[test]
+fn pressing_a_key_answers_right_away() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![char_key('d')]);
+
+    let ans = conflict_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Action::Diff, ans);
+    let final_frame = backend.frames().last().unwrap();
+    assert!(final_frame.has_token(&Token::AnsweredPrompt(
+        "Conflict:".into(),
+        "show the differences".into()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn keys_are_case_insensitive() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('Q', KeyModifiers::SHIFT)]);
+
+    let ans = conflict_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Action::Quit, ans);
+
+    Ok(())
+}
+
+#[test]
+fn question_mark_toggles_the_list_of_choices() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![char_key('?'), char_key('?'), char_key('n')]);
+
+    let ans = conflict_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Action::Skip, ans);
+
+    let is_expanded = |idx: usize| {
+        backend.frames()[idx]
+            .tokens()
+            .iter()
+            .any(|token| matches!(token, Token::ExpandChoices(_)))
+    };
+    assert!(!is_expanded(0));
+    assert!(is_expanded(1));
+    assert!(!is_expanded(2));
+
+    let expected = Token::ExpandChoices(vec![
+        ('y', "overwrite".into()),
+        ('n', "skip".into()),
+        ('d', "show the differences".into()),
+        ('q', "quit".into()),
+        ('?', "print help".into()),
+    ]);
+    assert!(backend.frames()[1].has_token(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_keys_display_an_error() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![char_key('x'), Key::Enter, char_key('y')]);
+
+    let ans = conflict_prompt().prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Action::Overwrite, ans);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom(
+        Expand::<Action>::DEFAULT_ERROR_MESSAGE.into(),
+    ));
+    assert!(backend.frames()[1].has_token(&error));
+    assert!(backend.frames()[2].has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn enter_picks_the_default_choice() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = conflict_prompt()
+        .with_default('N')
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(Action::Skip, ans);
+    assert!(backend.frames()[0].has_token(&Token::ExpandKeys("y,N,d,q,?".into())));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_choices_are_rejected() {
+    let empty: Expand<'_, Action> = Expand::new("Conflict:", vec![]);
+    let duplicated = conflict_prompt().with_choice('Y', "overwrite all", Action::Overwrite);
+    let help = conflict_prompt().with_choice('?', "help", Action::Quit);
+    let default = conflict_prompt().with_default('z');
+
+    for prompt in [empty, duplicated, help, default] {
+        let mut backend = FakeBackend::new(vec![Key::Enter]);
+        let ans = prompt.prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
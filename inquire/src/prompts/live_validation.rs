@@ -0,0 +1,81 @@
+//! Validation of text inputs while the user types, shared by the text prompts.
+
+use std::time::{Duration, Instant};
+
+use crate::validator::{ErrorMessage, LiveValidation, Validation};
+
+/// Tracks when the input of a prompt has to be validated as the user types and
+/// which result is displayed.
+pub(crate) struct LiveValidator {
+    options: LiveValidation,
+    /// Time of the last input change, while its validation is pending.
+    changed_at: Option<Instant>,
+    submitted: bool,
+    valid: bool,
+}
+
+impl LiveValidator {
+    pub fn new(options: LiveValidation) -> Self {
+        Self {
+            options,
+            changed_at: None,
+            submitted: false,
+            valid: false,
+        }
+    }
+
+    /// Registers a change of the input, returning whether it should be validated
+    /// right away. Otherwise, it is validated once the debounce time elapses.
+    pub fn input_changed(&mut self) -> bool {
+        if self.options.debounce.is_zero() {
+            return true;
+        }
+
+        self.changed_at = Some(Instant::now());
+        false
+    }
+
+    /// Validates the input on every change, for backends that can not wait for
+    /// the debounce time to elapse.
+    pub fn disable_debounce(&mut self) {
+        self.options.debounce = Duration::ZERO;
+    }
+
+    /// Time left until the pending validation, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.changed_at
+            .map(|changed_at| self.options.debounce.saturating_sub(changed_at.elapsed()))
+    }
+
+    /// Records the result of validating the input, returning the error and the
+    /// warning to display, in this order.
+    pub fn update(
+        &mut self,
+        validation: Validation,
+    ) -> (Option<ErrorMessage>, Option<ErrorMessage>) {
+        self.changed_at = None;
+        self.valid = validation == Validation::Valid;
+
+        match self.displays_results() {
+            true => validation.into_messages(),
+            false => (None, None),
+        }
+    }
+
+    /// Records the result of validating a submitted answer, after which
+    /// results are always displayed.
+    pub fn submit(&mut self, validation: &Validation) {
+        self.submitted = true;
+        self.changed_at = None;
+        self.valid = *validation == Validation::Valid;
+    }
+
+    /// Whether the valid input indicator is displayed.
+    pub fn shows_valid(&self) -> bool {
+        self.valid && self.displays_results()
+    }
+
+    fn displays_results(&self) -> bool {
+        !self.options.only_after_submit || self.submitted
+    }
+}
@@ -1,29 +1,62 @@
 mod action;
+mod background_validation;
 mod confirm;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
+#[cfg(feature = "date")]
+mod datetimeselect;
 #[cfg(feature = "editor")]
 mod editor;
+mod expand;
+mod live_validation;
+mod multi_line_text;
+mod multi_table_select;
+mod multi_tree_select;
 mod multiselect;
+mod number;
 mod one_liners;
 mod password;
 mod prompt;
+mod scoring;
 mod select;
+mod slider;
+mod sort;
+mod table;
+mod table_select;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+#[cfg(feature = "date")]
+mod timeselect;
+mod tree;
+mod tree_select;
 
 pub use action::*;
 pub use confirm::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
 pub use dateselect::*;
+#[cfg(feature = "date")]
+pub use datetimeselect::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use expand::*;
+pub use multi_line_text::*;
+pub use multi_table_select::*;
+pub use multi_tree_select::*;
 pub use multiselect::*;
+pub use number::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
 pub use password::*;
 pub use select::*;
+pub use slider::*;
+pub use sort::*;
+pub use table::{SortOrder, TableColumn};
+pub use table_select::*;
 pub use text::*;
+#[cfg(feature = "date")]
+pub use timeselect::*;
+pub use tree::TreeNode;
+pub use tree_select::*;
@@ -0,0 +1,50 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    Action, InnerAction, InputAction,
+};
+
+use super::config::MultiLineTextConfig;
+
+/// Set of actions for a MultiLineTextPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiLineTextPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Splits the current line at the cursor.
+    InsertNewLine,
+    /// Moves the cursor to the line above.
+    MoveToLineAbove,
+    /// Moves the cursor to the line below.
+    MoveToLineBelow,
+}
+
+impl InnerAction for MultiLineTextPromptAction {
+    type Config = MultiLineTextConfig;
+
+    fn from_key(key: Key, _config: &MultiLineTextConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(_) => Self::MoveToLineAbove,
+            Key::Down(_) => Self::MoveToLineBelow,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+
+    fn from_key_override(key: Key, config: &MultiLineTextConfig) -> Option<Action<Self>> {
+        if key == config.submit_key {
+            return Some(Action::Submit);
+        }
+
+        match key {
+            Key::Enter
+            | Key::Char('\n', KeyModifiers::NONE)
+            | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Inner(Self::InsertNewLine)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{ui::Key, MultiLineText};
+
+/// Configuration settings used in the execution of a MultiLineTextPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiLineTextConfig {
+    /// Key that submits the prompt, as Enter inserts new lines.
+    pub submit_key: Key,
+    /// Maximum amount of lines displayed at once, if any.
+    pub max_lines: Option<usize>,
+}
+
+impl From<&MultiLineText<'_>> for MultiLineTextConfig {
+    fn from(value: &MultiLineText<'_>) -> Self {
+        Self {
+            submit_key: value.submit_key,
+            max_lines: value.max_lines,
+        }
+    }
+}
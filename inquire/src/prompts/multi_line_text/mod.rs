@@ -0,0 +1,287 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, Key, KeyModifiers, MultiLineTextBackend, RenderConfig},
+    validator::{LiveValidation, StringValidator},
+};
+
+use self::prompt::MultiLineTextPrompt;
+
+/// Text prompt that edits multi-line input in place, without opening an external editor.
+///
+/// It works like [`Text`](crate::Text), except that Enter inserts a new line and the answer
+/// is submitted with a dedicated key, `Ctrl+D` by default. The user can move between lines
+/// with the Up and Down arrows, and lines longer than the terminal are wrapped.
+///
+/// ## Configuration options
+///
+/// - **Prompt message**: Main message when prompting the user for input, `"Commit message:"` in the example below.
+/// - **Help message**: Message displayed at the line below the input. By default, it explains how to insert new lines and submit the answer.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Initial value**: Initial value of the prompt's text input, which may contain new lines.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Submit key**: Key that submits the answer, `Ctrl+D` by default. `Alt+Enter` is represented as `Key::Char('\n', KeyModifiers::ALT)`.
+/// - **Max lines**: Maximum amount of lines displayed at once. Longer inputs scroll to keep the cursor visible.
+/// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{
+///     ui::{Key, KeyModifiers},
+///     MultiLineText,
+/// };
+///
+/// let message = MultiLineText::new("Commit message:")
+///     .with_submit_key(Key::Char('\n', KeyModifiers::ALT))
+///     .with_help_message("enter to insert a new line, alt+enter to submit")
+///     .with_max_lines(10)
+///     .prompt();
+///
+/// match message {
+///     Ok(message) => println!("Committing with:\n{message}"),
+///     Err(_) => println!("An error happened when asking for the commit message, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MultiLineText<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub initial_value: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<&'a str>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Key that submits the answer, as Enter inserts a new line.
+    pub submit_key: Key,
+
+    /// Maximum amount of lines displayed at once, if any.
+    ///
+    /// Inputs with more lines scroll to keep the cursor visible.
+    pub max_lines: Option<usize>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// Options of live validation, in which validators run on the whole text as it is edited.
+    ///
+    /// Disabled by default.
+    pub live_validation: Option<LiveValidation>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> MultiLineText<'a> {
+    /// Default formatter, set to [DEFAULT_STRING_FORMATTER](crate::formatter::DEFAULT_STRING_FORMATTER)
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = DEFAULT_STRING_FORMATTER;
+
+    /// Default validators added to the [MultiLineText] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default submit key, `Ctrl+D`.
+    pub const DEFAULT_SUBMIT_KEY: Key = Key::Char('d', KeyModifiers::CONTROL);
+
+    /// Default maximum amount of displayed lines, none.
+    pub const DEFAULT_MAX_LINES: Option<usize> = None;
+
+    /// Default help message, describing the default key bindings.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("enter to insert a new line, ctrl+d to submit");
+
+    /// Creates a [MultiLineText] with the provided message and default options.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            initial_value: None,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            submit_key: Self::DEFAULT_SUBMIT_KEY,
+            max_lines: Self::DEFAULT_MAX_LINES,
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            live_validation: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_initial_value(mut self, message: &'a str) -> Self {
+        self.initial_value = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, message: &'a str) -> Self {
+        self.default = Some(message);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the key that submits the answer.
+    ///
+    /// Remember to update the help message, which describes the default submit key.
+    pub fn with_submit_key(mut self, key: Key) -> Self {
+        self.submit_key = key;
+        self
+    }
+
+    /// Sets the maximum amount of lines displayed at once.
+    ///
+    /// Inputs with more lines scroll to keep the cursor visible. At least one line is always displayed.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the user's answer, such as
+    /// defining a limit of characters.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the user's answer, such as defining a limit of characters.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Enables live validation of the text as it is edited, instead of only when it is submitted.
+    pub fn with_live_validation(mut self, live_validation: LiveValidation) -> Self {
+        self.live_validation = Some(live_validation);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: MultiLineTextBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        MultiLineTextPrompt::from(self).prompt(backend)
+    }
+}
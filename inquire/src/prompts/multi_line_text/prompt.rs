@@ -0,0 +1,223 @@
+use std::{cmp::min, time::Duration};
+
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    input::{Input, InputActionResult, MultiLineInput},
+    prompts::{
+        live_validation::LiveValidator,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::MultiLineTextBackend,
+    utils::Page,
+    validator::{validate_all, ErrorMessage, StringValidator, Validation},
+    InquireError, MultiLineText,
+};
+
+use super::{action::MultiLineTextPromptAction, config::MultiLineTextConfig};
+
+pub struct MultiLineTextPrompt<'a> {
+    message: &'a str,
+    config: MultiLineTextConfig,
+    default: Option<&'a str>,
+    help_message: Option<&'a str>,
+    input: MultiLineInput,
+    /// Index of the first line displayed when the amount of lines is limited.
+    first_visible_line: usize,
+    formatter: StringFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+    live_validator: Option<LiveValidator>,
+    error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
+}
+
+impl<'a> From<MultiLineText<'a>> for MultiLineTextPrompt<'a> {
+    fn from(so: MultiLineText<'a>) -> Self {
+        let input = MultiLineInput::new_with(so.initial_value.unwrap_or_default());
+        let input = if let Some(placeholder) = so.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        let mut prompt = Self {
+            message: so.message,
+            config: (&so).into(),
+            default: so.default,
+            help_message: so.help_message,
+            input,
+            first_visible_line: 0,
+            formatter: so.formatter,
+            validators: so.validators,
+            live_validator: so.live_validation.map(LiveValidator::new),
+            error: None,
+            warning: None,
+        };
+        prompt.update_visible_lines();
+
+        prompt
+    }
+}
+
+impl<'a> MultiLineTextPrompt<'a> {
+    fn max_lines(&self) -> Option<usize> {
+        self.config.max_lines.map(|max| max.max(1))
+    }
+
+    /// Scrolls the displayed lines just enough to keep the cursor visible.
+    fn update_visible_lines(&mut self) {
+        let max_lines = match self.max_lines() {
+            Some(max_lines) => max_lines,
+            None => return,
+        };
+
+        let row = self.input.row();
+        let last_first_line = self.input.lines().len().saturating_sub(max_lines);
+
+        if row < self.first_visible_line {
+            self.first_visible_line = row;
+        } else if row >= self.first_visible_line + max_lines {
+            self.first_visible_line = row + 1 - max_lines;
+        }
+
+        self.first_visible_line = min(self.first_visible_line, last_first_line);
+    }
+
+    fn visible_lines(&self) -> Page<'_, Input> {
+        let lines = self.input.lines();
+        let end = self
+            .max_lines()
+            .map(|max_lines| min(self.first_visible_line + max_lines, lines.len()))
+            .unwrap_or(lines.len());
+
+        Page {
+            first: self.first_visible_line == 0,
+            last: end == lines.len(),
+            content: &lines[self.first_visible_line..end],
+            cursor: Some(self.input.row() - self.first_visible_line),
+            total: lines.len(),
+        }
+    }
+
+    fn get_current_answer(&self) -> String {
+        // Empty input with default values override any validators.
+        if self.input.is_empty() {
+            if let Some(val) = self.default {
+                return val.to_owned();
+            }
+        }
+
+        self.input.content()
+    }
+
+    fn validate_current_answer(&self, answer: &str) -> InquireResult<Validation> {
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
+    }
+
+    fn validate_live(&mut self) -> InquireResult<()> {
+        let validation = self.validate_current_answer(&self.get_current_answer())?;
+
+        if let Some(live) = &mut self.live_validator {
+            (self.error, self.warning) = live.update(validation);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for MultiLineTextPrompt<'a>
+where
+    Backend: MultiLineTextBackend,
+{
+    type Config = MultiLineTextConfig;
+    type InnerAction = MultiLineTextPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &MultiLineTextConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn disable_ticks(&mut self) {
+        if let Some(live) = &mut self.live_validator {
+            live.disable_debounce();
+        }
+    }
+
+    fn tick_timeout(&self) -> Option<Duration> {
+        self.live_validator
+            .as_ref()
+            .and_then(LiveValidator::timeout)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        self.validate_live()?;
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let answer = self.get_current_answer();
+        let validation = self.validate_current_answer(&answer)?;
+
+        if let Some(live) = &mut self.live_validator {
+            live.submit(&validation);
+        }
+
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(answer));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: MultiLineTextPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            MultiLineTextPromptAction::ValueInput(input_action) => self.input.handle(input_action),
+            MultiLineTextPromptAction::InsertNewLine => self.input.insert_new_line(),
+            MultiLineTextPromptAction::MoveToLineAbove => self.input.move_up(),
+            MultiLineTextPromptAction::MoveToLineBelow => self.input.move_down(),
+        };
+
+        self.update_visible_lines();
+
+        if let InputActionResult::ContentChanged = result {
+            self.warning = None;
+
+            if let Some(live) = &mut self.live_validator {
+                if live.input_changed() {
+                    self.validate_live()?;
+                }
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        } else if matches!(&self.live_validator, Some(live) if live.shows_valid()) {
+            backend.render_valid_input_indicator()?;
+        }
+
+        backend.render_multi_line_prompt(self.message, self.default)?;
+        backend.render_multi_line_input(self.visible_lines(), self.input.placeholder())?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    InquireError, MultiLineText,
+};
+
+fn default<'a>() -> MultiLineText<'a> {
+    MultiLineText::new("Question?")
+}
+
+fn text(s: &str) -> Vec<Key> {
+    s.chars()
+        .map(|c| match c {
+            '\n' => Key::Enter,
+            c => Key::Char(c, KeyModifiers::NONE),
+        })
+        .collect()
+}
+
+fn submit() -> Key {
+    Key::Char('d', KeyModifiers::CONTROL)
+}
+
+fn input_token(lines: &[&str], cursor: (usize, usize), first: bool, last: bool) -> Token {
+    Token::MultiLineInput {
+        lines: lines.iter().map(|l| (*l).to_owned()).collect(),
+        cursor: Some(cursor),
+        first,
+        last,
+    }
+}
+
+#[test]
+fn enter_inserts_new_lines_and_ctrl_d_submits() {
+    let mut keys = text("first line\nsecond line\n\nlast");
+    keys.push(submit());
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("first line\nsecond line\n\nlast", ans);
+}
+
+#[test]
+fn submit_key_is_configurable() {
+    let mut keys = text("a\nb");
+    keys.push(Key::Char('d', KeyModifiers::CONTROL));
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default()
+        .with_submit_key(Key::Char('\n', KeyModifiers::ALT))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+
+    let mut keys = text("a\nb");
+    keys.push(Key::Char('\n', KeyModifiers::ALT));
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default()
+        .with_submit_key(Key::Char('\n', KeyModifiers::ALT))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("a\nb", ans);
+}
+
+#[test]
+fn vertical_arrows_move_between_lines() {
+    let mut keys = text("abc\nd");
+    keys.push(Key::Up(KeyModifiers::NONE));
+    keys.extend(text("X"));
+    keys.push(Key::Down(KeyModifiers::NONE));
+    keys.extend(text("Y"));
+    keys.push(submit());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("aXbc\ndY", ans);
+    assert!(backend.frames()[6].has_token(&input_token(&["abc", "d"], (0, 1), true, true)));
+}
+
+#[test]
+fn backspace_at_line_start_joins_lines() {
+    let mut keys = text("ab\ncd");
+    keys.push(Key::Home);
+    keys.push(Key::Backspace);
+    keys.push(submit());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("abcd", ans);
+}
+
+#[test]
+fn initial_value_can_span_multiple_lines() {
+    let mut keys = vec![Key::Backspace];
+    keys.push(submit());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default()
+        .with_initial_value("one\ntwo\n")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("one\ntwo", ans);
+    assert!(backend.frames()[0].has_token(&input_token(&["one", "two", ""], (2, 0), true, true)));
+}
+
+#[test]
+fn empty_input_returns_default() {
+    let mut backend = FakeBackend::new(vec![submit()]);
+
+    let ans = default()
+        .with_default("nothing to add")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("nothing to add", ans);
+}
+
+#[test]
+fn validators_are_applied_to_the_whole_text() {
+    let mut keys = text("one line");
+    keys.push(submit());
+    keys.extend(text("\nanother"));
+    keys.push(submit());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default()
+        .with_validator(|ans: &str| match ans.lines().count() {
+            1 => Ok(Validation::Invalid("Write at least two lines".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("one line\nanother", ans);
+    assert!(
+        backend.frames()[9].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Write at least two lines".into()
+        )))
+    );
+}
+
+#[test]
+fn max_lines_scrolls_to_keep_cursor_visible() {
+    let mut keys = text("1\n2\n3\n4");
+    keys.extend([Key::Up(KeyModifiers::NONE); 3]);
+    keys.push(submit());
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = default()
+        .with_max_lines(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("1\n2\n3\n4", ans);
+
+    let frames = backend.frames();
+    assert!(frames[7].has_token(&input_token(&["3", "4"], (1, 1), false, true)));
+    assert!(frames[8].has_token(&input_token(&["3", "4"], (0, 1), false, true)));
+    assert!(frames[9].has_token(&input_token(&["2", "3"], (0, 1), false, false)));
+    assert!(frames[10].has_token(&input_token(&["1", "2"], (0, 1), true, false)));
+}
+
+#[test]
+fn long_lines_are_rendered_without_errors() {
+    let mut keys = text(&"word ".repeat(100));
+    keys.push(Key::Enter);
+    keys.extend([Key::Up(KeyModifiers::NONE), Key::Home]);
+    keys.push(submit());
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = default()
+        .with_max_lines(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(format!("{}\n", "word ".repeat(100)), ans);
+}
@@ -0,0 +1,19 @@
+use crate::MultiTableSelect;
+
+/// Configuration settings used in the execution of a MultiTableSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiTableSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of rows.
+    pub page_size: usize,
+}
+
+impl<T> From<&MultiTableSelect<'_, T>> for MultiTableSelectConfig {
+    fn from(value: &MultiTableSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
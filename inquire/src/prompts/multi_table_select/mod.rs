@@ -0,0 +1,379 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::Scorer,
+    ui::{table::TableSelectBackend, Backend, RenderConfig},
+    SortOrder, TableColumn,
+};
+
+use self::prompt::MultiTableSelectPrompt;
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "fuzzy")]
+static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Prompt suitable for when you need the user to select many rows of structured data,
+/// such as instances described by their name, zone, status and age.
+///
+/// The user can toggle the selection of the current highlighted row by pressing space,
+/// select all displayed rows by pressing the right arrow and clear all selections by
+/// pressing the left arrow.
+///
+/// Rows are displayed as a table below a header row. The content of each column is
+/// extracted from the rows by the [`TableColumn`]s provided to the prompt, and the columns
+/// are aligned according to the display width of their contents. When the table does not fit
+/// in the terminal, the widest columns are shrunk and their content is truncated with an ellipsis.
+///
+/// This prompt requires a prompt message, a **non-empty** `Vec` of rows and a **non-empty** `Vec`
+/// of columns. The rows can be of any type, as they are displayed through the columns.
+/// - If either list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// The user can sort the rows by pressing tab, which cycles through each column in ascending
+/// then descending order before going back to the original order.
+///
+/// The rows are paginated in order to provide a smooth experience to the user, with the default page size being 7.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Rows**: Options displayed to the user. Must be **non-empty**.
+/// - **Columns**: Columns displayed to the user. Must be **non-empty**. Columns can be excluded from
+///   the filter with [`TableColumn::without_filtering`] and compared with a custom function when sorting
+///   with [`TableColumn::with_comparator`].
+/// - **Sorting**: Column and order the rows are sorted by when the prompt is first rendered. If the column
+///   index is out-of-range of the column list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default selections**: Indexes of the rows that are selected when the prompt is first rendered. If any of
+///   the indices is out-of-range of the row list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first row). If the index
+///   is out-of-range of the row list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the content of the first column of the selected rows, separated by commas, by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+/// - **Scorer function**: Function that defines the order of rows and if they are displayed at all. It receives the
+///   content of the filterable columns, separated by spaces, as the string value of each row.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{MultiTableSelect, TableColumn};
+///
+/// struct Instance {
+///     name: &'static str,
+///     zone: &'static str,
+///     status: &'static str,
+/// }
+///
+/// let instances = vec![
+///     Instance { name: "web-1", zone: "us-east1-b", status: "RUNNING" },
+///     Instance { name: "db-1", zone: "europe-west1-d", status: "STOPPED" },
+/// ];
+///
+/// let columns = vec![
+///     TableColumn::new("NAME", &|i: &Instance| i.name.to_owned()),
+///     TableColumn::new("ZONE", &|i: &Instance| i.zone.to_owned()),
+///     TableColumn::new("STATUS", &|i: &Instance| i.status.to_owned()),
+/// ];
+///
+/// let ans = MultiTableSelect::new("Which instances do you want to stop?", instances, columns).prompt();
+///
+/// match ans {
+///     Ok(instances) => println!("Stopping {} instances...", instances.len()),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct MultiTableSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Rows displayed to the user.
+    pub options: Vec<T>,
+
+    /// Columns displayed to the user.
+    pub columns: Vec<TableColumn<'a, T>>,
+
+    /// Indexes of the rows selected by default.
+    pub default: Option<Vec<usize>>,
+
+    /// Column and order the rows are sorted by when the prompt is first rendered.
+    pub sort: Option<(usize, SortOrder)>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the rows using hjkl.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Whether to allow the rows to be filtered by user input or not.
+    ///
+    /// Defaults to true.
+    pub filter_input_enabled: bool,
+
+    /// Function called with the current user input to score the rows, receiving the
+    /// content of their filterable columns as string value.
+    pub scorer: Scorer<'a, T>,
+
+    /// Whether the scorer never matches a row for an input when it did not match
+    /// it for a prefix of that input, e.g. fuzzy or substring matching. When set, only
+    /// the rows that matched the previous input are scored again as the user types.
+    ///
+    /// True for the default scorer, and reset to false when setting another one.
+    pub monotone_scorer: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When not set, the content of the first column of the selected rows is displayed,
+    /// separated by commas.
+    pub formatter: Option<MultiOptionFormatter<'a, T>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> MultiTableSelect<'a, T> {
+    /// Default scoring function, which will create a score for the current row using the input value.
+    /// The return will be sorted in Descending order, leaving rows with None as a score out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::MultiTableSelect;
+    ///
+    /// let scorer = MultiTableSelect::<()>::DEFAULT_SCORER;
+    /// assert_eq!(None, scorer("east", &(), "web-1 europe-west1-d", 0));
+    /// assert!(scorer("east", &(), "web-1 us-east1-b", 1).is_some());
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(not(feature = "fuzzy"))]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default filter input enabled behaviour.
+    /// Defaults to true.
+    pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, space to select one, → to all, ← to none, tab to sort, type to filter");
+
+    /// Creates a [MultiTableSelect] with the provided message, rows and columns, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>, columns: Vec<TableColumn<'a, T>>) -> Self {
+        Self {
+            message,
+            options,
+            columns,
+            default: None,
+            sort: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            scorer: Self::DEFAULT_SCORER,
+            monotone_scorer: true,
+            formatter: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the indexes of the rows selected by default.
+    pub fn with_default(mut self, default: &[usize]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the column and order the rows are sorted by when the prompt is first rendered.
+    pub fn with_sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the scoring function.
+    ///
+    /// Every row is scored again on each change of the filter input, unless the
+    /// scorer is declared monotone with [`with_monotone_scorer`](Self::with_monotone_scorer).
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
+        self.monotone_scorer = false;
+        self
+    }
+
+    /// Sets whether the scorer never matches a row for an input when it did not
+    /// match it for a prefix of that input, in which case only the rows that matched
+    /// the previous input are scored again as the user types.
+    ///
+    /// Setting a scorer resets it to false, so this should be called afterwards.
+    pub fn with_monotone_scorer(mut self, monotone_scorer: bool) -> Self {
+        self.monotone_scorer = monotone_scorer;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Disables the filter input, which means the user will not be able to filter the rows
+    /// by typing.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned rows selected by the user, in their original order.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|op| op.into_iter().map(|o| o.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s containing
+    /// the index of the selections and the owned rows selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn raw_prompt_skippable(self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        match self.raw_prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(crate) fn prompt_with_backend<B: TableSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        MultiTableSelectPrompt::new(self)?.prompt(backend)
+    }
+}
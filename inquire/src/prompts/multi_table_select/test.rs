@@ -0,0 +1,140 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    MultiTableSelect, TableColumn,
+};
+
+#[derive(Debug, PartialEq)]
+struct Instance {
+    name: &'static str,
+    zone: &'static str,
+}
+
+fn instances() -> Vec<Instance> {
+    vec![
+        Instance {
+            name: "web-1",
+            zone: "us-east1-b",
+        },
+        Instance {
+            name: "db-1",
+            zone: "europe-west1-d",
+        },
+        Instance {
+            name: "cache-1",
+            zone: "asia-east1-a",
+        },
+    ]
+}
+
+fn columns() -> Vec<TableColumn<'static, Instance>> {
+    vec![
+        TableColumn::new("NAME", &|i: &Instance| i.name.to_owned()),
+        TableColumn::new("ZONE", &|i: &Instance| i.zone.to_owned()),
+    ]
+}
+
+fn checked(frame: &crate::ui::test::Frame) -> Vec<bool> {
+    frame
+        .tokens()
+        .iter()
+        .find_map(|token| match token {
+            Token::TableOptions { checked, .. } => Some(checked.clone()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn space() -> Key {
+    Key::Char(' ', KeyModifiers::NONE)
+}
+
+#[test]
+fn selected_rows_are_returned_in_original_order() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        space(),
+        Key::Up(KeyModifiers::NONE),
+        space(),
+        Key::Enter,
+    ]);
+
+    let ans = MultiTableSelect::new("Instances", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let names: Vec<&str> = ans.iter().map(|o| o.value.name).collect();
+    assert_eq!(vec!["web-1", "db-1"], names);
+    assert_eq!(vec![0, 1], ans.iter().map(|o| o.index).collect::<Vec<_>>());
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Instances".into(),
+            "web-1, db-1".into()
+        )));
+}
+
+#[test]
+fn right_arrow_selects_displayed_rows_only() {
+    let mut keys = Key::char_keys_from_str("asia");
+    keys.push(Key::Right(KeyModifiers::NONE));
+    keys.extend(vec![Key::Backspace; 4]);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = MultiTableSelect::new("Instances", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.len());
+    assert_eq!("cache-1", ans[0].value.name);
+
+    let frames = backend.frames();
+    assert_eq!(vec![false, false, true], checked(&frames[frames.len() - 2]));
+}
+
+#[test]
+fn left_arrow_clears_default_selections() {
+    let mut backend = FakeBackend::new(vec![Key::Left(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = MultiTableSelect::new("Instances", instances(), columns())
+        .with_default(&[0, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.is_empty());
+    assert_eq!(vec![true, false, true], checked(&backend.frames()[0]));
+}
+
+#[test]
+fn selections_follow_rows_when_sorting() {
+    let mut backend = FakeBackend::new(vec![Key::Tab, Key::Enter]);
+
+    let ans = MultiTableSelect::new("Instances", instances(), columns())
+        .with_default(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("db-1", ans[0].value.name);
+    assert_eq!(vec![false, true, false], checked(&backend.frames()[0]));
+    assert_eq!(vec![false, true, false], checked(&backend.frames()[1]));
+}
+
+#[test]
+fn out_of_bounds_default_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = MultiTableSelect::new("Instances", instances(), columns())
+        .with_default(&[3])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::InquireError::InvalidConfiguration(_))
+    ));
+}
@@ -0,0 +1,73 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::MultiTreeSelectConfig;
+
+/// Set of actions for a MultiTreeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiTreeSelectPromptAction {
+    /// Action on the value text input handler.
+    FilterInput(InputAction),
+    /// Moves the cursor to the node above.
+    MoveUp,
+    /// Moves the cursor to the node below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Expands the current node, or moves the cursor to its first child if
+    /// already expanded.
+    Expand,
+    /// Collapses the current node, or moves the cursor to its parent if
+    /// already collapsed.
+    Collapse,
+    /// Toggles the selection of the current node.
+    ToggleCurrentNode,
+}
+
+impl InnerAction for MultiTreeSelectPromptAction {
+    type Config = MultiTreeSelectConfig;
+
+    fn from_key(key: Key, config: &MultiTreeSelectConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::Collapse),
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::Expand),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) => Self::Expand,
+            Key::Left(KeyModifiers::NONE) => Self::Collapse,
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentNode,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::FilterInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
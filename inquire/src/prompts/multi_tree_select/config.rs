@@ -0,0 +1,19 @@
+use crate::MultiTreeSelect;
+
+/// Configuration settings used in the execution of a MultiTreeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiTreeSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of nodes.
+    pub page_size: usize,
+}
+
+impl<T> From<&MultiTreeSelect<'_, T>> for MultiTreeSelectConfig {
+    fn from(value: &MultiTreeSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
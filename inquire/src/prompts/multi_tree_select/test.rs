@@ -0,0 +1,131 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token, TreeRowToken},
+        Key, KeyModifiers,
+    },
+    MultiTreeSelect, TreeNode,
+};
+
+fn namespaces() -> Vec<TreeNode<&'static str>> {
+    vec![
+        TreeNode::new("default")
+            .with_children(vec![TreeNode::new("api"), TreeNode::new("web")])
+            .with_expanded(true),
+        TreeNode::new("monitoring")
+            .with_children(vec![TreeNode::new("grafana"), TreeNode::new("prometheus")]),
+    ]
+}
+
+fn row(value: &str, depth: usize, expanded: Option<bool>, checked: bool) -> TreeRowToken {
+    TreeRowToken {
+        value: value.to_owned(),
+        depth,
+        expanded,
+        checked: Some(checked),
+    }
+}
+
+fn space() -> Key {
+    Key::Char(' ', KeyModifiers::NONE)
+}
+
+fn down() -> Key {
+    Key::Down(KeyModifiers::NONE)
+}
+
+#[test]
+fn selected_nodes_are_returned_in_tree_order() {
+    let mut backend = FakeBackend::new(vec![
+        down(),
+        down(),
+        down(),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        space(),
+        Key::Home,
+        down(),
+        space(),
+        Key::Enter,
+    ]);
+
+    let ans = MultiTreeSelect::new("Resources", namespaces())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![vec!["default", "api"], vec!["monitoring", "grafana"]],
+        ans
+    );
+
+    let frames = backend.frames();
+    assert!(frames[frames.len() - 2].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("default", 0, Some(true), false),
+            row("api", 1, None, true),
+            row("web", 1, None, false),
+            row("monitoring", 0, Some(true), false),
+            row("grafana", 1, None, true),
+            row("prometheus", 1, None, false),
+        ],
+        cursor: Some(1),
+    }));
+}
+
+#[test]
+fn space_toggles_selection_of_parent_only() {
+    let mut backend = FakeBackend::new(vec![space(), down(), space(), space(), Key::Enter]);
+
+    let ans = MultiTreeSelect::new("Resources", namespaces())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![vec!["default"]], ans);
+}
+
+#[test]
+fn selections_are_kept_while_filtering() {
+    let mut keys = vec![down(), space()];
+    keys.extend(Key::char_keys_from_str("prom"));
+    keys.push(space());
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = MultiTreeSelect::new("Resources", namespaces())
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![vec!["default", "api"], vec!["monitoring", "prometheus"]],
+        ans
+    );
+}
+
+#[test]
+fn submitting_without_selections_returns_empty_list() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = MultiTreeSelect::new("Resources", namespaces())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.is_empty());
+}
+
+#[test]
+fn answer_is_formatted_as_paths() {
+    let mut backend = FakeBackend::new(vec![space(), down(), space(), Key::Enter]);
+
+    MultiTreeSelect::new("Resources", namespaces())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Resources".into(),
+            "default, default/api".into()
+        )));
+}
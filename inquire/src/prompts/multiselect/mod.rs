@@ -21,6 +21,9 @@ use crate::{
     validator::MultiOptionValidator,
 };
 
+#[cfg(feature = "parallel")]
+use crate::type_aliases::ParallelScorer;
+
 use self::prompt::MultiSelectPrompt;
 
 #[cfg(feature = "fuzzy")]
@@ -50,6 +53,7 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
+/// - **Minimum and maximum selections**: Limits on the number of selected options. Toggling more options than the maximum is refused, while submitting less options than the minimum displays an error. When any of them is set, a counter of selected options is displayed next to the prompt message.
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
@@ -101,6 +105,18 @@ pub struct MultiSelect<'a, T> {
     /// The list of options is sorted in descending order (highest score first)
     pub scorer: Scorer<'a, T>,
 
+    /// Function called with the current user input to score the provided
+    /// options in parallel. When set, it is used instead of `scorer`.
+    #[cfg(feature = "parallel")]
+    pub parallel_scorer: Option<ParallelScorer<'a>>,
+
+    /// Whether the scorer never matches an option for an input when it did not match
+    /// it for a prefix of that input, e.g. fuzzy or substring matching. When set, only
+    /// the options that matched the previous input are scored again as the user types.
+    ///
+    /// True for the default scorer, and reset to false when setting another one.
+    pub monotone_scorer: bool,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
@@ -112,6 +128,12 @@ pub struct MultiSelect<'a, T> {
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
 
+    /// Minimum number of options the user must select before submitting.
+    pub min_selections: Option<usize>,
+
+    /// Maximum number of options the user is allowed to select.
+    pub max_selections: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -193,6 +215,35 @@ where
             }
         };
 
+    /// Default parallel scoring function, equivalent to [`DEFAULT_SCORER`](Self::DEFAULT_SCORER)
+    /// and meant to be set with [`with_parallel_scorer`](Self::with_parallel_scorer).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::MultiSelect;
+    ///
+    /// let scorer = MultiSelect::<&str>::DEFAULT_PARALLEL_SCORER;
+    /// assert_eq!(None,     scorer("sa", "New York",   0));
+    /// assert_eq!(Some(49), scorer("sa", "Sacramento", 1));
+    /// assert_eq!(Some(35), scorer("sa", "Kansas",     2));
+    /// ```
+    #[cfg(all(feature = "parallel", feature = "fuzzy"))]
+    pub const DEFAULT_PARALLEL_SCORER: ParallelScorer<'a> =
+        &|input, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(all(feature = "parallel", not(feature = "fuzzy")))]
+    pub const DEFAULT_PARALLEL_SCORER: ParallelScorer<'a> =
+        &|input, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
@@ -232,8 +283,13 @@ where
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
             scorer: Self::DEFAULT_SCORER,
+            #[cfg(feature = "parallel")]
+            parallel_scorer: None,
+            monotone_scorer: true,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
+            min_selections: None,
+            max_selections: None,
             render_config: get_configuration(),
         }
     }
@@ -269,8 +325,34 @@ where
     }
 
     /// Sets the scoring function.
+    ///
+    /// Every option is scored again on each change of the filter input, unless the
+    /// scorer is declared monotone with [`with_monotone_scorer`](Self::with_monotone_scorer).
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
+        self.monotone_scorer = false;
+        self
+    }
+
+    /// Sets the scoring function used to filter the options in parallel.
+    ///
+    /// Worth it for lists with several thousands of options, this scorer takes
+    /// precedence over the one set by [`with_scorer`](Self::with_scorer).
+    #[cfg(feature = "parallel")]
+    pub fn with_parallel_scorer(mut self, scorer: ParallelScorer<'a>) -> Self {
+        self.parallel_scorer = Some(scorer);
+        self.monotone_scorer = false;
+        self
+    }
+
+    /// Sets whether the scorer never matches an option for an input when it did not
+    /// match it for a prefix of that input, as fuzzy or substring matching, in which
+    /// case only the options that matched the previous input are scored again as the
+    /// user types.
+    ///
+    /// Setting a scorer resets it to false, so this should be called afterwards.
+    pub fn with_monotone_scorer(mut self, monotone_scorer: bool) -> Self {
+        self.monotone_scorer = monotone_scorer;
         self
     }
 
@@ -293,6 +375,22 @@ where
         self
     }
 
+    /// Sets the minimum number of options the user must select. Submitting less
+    /// options displays an error message one line above the prompt.
+    pub fn with_min_selections(mut self, min_selections: usize) -> Self {
+        self.min_selections = Some(min_selections);
+        self
+    }
+
+    /// Sets the maximum number of options the user is allowed to select. Selecting
+    /// more options is refused, displaying an error message one line above the prompt.
+    ///
+    /// Selecting all options selects only the first ones displayed, up to the maximum.
+    pub fn with_max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
     /// Sets the indexes to be selected by default.
     ///
     /// The values should be valid indexes for the given option list. Any
@@ -1,18 +1,24 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{collections::BTreeSet, fmt::Display};
 
 use crate::{
     error::InquireResult,
     formatter::MultiOptionFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        scoring::ScoreCache,
+    },
     type_aliases::Scorer,
     ui::MultiSelectBackend,
-    utils::paginate,
+    utils::{max_selections_message, min_selections_message, paginate},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
     InquireError, MultiSelect,
 };
 
+#[cfg(feature = "parallel")]
+use crate::type_aliases::ParallelScorer;
+
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
 
 pub struct MultiSelectPrompt<'a, T> {
@@ -26,9 +32,15 @@ pub struct MultiSelectPrompt<'a, T> {
     input: Option<Input>,
     scored_options: Vec<usize>,
     scorer: Scorer<'a, T>,
+    #[cfg(feature = "parallel")]
+    parallel_scorer: Option<ParallelScorer<'a>>,
+    score_cache: ScoreCache,
     formatter: MultiOptionFormatter<'a, T>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    min_selections: Option<usize>,
+    max_selections: Option<usize>,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
@@ -53,9 +65,18 @@ where
             }
         }
 
+        if let (Some(min), Some(max)) = (mso.min_selections, mso.max_selections) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum number of selections {} is greater than the maximum {}",
+                    min, max
+                )));
+            }
+        }
+
         let string_options = mso.options.iter().map(T::to_string).collect();
         let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
+        let checked_options: BTreeSet<usize> = mso
             .default
             .as_ref()
             .map(|d| {
@@ -66,6 +87,16 @@ where
             })
             .unwrap_or_default();
 
+        if let Some(max) = mso.max_selections {
+            if checked_options.len() > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "{} options are selected by default, more than the maximum of {}",
+                    checked_options.len(),
+                    max
+                )));
+            }
+        }
+
         let input = match mso.filter_input_enabled {
             true => Some(Input::new_with(
                 mso.starting_filter_input.unwrap_or_default(),
@@ -83,9 +114,15 @@ where
             cursor_index: mso.starting_cursor,
             input,
             scorer: mso.scorer,
+            #[cfg(feature = "parallel")]
+            parallel_scorer: mso.parallel_scorer,
+            score_cache: ScoreCache::new(mso.monotone_scorer),
             formatter: mso.formatter,
             validator: mso.validator,
+            min_selections: mso.min_selections,
+            max_selections: mso.max_selections,
             error: None,
+            warning: None,
             checked: checked_options,
         })
     }
@@ -136,13 +173,52 @@ where
 
         if self.checked.contains(idx) {
             self.checked.remove(idx);
+        } else if self.is_at_max_selections() {
+            self.error = self
+                .max_selections
+                .map(|max| max_selections_message(max, "option"));
+            return ActionResult::NeedsRedraw;
         } else {
             self.checked.insert(*idx);
         }
 
+        self.error = None;
+        self.warning = None;
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_at_max_selections(&self) -> bool {
+        match self.max_selections {
+            Some(max) => self.checked.len() >= max,
+            None => false,
+        }
+    }
+
+    fn select_all(&mut self) -> ActionResult {
+        self.checked.clear();
+        self.error = None;
+        self.warning = None;
+
+        let max = self.max_selections.unwrap_or(usize::MAX);
+        for idx in self.scored_options.iter().take(max) {
+            self.checked.insert(*idx);
+        }
+
+        if self.scored_options.len() > max {
+            self.error = Some(max_selections_message(max, "option"));
+        }
+
         ActionResult::NeedsRedraw
     }
 
+    fn selection_counter(&self) -> Option<String> {
+        match (self.min_selections, self.max_selections) {
+            (_, Some(max)) => Some(format!("{}/{} selected", self.checked.len(), max)),
+            (Some(_), None) => Some(format!("{} selected", self.checked.len())),
+            (None, None) => None,
+        }
+    }
+
     fn clear_input_if_needed(&mut self, action: MultiSelectPromptAction) -> ActionResult {
         if self.config.keep_filter {
             return ActionResult::Clean;
@@ -170,6 +246,12 @@ where
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(min) = self.min_selections {
+            if self.checked.len() < min {
+                return Ok(Validation::Invalid(min_selections_message(min, "option")));
+            }
+        }
+
         if let Some(validator) = &self.validator {
             let selected_options = self
                 .options
@@ -211,25 +293,32 @@ where
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
-
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        let options = &self.options;
+        let string_options = &self.string_options;
+        let scorer = self.scorer;
+
+        #[cfg(feature = "parallel")]
+        let new_scored_options = match self.parallel_scorer {
+            Some(parallel_scorer) => self
+                .score_cache
+                .score_parallel(content, options.len(), |i| {
+                    parallel_scorer(content, &string_options[i], i)
+                }),
+            None => self.score_cache.score(content, options.len(), |i| {
+                scorer(content, &options[i], &string_options[i], i)
+            }),
+        };
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        #[cfg(not(feature = "parallel"))]
+        let new_scored_options = self.score_cache.score(content, options.len(), |i| {
+            scorer(content, &options[i], &string_options[i], i)
+        });
 
         if self.scored_options == new_scored_options {
             return;
         }
 
-        self.scored_options = new_scored_options;
+        self.scored_options = new_scored_options.to_vec();
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
@@ -267,15 +356,13 @@ where
     }
 
     fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
-        let answer = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_final_answer()),
-            Validation::Invalid(msg) => {
-                self.error = Some(msg);
-                None
-            }
-        };
+        let validation = self.validate_current_answer()?;
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(self.get_final_answer()));
+        }
 
-        Ok(answer)
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
     }
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
@@ -289,15 +376,11 @@ where
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
-            MultiSelectPromptAction::SelectAll => {
-                self.checked.clear();
-                for idx in &self.scored_options {
-                    self.checked.insert(*idx);
-                }
-                ActionResult::NeedsRedraw
-            }
+            MultiSelectPromptAction::SelectAll => self.select_all(),
             MultiSelectPromptAction::ClearSelections => {
                 self.checked.clear();
+                self.error = None;
+                self.warning = None;
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
@@ -324,9 +407,16 @@ where
 
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
         }
 
-        backend.render_multiselect_prompt(prompt, self.input.as_ref())?;
+        let selection_counter = self.selection_counter();
+        backend.render_multiselect_prompt(
+            prompt,
+            selection_counter.as_deref(),
+            self.input.as_ref(),
+        )?;
 
         let choices = self
             .scored_options
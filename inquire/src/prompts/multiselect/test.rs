@@ -1,9 +1,12 @@
+use rstest::rstest;
+
 use crate::{
     formatter::MultiOptionFormatter,
     list_option::ListOption,
     test::fake_backend,
     ui::{Key, KeyModifiers},
-    MultiSelect,
+    validator::Validation,
+    InquireError, MultiSelect,
 };
 
 #[test]
@@ -255,3 +258,170 @@ fn keep_filter_false_should_reset_option_list() {
     let expected_answer = vec![ListOption::new(0, 1), ListOption::new(2, 3)];
     assert_eq!(expected_answer, ans);
 }
+
+#[test]
+fn select_all_after_deleting_filter_characters_uses_restored_matches() {
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE), // filter to option 10
+        Key::Backspace,                     // filter to options 1 and 10
+        Key::Right(KeyModifiers::NONE),     // select all displayed options
+        Key::Enter,
+    ]);
+
+    let options = (1..=10).collect::<Vec<u32>>();
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![ListOption::new(0, 1), ListOption::new(9, 10)];
+    assert_eq!(expected_answer, ans);
+}
+
+#[test]
+fn toggles_beyond_max_selections_are_refused() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // refused, two options are already selected
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_max_selections(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![ListOption::new(0, 1), ListOption::new(1, 2)];
+    assert_eq!(expected_answer, ans);
+}
+
+#[test]
+fn unselecting_frees_room_for_max_selections() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // refused
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // unselects option 1
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_max_selections(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 2)], ans);
+}
+
+#[rstest]
+#[case::arrow(Key::Right(KeyModifiers::NONE), false)]
+#[case::vim(Key::Char('l', KeyModifiers::NONE), true)]
+fn select_all_respects_max_selections(#[case] select_all: Key, #[case] vim_mode: bool) {
+    let mut backend = fake_backend(vec![select_all, Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_vim_mode(vim_mode)
+        .with_max_selections(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![
+        ListOption::new(0, 1),
+        ListOption::new(1, 2),
+        ListOption::new(2, 3),
+    ];
+    assert_eq!(expected_answer, ans);
+}
+
+#[test]
+fn submit_is_refused_below_min_selections() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter, // refused, only one option is selected
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_min_selections(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![ListOption::new(0, 1), ListOption::new(1, 2)];
+    assert_eq!(expected_answer, ans);
+}
+
+#[test]
+fn min_selections_greater_than_max_is_invalid() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_min_selections(3)
+        .with_max_selections(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn default_selections_beyond_max_selections_are_invalid() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_default(&[0, 1, 2])
+        .with_max_selections(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn selection_with_a_warning_is_accepted_on_the_second_submit() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_validator(|selected: &[ListOption<&i32>]| match selected.len() {
+            0 | 1 => Ok(Validation::Valid),
+            _ => Ok(Validation::Warning("More than one option selected".into())),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![
+        ListOption::new(0, 1),
+        ListOption::new(1, 2),
+        ListOption::new(2, 3),
+    ];
+    assert_eq!(expected_answer, ans);
+}
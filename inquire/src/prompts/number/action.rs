@@ -0,0 +1,37 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::NumberConfig;
+
+/// Set of actions for a NumberPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Increments the value by the step.
+    Increment,
+    /// Decrements the value by the step.
+    Decrement,
+    /// Increments the value by the page step.
+    IncrementPage,
+    /// Decrements the value by the page step.
+    DecrementPage,
+}
+
+impl InnerAction for NumberPromptAction {
+    type Config = NumberConfig;
+
+    fn from_key(key: Key, _config: &NumberConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(_) => Self::Increment,
+            Key::Down(_) => Self::Decrement,
+            Key::PageUp(_) => Self::IncrementPage,
+            Key::PageDown(_) => Self::DecrementPage,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,294 @@
+mod action;
+mod config;
+mod numeric;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use numeric::Numeric;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, NumberBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::NumberPrompt;
+
+/// Prompt for numeric values that can be typed or stepped with the arrow keys.
+///
+/// It works with all primitive integer and float types. The Up and Down arrows increment and
+/// decrement the value by the [`step`](Self::step), while PageUp and PageDown use the larger
+/// [`page_step`](Self::page_step). Stepping starts from the current input, or from the default
+/// value (or zero) when the input is empty or not a valid number.
+///
+/// Stepped values are clamped to the optional [`min`](Self::min) and [`max`](Self::max) bounds.
+/// Typed values out of the bounds are flagged while typing and rejected on submission. When no
+/// placeholder is set, the bounds are described in the placeholder of the empty input.
+///
+/// An optional unit, such as "MB", is rendered after the input and appended to the final answer.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Number;
+///
+/// let size = Number::<u32>::new("Cache size:")
+///     .with_min(16)
+///     .with_max(4096)
+///     .with_step(16)
+///     .with_page_step(256)
+///     .with_default(512)
+///     .with_unit("MB")
+///     .prompt();
+///
+/// match size {
+///     Ok(size) => println!("Allocating {} MB", size),
+///     Err(_) => println!("An error happened, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Number<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_value: Option<T>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Minimum accepted value, inclusive.
+    pub min: Option<T>,
+
+    /// Maximum accepted value, inclusive.
+    pub max: Option<T>,
+
+    /// Amount added or subtracted by the Up and Down arrows.
+    pub step: T,
+
+    /// Amount added or subtracted by the PageUp and PageDown keys.
+    pub page_step: T,
+
+    /// Unit of the value, rendered after the input.
+    pub unit: Option<&'a str>,
+
+    /// Short hint that describes the expected value of the input.
+    ///
+    /// When not set, the placeholder describes the bounds of the value, if any.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// The unit, if any, is appended to the formatted value.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that formats the default value.
+    pub default_value_formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, after the bounds are checked,
+    /// stopping at and displaying to the user only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Error message displayed when value could not be parsed from input.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Number<'a, T>
+where
+    T: Numeric,
+{
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to step the value, page up/down for larger steps");
+
+    /// Default validators added to the [Number] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Creates a [Number] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_value: None,
+            default: None,
+            min: None,
+            max: None,
+            step: T::DEFAULT_STEP,
+            page_step: T::DEFAULT_PAGE_STEP,
+            unit: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|val| val.to_string(),
+            default_value_formatter: &|val| val.to_string(),
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: "Invalid input".into(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
+    ///
+    /// [`with_default`]: Self::with_default
+    pub fn with_starting_value(mut self, value: T) -> Self {
+        self.starting_value = Some(value);
+        self
+    }
+
+    /// Sets the default value.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the minimum accepted value, inclusive.
+    pub fn with_min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum accepted value, inclusive.
+    pub fn with_max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount added or subtracted by the Up and Down arrows.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the amount added or subtracted by the PageUp and PageDown keys.
+    pub fn with_page_step(mut self, page_step: T) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Sets the unit of the value, rendered after the input.
+    pub fn with_unit(mut self, unit: &'a str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the formatter for default values.
+    pub fn with_default_value_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, after the bounds are checked,
+    /// stopping at and displaying to the user only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: NumberBackend>(self, backend: &mut B) -> InquireResult<T> {
+        NumberPrompt::from(self).prompt(backend)
+    }
+}
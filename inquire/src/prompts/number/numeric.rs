@@ -0,0 +1,147 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Numeric types that can be used in [`Number`](crate::Number) and [`Slider`](crate::Slider) prompts.
+///
+/// It is implemented for all primitive integer and float types. Integers saturate at the
+/// bounds of the type when stepped, and floats are rounded to the decimal places of the
+/// stepped value and the step, so that stepping `0.2` by `0.1` results in `0.3`, and
+/// saturate at the largest finite values of the type.
+pub trait Numeric: Copy + PartialOrd + FromStr + Display {
+    /// Zero, used as the starting value when stepping an empty input without a default.
+    const ZERO: Self;
+
+    /// Default step of the Up and Down arrows.
+    const DEFAULT_STEP: Self;
+
+    /// Default step of the PageUp and PageDown keys.
+    const DEFAULT_PAGE_STEP: Self;
+
+    /// Adds the step to the value.
+    fn step_up(self, step: Self) -> Self;
+
+    /// Subtracts the step from the value.
+    fn step_down(self, step: Self) -> Self;
+
+    /// Converts the value to an `f64`, used to position it on a slider.
+    fn as_f64(self) -> f64;
+
+    /// Whether the value is a finite number, as opposed to a NaN or an infinity.
+    ///
+    /// Non-finite values are rejected as answers. Always `true` by default.
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_numeric_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const ZERO: Self = 0;
+                const DEFAULT_STEP: Self = 1;
+                const DEFAULT_PAGE_STEP: Self = 10;
+
+                fn step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_numeric_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const ZERO: Self = 0.0;
+                const DEFAULT_STEP: Self = 1.0;
+                const DEFAULT_PAGE_STEP: Self = 10.0;
+
+                fn step_up(self, step: Self) -> Self {
+                    let value = (self + step).clamp(<$t>::MIN, <$t>::MAX);
+                    round_to_decimals(value, decimals(self).max(decimals(step)))
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    let value = (self - step).clamp(<$t>::MIN, <$t>::MAX);
+                    round_to_decimals(value, decimals(self).max(decimals(step)))
+                }
+
+                fn as_f64(self) -> f64 {
+                    self.into()
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_numeric_for_float!(f32, f64);
+
+/// Number of decimal places of the shortest representation of the value.
+fn decimals<T: Display>(value: T) -> i32 {
+    let repr = value.to_string();
+    let decimals = repr.split_once('.').map(|(_, d)| d.len()).unwrap_or(0);
+
+    // Beyond this, the scaling factor is no longer exact and would add errors instead.
+    decimals.min(15) as i32
+}
+
+fn round_to_decimals<T>(value: T, decimals: i32) -> T
+where
+    T: Into<f64> + FromStr + Copy,
+{
+    let factor = 10f64.powi(decimals);
+    let scaled = value.into() * factor;
+
+    // Values this large have no decimal places left to round, and scaling them overflows.
+    if !scaled.is_finite() {
+        return value;
+    }
+
+    let rounded = scaled.round() / factor;
+
+    // Going through the shortest representation converts f64 back to f32 without
+    // bringing back the rounding error.
+    rounded.to_string().parse().unwrap_or(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Numeric;
+
+    #[test]
+    fn integers_saturate() {
+        assert_eq!(255u8, 250u8.step_up(10));
+        assert_eq!(0u8, 5u8.step_down(10));
+        assert_eq!(-3i32, 7i32.step_down(10));
+    }
+
+    #[test]
+    fn floats_are_rounded_to_the_decimals_of_their_operands() {
+        assert_eq!(0.3f64, 0.2f64.step_up(0.1));
+        assert_eq!(0.7f32, 0.8f32.step_down(0.1));
+        assert_eq!(1.125f64, 1.025f64.step_up(0.1));
+        assert_eq!(-0.5f64, 0.5f64.step_down(1.0));
+    }
+
+    #[test]
+    fn floats_saturate_at_the_largest_finite_values() {
+        assert_eq!(f64::MAX, f64::MAX.step_up(f64::MAX));
+        assert_eq!(f64::MIN, f64::MIN.step_down(f64::MAX));
+        assert_eq!(f32::MAX, f32::MAX.step_up(1.0));
+        assert_eq!(1.5e308f64, 1.5e308f64.step_up(0.1));
+    }
+}
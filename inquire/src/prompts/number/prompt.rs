@@ -0,0 +1,262 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::NumberBackend,
+    validator::{validate_all, CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, Number,
+};
+
+use super::{action::NumberPromptAction, config::NumberConfig, Numeric};
+
+pub struct NumberPrompt<'a, T> {
+    message: &'a str,
+    config: NumberConfig,
+    error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    page_step: T,
+    unit: Option<&'a str>,
+    placeholder: Option<String>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    default_value_formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    error_message: String,
+}
+
+impl<'a, T> From<Number<'a, T>> for NumberPrompt<'a, T>
+where
+    T: Numeric,
+{
+    fn from(no: Number<'a, T>) -> Self {
+        let placeholder = match no.placeholder {
+            Some(placeholder) => Some(placeholder.to_owned()),
+            None => bounds_description(no.min, no.max),
+        };
+
+        let mut prompt = Self {
+            message: no.message,
+            config: (&no).into(),
+            error: None,
+            warning: None,
+            help_message: no.help_message,
+            default: no.default,
+            min: no.min,
+            max: no.max,
+            step: no.step,
+            page_step: no.page_step,
+            unit: no.unit,
+            placeholder,
+            input: Input::new(),
+            formatter: no.formatter,
+            default_value_formatter: no.default_value_formatter,
+            validators: no.validators,
+            error_message: no.error_message,
+        };
+
+        let starting_input = no
+            .starting_value
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+        prompt.set_input(starting_input);
+
+        prompt
+    }
+}
+
+/// Description of the bounds, such as "between 1 and 10", if any.
+fn bounds_description<T: Numeric>(min: Option<T>, max: Option<T>) -> Option<String> {
+    match (min, max) {
+        (Some(min), Some(max)) => Some(format!("between {min} and {max}")),
+        (Some(min), None) => Some(format!("at least {min}")),
+        (None, Some(max)) => Some(format!("at most {max}")),
+        (None, None) => None,
+    }
+}
+
+impl<'a, T> NumberPrompt<'a, T>
+where
+    T: Numeric,
+{
+    fn set_input(&mut self, content: String) {
+        let input = Input::new_with(content);
+        self.input = match &self.placeholder {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        };
+    }
+
+    /// Parses the input, rejecting values that are not finite, such as `NaN` or `inf`.
+    fn parse_input(&self) -> Option<T> {
+        self.input
+            .content()
+            .parse::<T>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    fn is_in_range(&self, value: T) -> bool {
+        value.is_finite()
+            && !matches!(self.min, Some(min) if value < min)
+            && !matches!(self.max, Some(max) if value > max)
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn range_error(&self) -> ErrorMessage {
+        let bounds = bounds_description(self.min, self.max).unwrap_or_default();
+        let message = match self.unit {
+            Some(unit) => format!("The value should be {bounds} {unit}"),
+            None => format!("The value should be {bounds}"),
+        };
+
+        ErrorMessage::Custom(message)
+    }
+
+    /// Error displayed while typing, only when the input is a number out of the bounds.
+    fn live_error(&self) -> Option<ErrorMessage> {
+        match self.parse_input() {
+            Some(value) if !self.is_in_range(value) => Some(self.range_error()),
+            _ => None,
+        }
+    }
+
+    fn step_by(&mut self, step: T, up: bool) -> ActionResult {
+        let value = match self.parse_input() {
+            Some(value) => value,
+            None => self.default.unwrap_or(T::ZERO),
+        };
+
+        let value = match up {
+            true => value.step_up(step),
+            false => value.step_down(step),
+        };
+        let value = self.clamp(value).to_string();
+
+        if value == self.input.content() {
+            return ActionResult::Clean;
+        }
+
+        self.set_input(value);
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        if !value.is_finite() {
+            return Ok(Validation::Invalid(self.error_message.clone().into()));
+        }
+
+        if !self.is_in_range(*value) {
+            return Ok(Validation::Invalid(self.range_error()));
+        }
+
+        validate_all(self.validators.iter().map(|v| v.validate(value)))
+            .map_err(InquireError::Custom)
+    }
+
+    fn get_final_answer(&self) -> Result<T, String> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        self.parse_input().ok_or_else(|| self.error_message.clone())
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for NumberPrompt<'a, T>
+where
+    Backend: NumberBackend,
+    T: Numeric,
+{
+    type Config = NumberConfig;
+    type InnerAction = NumberPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &NumberConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        let formatted = (self.formatter)(*answer);
+
+        match self.unit {
+            Some(unit) => format!("{formatted} {unit}"),
+            None => formatted,
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let (answer, validation) = match self.get_final_answer() {
+            Ok(answer) => {
+                let validation = self.validate_current_answer(&answer)?;
+                (Some(answer), validation)
+            }
+            Err(message) => (None, Validation::Invalid(message.into())),
+        };
+
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(answer);
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: NumberPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            NumberPromptAction::ValueInput(input_action) => self.input.handle(input_action).into(),
+            NumberPromptAction::Increment => self.step_by(self.step, true),
+            NumberPromptAction::Decrement => self.step_by(self.step, false),
+            NumberPromptAction::IncrementPage => self.step_by(self.page_step, true),
+            NumberPromptAction::DecrementPage => self.step_by(self.page_step, false),
+        };
+
+        if result.needs_redraw() {
+            self.error = self.live_error();
+            self.warning = None;
+        }
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        }
+
+        let default_value_formatter = self.default_value_formatter;
+        let default_message = self.default.map(default_value_formatter);
+
+        backend.render_number_prompt(
+            self.message,
+            default_message.as_deref(),
+            &self.input,
+            self.unit,
+        )?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
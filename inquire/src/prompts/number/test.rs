@@ -0,0 +1,279 @@
+use rstest::rstest;
+
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    Number,
+};
+
+macro_rules! text_to_events {
+    ($text:expr) => {{
+        $text
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .collect::<Vec<Key>>()
+    }};
+}
+
+fn error_of(tokens: &[Token]) -> Option<&ErrorMessage> {
+    tokens.iter().find_map(|token| match token {
+        Token::ErrorMessage(error) => Some(error),
+        _ => None,
+    })
+}
+
+#[test]
+fn arrows_step_the_value() {
+    let keys = vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<i32>::new("")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(2, ans);
+}
+
+#[test]
+fn page_keys_use_the_page_step() {
+    let keys = vec![
+        Key::PageUp(KeyModifiers::NONE),
+        Key::PageUp(KeyModifiers::NONE),
+        Key::PageDown(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<u64>::new("")
+        .with_step(5)
+        .with_page_step(25)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(30, ans);
+}
+
+#[test]
+fn stepping_starts_from_the_default() {
+    let keys = vec![Key::Up(KeyModifiers::NONE), Key::Enter];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<i32>::new("")
+        .with_default(10)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(11, ans);
+}
+
+#[test]
+fn stepped_values_are_clamped_to_the_bounds() {
+    let keys = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<i32>::new("")
+        .with_min(3)
+        .with_max(8)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(3, ans);
+
+    let keys = vec![
+        Key::PageUp(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<i32>::new("")
+        .with_min(3)
+        .with_max(8)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(8, ans);
+}
+
+#[test]
+fn unsigned_values_saturate_at_zero() {
+    let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<u8>::new("")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0, ans);
+}
+
+#[test]
+fn float_steps_are_not_affected_by_rounding_errors() {
+    let keys = vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<f64>::new("")
+        .with_starting_value(0.1)
+        .with_step(0.1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0.3, ans);
+}
+
+#[test]
+fn empty_input_returns_the_default() {
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+
+    let ans = Number::<f32>::new("")
+        .with_default(2.5)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(2.5, ans);
+}
+
+#[test]
+fn out_of_range_input_is_flagged_while_typing_and_rejected() {
+    let mut keys = text_to_events!("20");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<i32>::new("")
+        .with_max(10)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(2, ans);
+
+    let expected = ErrorMessage::Custom("The value should be at most 10".into());
+    let frames = backend.frames();
+    assert_eq!(None, error_of(frames[1].tokens()));
+    assert_eq!(Some(&expected), error_of(frames[2].tokens()));
+    assert_eq!(Some(&expected), error_of(frames[3].tokens()));
+    assert_eq!(None, error_of(frames[4].tokens()));
+}
+
+#[test]
+fn invalid_input_shows_the_error_message() {
+    let mut keys = text_to_events!("1x");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<i32>::new("")
+        .with_error_message("Please type a number")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans);
+
+    let expected = ErrorMessage::Custom("Please type a number".into());
+    assert_eq!(Some(&expected), error_of(backend.frames()[3].tokens()));
+}
+
+#[rstest]
+#[case("NaN")]
+#[case("inf")]
+#[case("-inf")]
+#[case("1e309")]
+fn non_finite_inputs_are_rejected(#[case] input: &str) {
+    let mut keys = text_to_events!(input);
+    keys.push(Key::Enter);
+    keys.extend(vec![Key::Backspace; input.len()]);
+    keys.push(Key::Char('5', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<f64>::new("")
+        .with_min(0.0)
+        .with_max(10.0)
+        .with_error_message("Please type a number")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(5.0, ans);
+
+    let expected = ErrorMessage::Custom("Please type a number".into());
+    let frames = backend.frames();
+    // Not flagged as out of range while typing, but not accepted either.
+    assert_eq!(None, error_of(frames[input.len()].tokens()));
+    assert_eq!(Some(&expected), error_of(frames[input.len() + 1].tokens()));
+}
+
+#[test]
+fn stepping_from_a_non_finite_input_starts_from_zero() {
+    let mut keys = text_to_events!("NaN");
+    keys.push(Key::Up(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Number::<f64>::new("")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1.0, ans);
+}
+
+#[test]
+fn values_near_the_largest_float_do_not_overflow() {
+    let mut keys = text_to_events!("1.7e308");
+    keys.push(Key::Up(KeyModifiers::NONE));
+    keys.push(Key::PageUp(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Number::<f64>::new("")
+        .with_page_step(f64::MAX)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(f64::MAX, ans);
+}
+
+#[test]
+fn bounds_and_unit_are_rendered() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Number::<u32>::new("Size:")
+        .with_min(1)
+        .with_max(512)
+        .with_default(64)
+        .with_unit("MB")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(64, ans);
+
+    let first = &backend.frames()[0];
+    assert!(first.has_token(&Token::Unit("MB".into())));
+    let placeholder = first.tokens().iter().find_map(|token| match token {
+        Token::Input(input) => input.placeholder(),
+        _ => None,
+    });
+    assert_eq!(Some("between 1 and 512"), placeholder);
+
+    let last = backend.frames().last().unwrap();
+    assert!(last.has_token(&Token::AnsweredPrompt("Size:".into(), "64 MB".into())));
+}
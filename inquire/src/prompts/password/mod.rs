@@ -1,11 +1,17 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(feature = "zeroize")]
+mod secret;
+mod strength;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+#[cfg(feature = "zeroize")]
+pub use secret::SecretString;
+pub use strength::PasswordStrength;
 
 use crate::{
     config::get_configuration,
@@ -13,8 +19,9 @@ use crate::{
     formatter::StringFormatter,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::StrengthEstimator,
     ui::{Backend, PasswordBackend, RenderConfig},
-    validator::StringValidator,
+    validator::{LiveValidation, StringValidator},
 };
 
 use self::prompt::PasswordPrompt;
@@ -55,6 +62,9 @@ pub enum PasswordDisplayMode {
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
 ///   - No validators are on by default.
+/// - **Strength meter**: Live indicator of the strength of the password, displayed below the input when enabled with `with_strength_meter()`.
+///   - The strength is estimated by a built-in heuristic, or by a custom estimator set with `with_strength_estimator()`.
+///   - Only the strength level is displayed, even when the input is hidden.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
 ///
@@ -118,6 +128,16 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Options of live validation, in which validators run as the password is typed.
+    ///
+    /// Disabled by default. The confirmation input is not validated live.
+    pub live_validation: Option<LiveValidation>,
+
+    /// Function that estimates the strength of the input, displayed below it.
+    ///
+    /// When `None`, no strength meter is displayed.
+    pub strength_estimator: Option<StrengthEstimator<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -148,6 +168,10 @@ impl<'a> Password<'a> {
     /// Default password display mode.
     pub const DEFAULT_DISPLAY_MODE: PasswordDisplayMode = PasswordDisplayMode::Hidden;
 
+    /// Default strength estimator, set to the built-in [`PasswordStrength::estimate`] heuristic.
+    pub const DEFAULT_STRENGTH_ESTIMATOR: StrengthEstimator<'a> =
+        &|password| PasswordStrength::estimate(password);
+
     /// Creates a [Password] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -160,6 +184,8 @@ impl<'a> Password<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            live_validation: None,
+            strength_estimator: None,
             render_config: get_configuration(),
         }
     }
@@ -206,6 +232,19 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Enables the strength meter, estimating the strength of the input with
+    /// [`DEFAULT_STRENGTH_ESTIMATOR`](Self::DEFAULT_STRENGTH_ESTIMATOR).
+    pub fn with_strength_meter(mut self) -> Self {
+        self.strength_estimator = Some(Self::DEFAULT_STRENGTH_ESTIMATOR);
+        self
+    }
+
+    /// Enables the strength meter, estimating the strength of the input with the given function.
+    pub fn with_strength_estimator(mut self, estimator: StrengthEstimator<'a>) -> Self {
+        self.strength_estimator = Some(estimator);
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as requiring
     /// special characters in the password.
@@ -236,6 +275,13 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Enables live validation of the password as it is typed, e.g. to flag missing
+    /// character classes before the user submits.
+    pub fn with_live_validation(mut self, live_validation: LiveValidation) -> Self {
+        self.live_validation = Some(live_validation);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -274,6 +320,18 @@ impl<'a> Password<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, returning the
+    /// password wrapped in a [`SecretString`].
+    ///
+    /// The prompt's own buffers are wiped when they are cleared or dropped, and
+    /// the returned value is wiped when dropped. Validators are called with a
+    /// borrowed view of the input buffer, so no other copies are made.
+    #[cfg(feature = "zeroize")]
+    pub fn prompt_secret(self) -> InquireResult<SecretString> {
+        self.prompt().map(SecretString::from)
+    }
+
     pub(crate) fn prompt_with_backend<B: PasswordBackend>(
         self,
         backend: &mut B,
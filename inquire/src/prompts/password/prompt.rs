@@ -1,10 +1,16 @@
+use std::time::Duration;
+
 use crate::{
     error::InquireResult,
     formatter::StringFormatter,
-    input::Input,
-    prompts::prompt::{ActionResult, Prompt},
+    input::{Input, InputActionResult},
+    prompts::{
+        live_validation::LiveValidator,
+        prompt::{ActionResult, Prompt},
+    },
+    type_aliases::StrengthEstimator,
     ui::PasswordBackend,
-    validator::{ErrorMessage, StringValidator, Validation},
+    validator::{validate_all, ErrorMessage, StringValidator, Validation},
     InquireError, Password, PasswordDisplayMode,
 };
 
@@ -32,7 +38,10 @@ pub struct PasswordPrompt<'a> {
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    strength_estimator: Option<StrengthEstimator<'a>>,
+    live_validator: Option<LiveValidator>,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
 }
 
 impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
@@ -57,8 +66,11 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             confirmation_stage: false,
             formatter: so.formatter,
             validators: so.validators,
+            strength_estimator: so.strength_estimator,
+            live_validator: so.live_validation.map(LiveValidator::new),
             input: Input::new(),
             error: None,
+            warning: None,
         }
     }
 }
@@ -95,7 +107,7 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn confirmation_step(&mut self) -> ConfirmationStepResult {
-        let cur_answer = self.cur_answer().to_owned();
+        let cur_answer = self.input.content();
         match &mut self.confirmation {
             None => ConfirmationStepResult::NoConfirmationRequired,
             Some(confirmation) => {
@@ -120,20 +132,41 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.cur_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
-            }
-        }
+        let answer = self.cur_answer();
 
-        Ok(Validation::Valid)
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
     }
 
     fn cur_answer(&self) -> &str {
         self.input.content()
     }
+
+    fn input_changed(&mut self) -> InquireResult<()> {
+        if self.confirmation_stage {
+            return Ok(());
+        }
+
+        self.warning = None;
+
+        if let Some(live) = &mut self.live_validator {
+            if live.input_changed() {
+                self.validate_live()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_live(&mut self) -> InquireResult<()> {
+        let validation = self.validate_current_answer()?;
+
+        if let Some(live) = &mut self.live_validator {
+            (self.error, self.warning) = live.update(validation);
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, Backend> Prompt<Backend> for PasswordPrompt<'a>
@@ -168,25 +201,50 @@ where
         Ok(true)
     }
 
+    fn disable_ticks(&mut self) {
+        if let Some(live) = &mut self.live_validator {
+            live.disable_debounce();
+        }
+    }
+
+    fn tick_timeout(&self) -> Option<Duration> {
+        self.live_validator
+            .as_ref()
+            .and_then(LiveValidator::timeout)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        self.validate_live()?;
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
-        if let Validation::Invalid(msg) = self.validate_current_answer()? {
-            self.error = Some(msg);
-            if self.config.display_mode == PasswordDisplayMode::Hidden {
-                self.input.clear();
+        let validation = self.validate_current_answer()?;
+
+        if let Some(live) = &mut self.live_validator {
+            live.submit(&validation);
+        }
+
+        if !validation.accepts(self.warning.as_ref()) {
+            if let Validation::Invalid(_) = validation {
+                if self.config.display_mode == PasswordDisplayMode::Hidden {
+                    self.input.clear();
+                }
             }
+
+            (self.error, self.warning) = validation.into_messages();
             return Ok(None);
         }
 
         let confirmation = self.confirmation_step();
 
-        let cur_answer = self.cur_answer().to_owned();
-
         let result = match confirmation {
             ConfirmationStepResult::NoConfirmationRequired
-            | ConfirmationStepResult::ConfirmationValidated => Some(cur_answer),
+            | ConfirmationStepResult::ConfirmationValidated => Some(self.input.take_content()),
             ConfirmationStepResult::ConfirmationPending => None,
             ConfirmationStepResult::ConfirmationInvalidated(message) => {
                 self.error = Some(message);
+                self.warning = None;
                 self.input.clear();
                 None
             }
@@ -198,7 +256,13 @@ where
     fn handle(&mut self, action: PasswordPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             PasswordPromptAction::ValueInput(input_action) => {
-                self.active_input_mut().handle(input_action).into()
+                let result = self.active_input_mut().handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.input_changed()?;
+                }
+
+                result.into()
             }
             PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
         };
@@ -209,6 +273,12 @@ where
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if !self.confirmation_stage {
+            if let Some(warning) = &self.warning {
+                backend.render_warning_message(warning)?;
+            } else if matches!(&self.live_validator, Some(live) if live.shows_valid()) {
+                backend.render_valid_input_indicator()?;
+            }
         }
 
         match self.current_mode {
@@ -250,6 +320,12 @@ where
             }
         }
 
+        if let Some(estimator) = self.strength_estimator {
+            if !self.confirmation_stage {
+                backend.render_password_strength(estimator(self.input.content()))?;
+            }
+        }
+
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
         }
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// Password returned by [`Password::prompt_secret`](crate::Password::prompt_secret).
+///
+/// The underlying buffer is wiped when the value is dropped, and its `Debug`
+/// implementation does not print the password, so it can not leak through logs.
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Borrows the password.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
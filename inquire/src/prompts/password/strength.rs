@@ -0,0 +1,108 @@
+use std::fmt;
+
+use crate::validator::CharacterClasses;
+
+/// Strength of a password, displayed by the strength meter of [`Password`](crate::Password)
+/// prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    /// Trivially guessable, e.g. short or single-class passwords.
+    VeryWeak,
+    /// Weak.
+    Weak,
+    /// Medium.
+    Medium,
+    /// Strong.
+    Strong,
+    /// Very strong.
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// Number of strength levels.
+    pub const LEVELS: usize = 5;
+
+    /// Built-in estimator, based on the entropy of a random password with the same
+    /// length and character classes.
+    ///
+    /// Repeated consecutive characters only count once towards the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::PasswordStrength;
+    ///
+    /// assert_eq!(PasswordStrength::VeryWeak, PasswordStrength::estimate("aaaaaaaa"));
+    /// assert_eq!(PasswordStrength::Weak, PasswordStrength::estimate("hunter"));
+    /// assert_eq!(PasswordStrength::Medium, PasswordStrength::estimate("hunter2024"));
+    /// assert_eq!(PasswordStrength::Strong, PasswordStrength::estimate("Tr0ub4dor&3"));
+    /// assert_eq!(PasswordStrength::VeryStrong, PasswordStrength::estimate("Tr0ub4dor&3-horse"));
+    /// ```
+    pub fn estimate(password: &str) -> Self {
+        Self::from_entropy(entropy_bits(password))
+    }
+
+    /// Strength of a password with the given entropy, in bits.
+    pub fn from_entropy(bits: f64) -> Self {
+        if bits < 28.0 {
+            Self::VeryWeak
+        } else if bits < 36.0 {
+            Self::Weak
+        } else if bits < 60.0 {
+            Self::Medium
+        } else if bits < 100.0 {
+            Self::Strong
+        } else {
+            Self::VeryStrong
+        }
+    }
+
+    /// Level of the strength, from 1 for [`VeryWeak`](Self::VeryWeak) to
+    /// [`LEVELS`](Self::LEVELS) for [`VeryStrong`](Self::VeryStrong).
+    pub fn level(self) -> usize {
+        self as usize + 1
+    }
+}
+
+impl fmt::Display for PasswordStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::VeryWeak => "very weak",
+            Self::Weak => "weak",
+            Self::Medium => "medium",
+            Self::Strong => "strong",
+            Self::VeryStrong => "very strong",
+        };
+
+        f.write_str(label)
+    }
+}
+
+fn entropy_bits(password: &str) -> f64 {
+    let classes = CharacterClasses::of(password);
+    let pool_size: u32 = [
+        (CharacterClasses::LOWERCASE, 26),
+        (CharacterClasses::UPPERCASE, 26),
+        (CharacterClasses::DIGIT, 10),
+        (CharacterClasses::SYMBOL, 33),
+    ]
+    .iter()
+    .filter(|(class, _)| classes.contains(*class))
+    .map(|(_, size)| size)
+    .sum();
+
+    if pool_size == 0 {
+        return 0.0;
+    }
+
+    let mut length = 0;
+    let mut previous = None;
+    for c in password.chars() {
+        if previous != Some(c) {
+            length += 1;
+        }
+        previous = Some(c);
+    }
+
+    f64::from(length) * f64::from(pool_size).log2()
+}
@@ -254,3 +254,144 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn secret_string_debug_is_redacted() {
+    let secret = super::SecretString::from(String::from("hunter2"));
+
+    assert_eq!("hunter2", secret.expose_secret());
+    assert_eq!("SecretString([REDACTED])", format!("{:?}", secret));
+}
+
+mod strength_meter {
+    use crate::{
+        ui::{
+            test::{FakeBackend, Token},
+            Key, KeyModifiers,
+        },
+        Password, PasswordStrength,
+    };
+
+    fn strengths(backend: &FakeBackend) -> Vec<Option<PasswordStrength>> {
+        backend
+            .frames()
+            .iter()
+            .map(|frame| {
+                frame.tokens().iter().find_map(|token| match token {
+                    Token::PasswordStrength(strength) => Some(*strength),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hidden_input_only_renders_strength_level() {
+        let mut keys: Vec<Key> = text_to_events!("Tr0ub4dor&3");
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+
+        let ans = Password::new("Password:")
+            .without_confirmation()
+            .with_strength_meter()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("Tr0ub4dor&3", ans);
+
+        let strengths = strengths(&backend);
+        assert_eq!(Some(PasswordStrength::VeryWeak), strengths[0]);
+        assert_eq!(Some(PasswordStrength::Strong), strengths[11]);
+
+        for frame in backend.frames() {
+            assert!(frame
+                .tokens()
+                .iter()
+                .all(|token| !matches!(token, Token::Input(_))));
+        }
+    }
+
+    #[test]
+    fn custom_estimator_is_used() {
+        let mut keys: Vec<Key> = text_to_events!("abc");
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+
+        Password::new("Password:")
+            .without_confirmation()
+            .with_strength_estimator(&|password| match password.len() {
+                0..=2 => PasswordStrength::Weak,
+                _ => PasswordStrength::VeryStrong,
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        let strengths = strengths(&backend);
+        assert_eq!(Some(PasswordStrength::Weak), strengths[2]);
+        assert_eq!(Some(PasswordStrength::VeryStrong), strengths[3]);
+    }
+
+    #[test]
+    fn meter_is_hidden_while_confirming() {
+        let mut keys: Vec<Key> = text_to_events!("abc\nabc");
+        keys.push(Key::Char('\n', KeyModifiers::NONE));
+        let mut backend = FakeBackend::new(keys);
+
+        Password::new("Password:")
+            .with_strength_meter()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        let strengths = strengths(&backend);
+        assert_eq!(Some(PasswordStrength::VeryWeak), strengths[3]);
+        assert_eq!(None, strengths[4]);
+    }
+
+    #[test]
+    fn no_meter_by_default() {
+        let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+        Password::new("Password:")
+            .without_confirmation()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(strengths(&backend).iter().all(Option::is_none));
+    }
+}
+
+#[test]
+fn live_validation_is_displayed_while_typing_the_password() {
+    use crate::ui::test::{FakeBackend, Token};
+    use crate::validator::LiveValidation;
+
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Password::new("")
+        .with_validator(|ans: &str| match ans.len() {
+            len if len >= 3 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Too short".into())),
+        })
+        .with_live_validation(LiveValidation::new())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!("abc", ans);
+
+    let frames = backend.frames();
+    let too_short = Token::ErrorMessage(ErrorMessage::Custom("Too short".into()));
+    assert!(frames[1].has_token(&too_short));
+    assert!(frames[3].has_token(&Token::ValidInputIndicator));
+    // The confirmation input is not validated live.
+    assert!(!frames[5].has_token(&too_short));
+    assert!(!frames[5].has_token(&Token::ValidInputIndicator));
+}
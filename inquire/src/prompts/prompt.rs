@@ -1,5 +1,7 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
+use std::time::{Duration, Instant};
+
 use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
 
 use super::action::{Action, InnerAction};
@@ -14,11 +16,16 @@ pub enum ActionResult {
     /// The action either didn't result in a state change or the state
     /// change does not require a redraw.
     Clean,
+
+    /// The action completed the answer, which is submitted right away as if
+    /// the user had pressed enter.
+    Submit,
 }
 
 impl ActionResult {
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
+            (Self::Submit, _) | (_, Self::Submit) => Self::Submit,
             (Self::NeedsRedraw, _) | (_, Self::NeedsRedraw) => Self::NeedsRedraw,
             (Self::Clean, Self::Clean) => Self::Clean,
         }
@@ -104,6 +111,32 @@ where
     /// to the prompt, which will then be submitted to this method just the same.
     fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
 
+    /// Returns how long the prompt waits for a key before calling
+    /// [`tick`](Self::tick), when it has pending work that does not depend on
+    /// user input, such as a debounced validation or a spinner to animate.
+    ///
+    /// Returns `None` by default, waiting for keys indefinitely.
+    fn tick_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Hook called before the first draw when the backend can not wait for a key
+    /// for a limited amount of time, in which case [`tick`](Self::tick) is only
+    /// called once the next key is pressed.
+    ///
+    /// Prompts should then run the work they defer to ticks right away, e.g. validate
+    /// the input on every change instead of debouncing it.
+    fn disable_ticks(&mut self) {}
+
+    /// Hook called when no key was pressed during the [`tick_timeout`](Self::tick_timeout).
+    ///
+    /// Returns whether the prompt needs to be re-rendered, or `Submit` when work
+    /// completed in the background, such as a background validation, and the
+    /// answer should be submitted again.
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
     /// Hook called for the rendering of the prompt UI.
     ///
     /// The implementation should **not** call neither `frame_setup` or
@@ -116,6 +149,9 @@ where
     /// This should not be reimplemented by types that implement this trait,
     /// unless the situation really warrants it.
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
+        if !backend.supports_timeout() {
+            self.disable_ticks();
+        }
         self.setup()?;
 
         let mut last_handle = ActionResult::NeedsRedraw;
@@ -127,17 +163,37 @@ where
                 last_handle = ActionResult::Clean;
             }
 
-            let key = backend.read_key()?;
+            let key = match self.tick_timeout() {
+                Some(timeout) => {
+                    let started_at = Instant::now();
+                    let key = backend.read_key_timeout(timeout)?;
+
+                    // Readers that can not wait for a limited time return once a key
+                    // is pressed, possibly long after the timeout.
+                    if key.is_none() || started_at.elapsed() >= timeout {
+                        // Handled before the key, which might change the answer.
+                        last_handle = match self.tick()? {
+                            ActionResult::Submit => match self.submit()? {
+                                Some(answer) => break answer,
+                                None => ActionResult::NeedsRedraw,
+                            },
+                            result => result,
+                        };
+                    }
+
+                    key
+                }
+                None => Some(backend.read_key()?),
+            };
+
+            let Some(key) = key else {
+                continue;
+            };
             let action = Action::from_key(key, self.config());
 
             if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
-                    }
+                let result = match action {
+                    Action::Submit => ActionResult::Submit,
                     Action::Cancel => {
                         let pre_cancel_result = self.pre_cancel()?;
 
@@ -153,6 +209,17 @@ where
                     Action::Interrupt => return Err(InquireError::OperationInterrupted),
                     Action::Inner(inner_action) => self.handle(inner_action)?,
                 };
+
+                let result = match result {
+                    ActionResult::Submit => {
+                        if let Some(answer) = self.submit()? {
+                            break answer;
+                        }
+                        ActionResult::NeedsRedraw
+                    }
+                    result => result,
+                };
+                last_handle = last_handle.merge(result);
             }
         };
 
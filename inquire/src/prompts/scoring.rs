@@ -0,0 +1,253 @@
+//! Filtering of list options shared by the prompts that display them.
+
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Minimum amount of options handed to each rayon job when scoring in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_LEN: usize = 1024;
+
+/// Scores list options against the filter input and caches the results per query.
+///
+/// With narrowing enabled, the scorer is known to never match an option for an
+/// input if it did not match it for a prefix of that input. This allows the cache
+/// to only rescore the options that matched the longest cached prefix of the new
+/// query when the user keeps typing, instead of every option of the list.
+///
+/// Only the queries that are prefixes of the current one are kept, which covers
+/// the user deleting characters while keeping memory usage bounded.
+pub(crate) struct ScoreCache {
+    results: HashMap<String, Vec<usize>>,
+    narrowing: bool,
+}
+
+impl ScoreCache {
+    pub fn new(narrowing: bool) -> Self {
+        Self {
+            results: HashMap::new(),
+            narrowing,
+        }
+    }
+
+    /// Returns the indexes of the options matched by `query`, sorted by
+    /// descending score. `scorer` receives the index of the option to score.
+    pub fn score<F>(&mut self, query: &str, len: usize, scorer: F) -> &[usize]
+    where
+        F: Fn(usize) -> Option<i64>,
+    {
+        self.score_with(query, len, |candidates| {
+            candidates
+                .iter()
+                .filter_map(|&idx| scorer(idx).map(|score| (idx, score)))
+                .collect()
+        })
+    }
+
+    /// Same as [`score`](Self::score), but splits the candidates in jobs
+    /// ran by the rayon thread pool.
+    #[cfg(feature = "parallel")]
+    pub fn score_parallel<F>(&mut self, query: &str, len: usize, scorer: F) -> &[usize]
+    where
+        F: Fn(usize) -> Option<i64> + Sync,
+    {
+        use rayon::prelude::*;
+
+        self.score_with(query, len, |candidates| {
+            candidates
+                .par_iter()
+                .with_min_len(PARALLEL_MIN_LEN)
+                .filter_map(|&idx| scorer(idx).map(|score| (idx, score)))
+                .collect()
+        })
+    }
+
+    fn score_with<R>(&mut self, query: &str, len: usize, run: R) -> &[usize]
+    where
+        R: FnOnce(&[usize]) -> Vec<(usize, i64)>,
+    {
+        self.results
+            .retain(|cached_query, _| query.starts_with(cached_query.as_str()));
+
+        if self.results.contains_key(query) {
+            return &self.results[query];
+        }
+
+        let longest_prefix = match self.narrowing {
+            true => self
+                .results
+                .iter()
+                .max_by_key(|(cached_query, _)| cached_query.len()),
+            false => None,
+        };
+
+        let mut scores = match longest_prefix {
+            Some((_, matches)) => run(matches),
+            None => run(&(0..len).collect::<Vec<usize>>()),
+        };
+
+        // ties are broken by the original index so that the order does not
+        // depend on which subset of options was scored.
+        scores.sort_unstable_by_key(|&(idx, score)| (Reverse(score), idx));
+
+        let matches = scores.into_iter().map(|(idx, _)| idx).collect();
+
+        self.results.entry(query.to_owned()).or_insert(matches)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::ScoreCache;
+
+    const OPTIONS: &[&str] = &[
+        "New York",
+        "Los Angeles",
+        "San Antonio",
+        "San Diego",
+        "Dallas",
+        "San Francisco",
+        "Austin",
+        "San Jose",
+    ];
+
+    fn contains(query: &str, idx: usize) -> Option<i64> {
+        let value = OPTIONS[idx].to_lowercase();
+        value.find(&query.to_lowercase()).map(|pos| -(pos as i64))
+    }
+
+    #[test]
+    fn empty_query_scores_every_option() {
+        let mut cache = ScoreCache::new(true);
+        let calls = Cell::new(0);
+
+        let result = cache.score("", OPTIONS.len(), |idx| {
+            calls.set(calls.get() + 1);
+            contains("", idx)
+        });
+
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7], result);
+        assert_eq!(OPTIONS.len(), calls.get());
+    }
+
+    #[test]
+    fn results_are_sorted_by_score_then_index() {
+        let mut cache = ScoreCache::new(true);
+
+        let result = cache.score("an", OPTIONS.len(), |idx| contains("an", idx));
+
+        assert_eq!(&[2, 3, 5, 7, 1], result);
+    }
+
+    #[test]
+    fn extended_query_only_rescores_previous_matches() {
+        let mut cache = ScoreCache::new(true);
+        cache.score("sa", OPTIONS.len(), |idx| contains("sa", idx));
+
+        let calls = Cell::new(0);
+        let result = cache.score("san", OPTIONS.len(), |idx| {
+            calls.set(calls.get() + 1);
+            contains("san", idx)
+        });
+
+        assert_eq!(&[2, 3, 5, 7], result);
+        assert_eq!(4, calls.get());
+    }
+
+    #[test]
+    fn cached_queries_are_not_rescored() {
+        let mut cache = ScoreCache::new(true);
+        cache.score("s", OPTIONS.len(), |idx| contains("s", idx));
+        cache.score("sa", OPTIONS.len(), |idx| contains("sa", idx));
+        cache.score("san", OPTIONS.len(), |idx| contains("san", idx));
+
+        let result = cache.score("sa", OPTIONS.len(), |_| panic!("should be cached"));
+        assert_eq!(&[2, 3, 5, 7], result);
+
+        let result = cache.score("s", OPTIONS.len(), |_| panic!("should be cached"));
+        assert_eq!(&[2, 3, 5, 7, 1, 6, 4], result);
+    }
+
+    #[test]
+    fn diverging_query_is_scored_from_common_prefix() {
+        let mut cache = ScoreCache::new(true);
+        cache.score("sa", OPTIONS.len(), |idx| contains("sa", idx));
+        cache.score("san", OPTIONS.len(), |idx| contains("san", idx));
+
+        let calls = Cell::new(0);
+        let result = cache.score("sal", OPTIONS.len(), |idx| {
+            calls.set(calls.get() + 1);
+            contains("sal", idx)
+        });
+
+        assert!(result.is_empty());
+        assert_eq!(4, calls.get());
+    }
+
+    #[test]
+    fn unrelated_query_scores_every_option() {
+        let mut cache = ScoreCache::new(true);
+        cache.score("sa", OPTIONS.len(), |idx| contains("sa", idx));
+
+        let calls = Cell::new(0);
+        let result = cache.score("ew", OPTIONS.len(), |idx| {
+            calls.set(calls.get() + 1);
+            contains("ew", idx)
+        });
+
+        assert_eq!(&[0], result);
+        assert_eq!(OPTIONS.len(), calls.get());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_scoring_matches_sequential_scoring() {
+        let options = (0..10_000)
+            .map(|i| format!("option {}", i))
+            .collect::<Vec<String>>();
+        let scorer = |query: &str, idx: usize| options[idx].find(query).map(|pos| pos as i64);
+
+        let mut sequential = ScoreCache::new(true);
+        let mut parallel = ScoreCache::new(true);
+
+        for query in ["", "1", "12", "123", "12", "7"] {
+            let expected = sequential
+                .score(query, options.len(), |idx| scorer(query, idx))
+                .to_vec();
+            let actual = parallel.score_parallel(query, options.len(), |idx| scorer(query, idx));
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    fn acronym(query: &str, idx: usize) -> Option<i64> {
+        let initials = OPTIONS[idx]
+            .split(' ')
+            .filter_map(|word| word.chars().next())
+            .collect::<String>()
+            .to_lowercase();
+
+        match initials == query {
+            true => Some(0),
+            false => None,
+        }
+    }
+
+    #[test]
+    fn extended_query_rescores_every_option_without_narrowing() {
+        let mut cache = ScoreCache::new(false);
+        cache.score("s", OPTIONS.len(), |idx| acronym("s", idx));
+
+        let calls = Cell::new(0);
+        let result = cache.score("sa", OPTIONS.len(), |idx| {
+            calls.set(calls.get() + 1);
+            acronym("sa", idx)
+        });
+
+        assert_eq!(&[2], result);
+        assert_eq!(OPTIONS.len(), calls.get());
+
+        let result = cache.score("s", OPTIONS.len(), |_| panic!("should be cached"));
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+/// Answer of a [`Select`](crate::Select) prompt configured with an "other" option,
+/// returned by [`SelectWithOther::prompt_with_other`](crate::SelectWithOther::prompt_with_other).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectAnswer<T> {
+    /// The user selected one of the provided options.
+    Chosen(T),
+    /// The user selected the "other" option and typed their own answer.
+    Custom(String),
+}
@@ -1,11 +1,15 @@
 mod action;
+mod answer;
 mod config;
+mod other;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use answer::*;
+pub use other::*;
 use std::fmt::Display;
 
 use crate::{
@@ -19,6 +23,9 @@ use crate::{
     ui::{Backend, RenderConfig, SelectBackend},
 };
 
+#[cfg(feature = "parallel")]
+use crate::type_aliases::ParallelScorer;
+
 use self::prompt::SelectPrompt;
 
 #[cfg(feature = "fuzzy")]
@@ -50,6 +57,7 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Other option**: Synthetic option appended to the list that lets the user type their own answer, validated by its own set of validators. Set with [`with_other_option`](Select::with_other_option), which returns a [`SelectWithOther`] prompt.
 ///
 /// # Example
 ///
@@ -106,6 +114,18 @@ pub struct Select<'a, T> {
     /// options.
     pub scorer: Scorer<'a, T>,
 
+    /// Function called with the current user input to score the provided
+    /// options in parallel. When set, it is used instead of `scorer`.
+    #[cfg(feature = "parallel")]
+    pub parallel_scorer: Option<ParallelScorer<'a>>,
+
+    /// Whether the scorer never matches an option for an input when it did not match
+    /// it for a prefix of that input, e.g. fuzzy or substring matching. When set, only
+    /// the options that matched the previous input are scored again as the user types.
+    ///
+    /// True for the default scorer, and reset to false when setting another one.
+    pub monotone_scorer: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
@@ -178,6 +198,35 @@ where
             }
         };
 
+    /// Default parallel scoring function, equivalent to [`DEFAULT_SCORER`](Self::DEFAULT_SCORER)
+    /// and meant to be set with [`with_parallel_scorer`](Self::with_parallel_scorer).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let scorer = Select::<&str>::DEFAULT_PARALLEL_SCORER;
+    /// assert_eq!(None,     scorer("sa", "New York",   0));
+    /// assert_eq!(Some(49), scorer("sa", "Sacramento", 1));
+    /// assert_eq!(Some(35), scorer("sa", "Kansas",     2));
+    /// ```
+    #[cfg(all(feature = "parallel", feature = "fuzzy"))]
+    pub const DEFAULT_PARALLEL_SCORER: ParallelScorer<'a> =
+        &|input, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(all(feature = "parallel", not(feature = "fuzzy")))]
+    pub const DEFAULT_PARALLEL_SCORER: ParallelScorer<'a> =
+        &|input, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
@@ -211,6 +260,9 @@ where
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             scorer: Self::DEFAULT_SCORER,
+            #[cfg(feature = "parallel")]
+            parallel_scorer: None,
+            monotone_scorer: true,
             formatter: Self::DEFAULT_FORMATTER,
             render_config: get_configuration(),
             starting_filter_input: None,
@@ -242,8 +294,34 @@ where
     }
 
     /// Sets the scoring function.
+    ///
+    /// Every option is scored again on each change of the filter input, unless the
+    /// scorer is declared monotone with [`with_monotone_scorer`](Self::with_monotone_scorer).
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
+        self.monotone_scorer = false;
+        self
+    }
+
+    /// Sets the scoring function used to filter the options in parallel.
+    ///
+    /// Worth it for lists with several thousands of options, this scorer takes
+    /// precedence over the one set by [`with_scorer`](Self::with_scorer).
+    #[cfg(feature = "parallel")]
+    pub fn with_parallel_scorer(mut self, scorer: ParallelScorer<'a>) -> Self {
+        self.parallel_scorer = Some(scorer);
+        self.monotone_scorer = false;
+        self
+    }
+
+    /// Sets whether the scorer never matches an option for an input when it did not
+    /// match it for a prefix of that input, as fuzzy or substring matching, in which
+    /// case only the options that matched the previous input are scored again as the
+    /// user types.
+    ///
+    /// Setting a scorer resets it to false, so this should be called afterwards.
+    pub fn with_monotone_scorer(mut self, monotone_scorer: bool) -> Self {
+        self.monotone_scorer = monotone_scorer;
         self
     }
 
@@ -253,6 +331,18 @@ where
         self
     }
 
+    /// Appends a synthetic option with the given label to the list. When chosen, the
+    /// filter input turns into a text input where the user can type their own answer.
+    ///
+    /// Pressing ESC while typing the answer goes back to the list of options.
+    ///
+    /// Returns a [`SelectWithOther`] prompt, run with
+    /// [`prompt_with_other`](SelectWithOther::prompt_with_other) so the typed answer can be
+    /// returned. The other options of the prompt are expected to be set beforehand.
+    pub fn with_other_option(self, label: &'a str) -> SelectWithOther<'a, T> {
+        SelectWithOther::new(self, label)
+    }
+
     /// Sets the starting cursor index.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
@@ -340,6 +430,9 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        match SelectPrompt::new(self, None)?.prompt(backend)? {
+            SelectAnswer::Chosen(answer) => Ok(answer),
+            SelectAnswer::Custom(_) => unreachable!("no other option was set"),
+        }
     }
 }
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, SelectBackend},
+    validator::StringValidator,
+    Select,
+};
+
+use super::{answer::SelectAnswer, prompt::SelectPrompt};
+
+/// [`Select`] prompt with a synthetic option appended to the list, which lets the user
+/// type their own answer instead of choosing one of the options.
+///
+/// Created with [`Select::with_other_option`] and run with
+/// [`prompt_with_other`](Self::prompt_with_other), which returns either the chosen
+/// option or the typed answer.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{validator::ValueRequiredValidator, Select, SelectAnswer};
+///
+/// let options = vec!["Banana", "Apple", "Strawberry", "Grapes", "Lemon"];
+///
+/// let ans = Select::new("What's your favorite fruit?", options)
+///     .with_other_option("Other...")
+///     .with_other_validator(ValueRequiredValidator::default())
+///     .prompt_with_other();
+///
+/// match ans {
+///     Ok(SelectAnswer::Chosen(choice)) => println!("{choice}! That's mine too!"),
+///     Ok(SelectAnswer::Custom(fruit)) => println!("{fruit}? I should try it some day."),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SelectWithOther<'a, T> {
+    /// Underlying select prompt, displaying the options.
+    pub select: Select<'a, T>,
+
+    /// Label of the synthetic option appended to the list.
+    pub label: &'a str,
+
+    /// Collection of validators to apply to the answer typed after choosing
+    /// the other option.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    pub validators: Vec<Box<dyn StringValidator>>,
+}
+
+impl<'a, T> SelectWithOther<'a, T>
+where
+    T: Display,
+{
+    /// Creates a [`SelectWithOther`] appending an option with the given label to the
+    /// options of the select prompt.
+    pub fn new(select: Select<'a, T>, label: &'a str) -> Self {
+        Self {
+            select,
+            label,
+            validators: Vec::new(),
+        }
+    }
+
+    /// Adds a validator to the collection of validators applied to the answer typed
+    /// after choosing the other option.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_other_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators applied to the answer typed
+    /// after choosing the other option, in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_other_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns [`SelectAnswer::Chosen`] with the owned object selected by the user,
+    /// or [`SelectAnswer::Custom`] with the answer they typed after choosing the
+    /// other option.
+    pub fn prompt_with_other(self) -> InquireResult<SelectAnswer<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.select.render_config)?;
+        self.prompt_with_other_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type of [`prompt_with_other`](Self::prompt_with_other) with `Some`.
+    pub fn prompt_with_other_skippable(self) -> InquireResult<Option<SelectAnswer<T>>> {
+        match self.prompt_with_other() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(crate) fn prompt_with_other_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<SelectAnswer<T>> {
+        let other = Some((self.label, self.validators));
+
+        let answer = match SelectPrompt::new(self.select, other)?.prompt(backend)? {
+            SelectAnswer::Chosen(answer) => SelectAnswer::Chosen(answer.value),
+            SelectAnswer::Custom(answer) => SelectAnswer::Custom(answer),
+        };
+
+        Ok(answer)
+    }
+}
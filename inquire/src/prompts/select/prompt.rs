@@ -1,18 +1,44 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::fmt::{self, Display};
 
 use crate::{
     error::InquireResult,
     formatter::OptionFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        scoring::ScoreCache,
+    },
     type_aliases::Scorer,
     ui::SelectBackend,
     utils::paginate,
+    validator::{validate_all, ErrorMessage, StringValidator, Validation},
     InquireError, Select,
 };
 
-use super::{action::SelectPromptAction, config::SelectConfig};
+#[cfg(feature = "parallel")]
+use crate::type_aliases::ParallelScorer;
+
+use super::{action::SelectPromptAction, answer::SelectAnswer, config::SelectConfig};
+
+/// Entry of the list rendered to the user, either one of the options or the
+/// synthetic other option.
+enum SelectEntry<'a, T> {
+    Option(&'a T),
+    Other(&'a str),
+}
+
+impl<'a, T> Display for SelectEntry<'a, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Option(option) => option.fmt(f),
+            Self::Other(label) => label.fmt(f),
+        }
+    }
+}
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
@@ -24,14 +50,29 @@ pub struct SelectPrompt<'a, T> {
     cursor_index: usize,
     input: Option<Input>,
     scorer: Scorer<'a, T>,
+    #[cfg(feature = "parallel")]
+    parallel_scorer: Option<ParallelScorer<'a>>,
+    score_cache: ScoreCache,
     formatter: OptionFormatter<'a, T>,
+    other_option: Option<&'a str>,
+    other_validators: Vec<Box<dyn StringValidator>>,
+    filter_input_enabled: bool,
+    vim_mode: bool,
+    writing_other: bool,
+    error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
 where
     T: Display,
 {
-    pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
+    /// Creates the prompt of the select options, with the label and the validators
+    /// of the other option, if any.
+    pub fn new(
+        so: Select<'a, T>,
+        other: Option<(&'a str, Vec<Box<dyn StringValidator>>)>,
+    ) -> InquireResult<Self> {
         if so.options.is_empty() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
@@ -49,6 +90,11 @@ where
         let string_options = so.options.iter().map(T::to_string).collect();
         let scored_options = (0..so.options.len()).collect();
 
+        let (other_option, other_validators) = match other {
+            Some((label, validators)) => (Some(label), validators),
+            None => (None, vec![]),
+        };
+
         let input = match so.filter_input_enabled {
             true => Some(Input::new_with(
                 so.starting_filter_input.unwrap_or_default(),
@@ -66,7 +112,17 @@ where
             cursor_index: so.starting_cursor,
             input,
             scorer: so.scorer,
+            #[cfg(feature = "parallel")]
+            parallel_scorer: so.parallel_scorer,
+            score_cache: ScoreCache::new(so.monotone_scorer),
             formatter: so.formatter,
+            other_option,
+            other_validators,
+            filter_input_enabled: so.filter_input_enabled,
+            vim_mode: so.vim_mode,
+            writing_other: false,
+            error: None,
+            warning: None,
         })
     }
 
@@ -75,7 +131,7 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.entries_len().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -85,14 +141,15 @@ where
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
+        let entries_len = self.entries_len();
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        if new_position >= entries_len {
+            new_position = if entries_len == 0 {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % entries_len
             } else {
-                self.scored_options.len().saturating_sub(1)
+                entries_len.saturating_sub(1)
             }
         }
 
@@ -108,10 +165,69 @@ where
         }
     }
 
+    /// Amount of entries displayed in the list, including the other option.
+    fn entries_len(&self) -> usize {
+        match self.other_option {
+            Some(_) => self.scored_options.len() + 1,
+            None => self.scored_options.len(),
+        }
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.scored_options.get(self.cursor_index).is_some()
     }
 
+    fn has_other_option_highlighted(&self) -> bool {
+        self.other_option.is_some() && self.cursor_index == self.scored_options.len()
+    }
+
+    /// Turns the filter input into the input of the answer typed by the user,
+    /// keeping what was typed so far as its starting value.
+    fn start_writing_other(&mut self) {
+        self.writing_other = true;
+        // typed characters must reach the input, so the vim bindings are disabled.
+        self.config.vim_mode = false;
+
+        if self.input.is_none() {
+            self.input = Some(Input::new());
+        }
+    }
+
+    /// Goes back to the list of options, using the typed answer as the filter.
+    fn stop_writing_other(&mut self) {
+        self.writing_other = false;
+        self.config.vim_mode = self.vim_mode;
+        self.error = None;
+        self.warning = None;
+
+        if !self.filter_input_enabled {
+            self.input = None;
+        }
+
+        self.run_scorer();
+    }
+
+    fn validate_other_answer(&self, answer: &str) -> InquireResult<Validation> {
+        validate_all(self.other_validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
+    }
+
+    fn submit_other_answer(&mut self) -> InquireResult<Option<String>> {
+        let answer = self
+            .input
+            .as_ref()
+            .map(|input| input.content().to_owned())
+            .unwrap_or_default();
+
+        let validation = self.validate_other_answer(&answer)?;
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(answer));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
+    }
+
     fn get_final_answer(&mut self) -> ListOption<T> {
         // should only be called after current cursor index is validated
         // on has_answer_highlighted
@@ -128,30 +244,37 @@ where
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
-
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        let options = &self.options;
+        let string_options = &self.string_options;
+        let scorer = self.scorer;
+
+        #[cfg(feature = "parallel")]
+        let new_scored_options = match self.parallel_scorer {
+            Some(parallel_scorer) => self
+                .score_cache
+                .score_parallel(content, options.len(), |i| {
+                    parallel_scorer(content, &string_options[i], i)
+                }),
+            None => self.score_cache.score(content, options.len(), |i| {
+                scorer(content, &options[i], &string_options[i], i)
+            }),
+        };
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        #[cfg(not(feature = "parallel"))]
+        let new_scored_options = self.score_cache.score(content, options.len(), |i| {
+            scorer(content, &options[i], &string_options[i], i)
+        });
 
         if self.scored_options == new_scored_options {
             return;
         }
 
-        self.scored_options = new_scored_options;
+        self.scored_options = new_scored_options.to_vec();
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
-        } else if self.scored_options.len() <= self.cursor_index {
-            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        } else if self.entries_len() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.entries_len().saturating_sub(1));
         }
     }
 }
@@ -163,7 +286,7 @@ where
 {
     type Config = SelectConfig;
     type InnerAction = SelectPromptAction;
-    type Output = ListOption<T>;
+    type Output = SelectAnswer<ListOption<T>>;
 
     fn message(&self) -> &str {
         self.message
@@ -173,8 +296,11 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+    fn format_answer(&self, answer: &SelectAnswer<ListOption<T>>) -> String {
+        match answer {
+            SelectAnswer::Chosen(answer) => (self.formatter)(answer.as_ref()),
+            SelectAnswer::Custom(answer) => answer.clone(),
+        }
     }
 
     fn setup(&mut self) -> InquireResult<()> {
@@ -182,9 +308,27 @@ where
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.writing_other {
+            self.stop_writing_other();
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<SelectAnswer<ListOption<T>>>> {
+        if self.writing_other {
+            return Ok(self.submit_other_answer()?.map(SelectAnswer::Custom));
+        }
+
+        if self.has_other_option_highlighted() {
+            self.start_writing_other();
+            return Ok(None);
+        }
+
         let answer = match self.has_answer_highlighted() {
-            true => Some(self.get_final_answer()),
+            true => Some(SelectAnswer::Chosen(self.get_final_answer())),
             false => None,
         };
 
@@ -192,6 +336,23 @@ where
     }
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
+        if self.writing_other {
+            let result = match (action, self.input.as_mut()) {
+                (SelectPromptAction::FilterInput(input_action), Some(input)) => {
+                    let result = input.handle(input_action);
+
+                    if let InputActionResult::ContentChanged = result {
+                        self.warning = None;
+                    }
+
+                    result.into()
+                }
+                _ => ActionResult::Clean,
+            };
+
+            return Ok(result);
+        }
+
         let result = match action {
             SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
             SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
@@ -220,18 +381,34 @@ where
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        }
+
         backend.render_select_prompt(prompt, self.input.as_ref())?;
 
-        let choices = self
-            .scored_options
-            .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+        // While the answer to the "other" option is typed, the list is hidden.
+        if !self.writing_other {
+            let mut choices = self
+                .scored_options
+                .iter()
+                .cloned()
+                .map(|i| ListOption::new(i, SelectEntry::Option(self.options.get(i).unwrap())))
+                .collect::<Vec<ListOption<SelectEntry<'_, T>>>>();
+
+            if let Some(label) = self.other_option {
+                choices.push(ListOption::new(
+                    self.options.len(),
+                    SelectEntry::Other(label),
+                ));
+            }
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+            let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
 
-        backend.render_options(page)?;
+            backend.render_options(page)?;
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
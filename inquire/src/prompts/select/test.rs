@@ -2,8 +2,13 @@ use crate::{
     formatter::OptionFormatter,
     list_option::ListOption,
     test::fake_backend,
-    ui::{Key, KeyModifiers},
-    Select,
+    type_aliases::Scorer,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ValueRequiredValidator,
+    Select, SelectAnswer,
 };
 
 #[test]
@@ -142,3 +147,238 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn deleting_filter_characters_restores_previous_matches() {
+    let mut backend = fake_backend(vec![
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE), // only "San Jose" is displayed
+        Key::Backspace,
+        Key::Backspace,
+        Key::Backspace, // "sa" matches the three options again
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["San Jose", "Sacramento", "San Diego"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Sacramento"), ans);
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn parallel_scorer_is_used_when_set() {
+    let mut backend = fake_backend(vec![
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Char('7', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = (0..10_000).collect::<Vec<u32>>();
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|_, _, _, _| None)
+        .with_parallel_scorer(&|input, value, _| value.starts_with(input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(27, 27), ans);
+}
+
+#[test]
+fn other_option_returns_typed_answer() {
+    let mut input = vec![Key::Up(KeyModifiers::NONE), Key::Enter];
+    input.append(&mut Key::char_keys_from_str("Kiwi"));
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Custom(String::from("Kiwi")), ans);
+}
+
+#[test]
+fn help_message_is_displayed_while_writing_other_answer() {
+    let mut backend = FakeBackend::new(vec![Key::Up(KeyModifiers::NONE), Key::Enter, Key::Escape]);
+
+    let ans = Select::new("Question", vec!["Banana", "Apple"])
+        .with_help_message("Pick a fruit")
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+    let help = Token::HelpMessage(String::from("Pick a fruit"));
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&help));
+    assert!(frames[2].has_token(&help));
+    // The options are hidden while the answer is typed.
+    assert!(!frames[2]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::Options { .. })));
+}
+
+#[test]
+fn other_option_returns_chosen_option() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Chosen("Apple"), ans);
+}
+
+#[test]
+fn other_option_is_displayed_when_no_option_matches_filter() {
+    let mut input = Key::char_keys_from_str("Kiwi");
+    input.push(Key::Enter); // the other option is the only one displayed
+    input.push(Key::Enter); // the filter input is kept as the typed answer
+    let mut backend = fake_backend(input);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Custom(String::from("Kiwi")), ans);
+}
+
+#[test]
+fn other_option_answer_is_validated() {
+    let mut input = vec![Key::Up(KeyModifiers::NONE), Key::Enter, Key::Enter];
+    input.append(&mut Key::char_keys_from_str("Kiwi"));
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_other_option("Other...")
+        .with_other_validator(ValueRequiredValidator::default())
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Custom(String::from("Kiwi")), ans);
+}
+
+#[test]
+fn other_option_answer_does_not_run_scorer_or_vim_bindings() {
+    let mut input = vec![Key::Up(KeyModifiers::NONE), Key::Enter];
+    input.append(&mut Key::char_keys_from_str("jk"));
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_vim_mode(true)
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Custom(String::from("jk")), ans);
+}
+
+#[test]
+fn escape_while_writing_other_answer_goes_back_to_options() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Escape, // back to the list, filtered by 'a'
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Banana", "Apple", "Kiwi"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|input, _, value, _| value.to_lowercase().contains(input).then_some(0))
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Chosen("Apple"), ans);
+}
+
+#[test]
+fn other_option_without_filtering_uses_a_text_input() {
+    let mut input = vec![Key::Up(KeyModifiers::NONE), Key::Enter];
+    input.append(&mut Key::char_keys_from_str("Kiwi"));
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .without_filtering()
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Custom(String::from("Kiwi")), ans);
+}
+
+#[test]
+fn other_option_keeps_the_settings_of_the_select_prompt() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec!["Banana", "Apple"];
+
+    let ans = Select::new("Question", options)
+        .with_starting_cursor(1)
+        .with_other_option("Other...")
+        .prompt_with_other_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectAnswer::Chosen("Apple"), ans);
+}
+
+#[test]
+fn custom_scorers_rescore_every_option_when_the_filter_is_extended() {
+    let mut backend = fake_backend(vec![
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    // Matches the initials of the option, so "sa" matches options that "s" does not.
+    let acronym: Scorer<'_, &str> = &|input, _option, string_value, _idx| {
+        let initials = string_value
+            .split(' ')
+            .filter_map(|word| word.chars().next())
+            .collect::<String>()
+            .to_lowercase();
+
+        match initials == input {
+            true => Some(0),
+            false => None,
+        }
+    };
+
+    let ans = Select::new("Question", vec!["Salt Lake", "San Antonio"])
+        .with_scorer(acronym)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "San Antonio"), ans);
+}
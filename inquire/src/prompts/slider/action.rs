@@ -0,0 +1,35 @@
+use crate::{ui::Key, InnerAction};
+
+use super::config::SliderConfig;
+
+/// Set of actions for a SliderPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliderPromptAction {
+    /// Moves the focused handle one step towards the start of the bar.
+    MoveLeft,
+    /// Moves the focused handle one step towards the end of the bar.
+    MoveRight,
+    /// Moves the focused handle as far as possible towards the start of the bar.
+    MoveToStart,
+    /// Moves the focused handle as far as possible towards the end of the bar.
+    MoveToEnd,
+    /// Focuses the other handle of range sliders.
+    SwitchHandle,
+}
+
+impl InnerAction for SliderPromptAction {
+    type Config = SliderConfig;
+
+    fn from_key(key: Key, config: &SliderConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(_) => Self::MoveLeft,
+            Key::Right(_) => Self::MoveRight,
+            Key::Home => Self::MoveToStart,
+            Key::End => Self::MoveToEnd,
+            Key::Tab if config.range => Self::SwitchHandle,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
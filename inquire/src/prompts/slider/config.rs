@@ -0,0 +1,20 @@
+use crate::{RangeSlider, Slider};
+
+/// Configuration settings used in the execution of a SliderPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct SliderConfig {
+    /// Whether the slider has two handles, switched with tab.
+    pub range: bool,
+}
+
+impl<T> From<&Slider<'_, T>> for SliderConfig {
+    fn from(_value: &Slider<'_, T>) -> Self {
+        Self { range: false }
+    }
+}
+
+impl<T> From<&RangeSlider<'_, T>> for SliderConfig {
+    fn from(_value: &RangeSlider<'_, T>) -> Self {
+        Self { range: true }
+    }
+}
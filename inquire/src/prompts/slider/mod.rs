@@ -0,0 +1,207 @@
+mod action;
+mod config;
+mod prompt;
+mod range;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use range::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SliderBackend},
+    Numeric,
+};
+
+use self::prompt::SliderPrompt;
+
+/// Prompt to pick a number between two bounds by moving the handle of a horizontal bar.
+///
+/// The bar fills the width of the terminal, up to a limit, and the current value is displayed
+/// next to it. The Left and Right arrows move the handle by the [`step`](Self::step), and Home
+/// and End move it to the bounds.
+///
+/// Tick labels can be displayed under the bar at given values with [`with_tick`](Self::with_tick),
+/// and a unit, such as "%", is displayed after the value. To pick a range of values with two
+/// handles, see [`RangeSlider`].
+///
+/// The bar is styled by the [`slider`](crate::ui::RenderConfig::slider) section of the render config.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Slider;
+///
+/// let replicas = Slider::<u8>::new("How many replicas?", 1, 20)
+///     .with_starting_value(3)
+///     .with_tick(1, "1")
+///     .with_tick(10, "10")
+///     .with_tick(20, "20")
+///     .prompt();
+///
+/// match replicas {
+///     Ok(replicas) => println!("Scaling to {} replicas", replicas),
+///     Err(_) => println!("An error happened, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Slider<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Value at the start of the bar.
+    pub min: T,
+
+    /// Value at the end of the bar.
+    pub max: T,
+
+    /// Amount the handle is moved by the Left and Right arrows.
+    pub step: T,
+
+    /// Initial value of the handle, the minimum when not set.
+    pub starting_value: Option<T>,
+
+    /// Labels displayed under the bar at their values.
+    ///
+    /// Labels that would overlap the previous one are not displayed.
+    pub ticks: Vec<(T, &'a str)>,
+
+    /// Unit of the value, displayed after it.
+    pub unit: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the value displayed next to the bar and as the final answer.
+    ///
+    /// The unit, if any, is appended to the formatted value.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Slider<'a, T>
+where
+    T: Numeric,
+{
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move, home/end to jump to the bounds");
+
+    /// Creates a [Slider] with the provided message and bounds, and default configuration values.
+    ///
+    /// The minimum is expected to be lower than the maximum.
+    pub fn new(message: &'a str, min: T, max: T) -> Self {
+        Self {
+            message,
+            min,
+            max,
+            step: T::DEFAULT_STEP,
+            starting_value: None,
+            ticks: vec![],
+            unit: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|val| val.to_string(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the amount the handle is moved by the Left and Right arrows.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the initial value of the handle.
+    pub fn with_starting_value(mut self, value: T) -> Self {
+        self.starting_value = Some(value);
+        self
+    }
+
+    /// Adds a label displayed under the bar at the given value.
+    pub fn with_tick(mut self, value: T, label: &'a str) -> Self {
+        self.ticks.push((value, label));
+        self
+    }
+
+    /// Sets the unit of the value, displayed after it.
+    pub fn with_unit(mut self, unit: &'a str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SliderBackend>(self, backend: &mut B) -> InquireResult<T> {
+        let values = SliderPrompt::from(self).prompt(backend)?;
+        Ok(values[0])
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{SliderBackend, SliderBar},
+    Numeric, RangeSlider, Slider,
+};
+
+use super::{action::SliderPromptAction, config::SliderConfig};
+
+pub struct SliderPrompt<'a, T> {
+    message: &'a str,
+    config: SliderConfig,
+    help_message: Option<&'a str>,
+    min: T,
+    max: T,
+    step: T,
+    /// One value per handle, in ascending order.
+    values: Vec<T>,
+    active: usize,
+    ticks: Vec<(T, &'a str)>,
+    unit: Option<&'a str>,
+    formatter: CustomTypeFormatter<'a, T>,
+}
+
+fn clamp<T: Numeric>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+impl<'a, T> From<Slider<'a, T>> for SliderPrompt<'a, T>
+where
+    T: Numeric,
+{
+    fn from(so: Slider<'a, T>) -> Self {
+        let value = clamp(so.starting_value.unwrap_or(so.min), so.min, so.max);
+
+        Self {
+            message: so.message,
+            config: (&so).into(),
+            help_message: so.help_message,
+            min: so.min,
+            max: so.max,
+            step: so.step,
+            values: vec![value],
+            active: 0,
+            ticks: so.ticks,
+            unit: so.unit,
+            formatter: so.formatter,
+        }
+    }
+}
+
+impl<'a, T> From<RangeSlider<'a, T>> for SliderPrompt<'a, T>
+where
+    T: Numeric,
+{
+    fn from(so: RangeSlider<'a, T>) -> Self {
+        let (start, end) = so.starting_range.unwrap_or((so.min, so.max));
+        let start = clamp(start, so.min, so.max);
+        let end = clamp(end, start, so.max);
+
+        Self {
+            message: so.message,
+            config: (&so).into(),
+            help_message: so.help_message,
+            min: so.min,
+            max: so.max,
+            step: so.step,
+            values: vec![start, end],
+            active: 0,
+            ticks: so.ticks,
+            unit: so.unit,
+            formatter: so.formatter,
+        }
+    }
+}
+
+impl<'a, T> SliderPrompt<'a, T>
+where
+    T: Numeric,
+{
+    /// Moves the focused handle to the value, without going past the bounds
+    /// or the other handle.
+    fn move_to(&mut self, value: T) -> ActionResult {
+        let low = match self.active {
+            0 => self.min,
+            idx => self.values[idx - 1],
+        };
+        let high = match self.values.get(self.active + 1) {
+            Some(next) => *next,
+            None => self.max,
+        };
+
+        let value = clamp(value, low, high);
+        if value == self.values[self.active] {
+            return ActionResult::Clean;
+        }
+
+        self.values[self.active] = value;
+        ActionResult::NeedsRedraw
+    }
+
+    fn position(&self, value: T) -> f64 {
+        let span = self.max.as_f64() - self.min.as_f64();
+
+        match span > 0.0 {
+            true => (value.as_f64() - self.min.as_f64()) / span,
+            false => 0.0,
+        }
+    }
+
+    fn format_values(&self, values: &[T]) -> String {
+        let formatted: Vec<String> = values
+            .iter()
+            .map(|value| (self.formatter)(*value))
+            .collect();
+        let formatted = formatted.join(" – ");
+
+        match self.unit {
+            Some(unit) => format!("{formatted} {unit}"),
+            None => formatted,
+        }
+    }
+
+    fn bar(&self) -> SliderBar {
+        let mut ticks: Vec<(f64, String)> = self
+            .ticks
+            .iter()
+            .map(|(value, label)| (self.position(*value), String::from(*label)))
+            .collect();
+        ticks.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        SliderBar {
+            handles: self
+                .values
+                .iter()
+                .map(|value| self.position(*value))
+                .collect(),
+            active: self.active,
+            label: self.format_values(&self.values),
+            ticks,
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for SliderPrompt<'a, T>
+where
+    Backend: SliderBackend,
+    T: Numeric,
+{
+    type Config = SliderConfig;
+    type InnerAction = SliderPromptAction;
+    type Output = Vec<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &SliderConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<T>) -> String {
+        self.format_values(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<T>>> {
+        Ok(Some(self.values.clone()))
+    }
+
+    fn handle(&mut self, action: SliderPromptAction) -> InquireResult<ActionResult> {
+        let current = self.values[self.active];
+
+        let result = match action {
+            SliderPromptAction::MoveLeft => self.move_to(current.step_down(self.step)),
+            SliderPromptAction::MoveRight => self.move_to(current.step_up(self.step)),
+            SliderPromptAction::MoveToStart => self.move_to(self.min),
+            SliderPromptAction::MoveToEnd => self.move_to(self.max),
+            SliderPromptAction::SwitchHandle => {
+                self.active = (self.active + 1) % self.values.len();
+                ActionResult::NeedsRedraw
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_slider_prompt(self.message)?;
+        backend.render_slider(&self.bar())?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
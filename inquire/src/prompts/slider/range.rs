@@ -0,0 +1,192 @@
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SliderBackend},
+    Numeric,
+};
+
+use super::prompt::SliderPrompt;
+
+/// [`Slider`](crate::Slider) with two handles, to pick a range of numbers between two bounds.
+///
+/// Tab switches the focused handle, which is moved with the Left and Right arrows, or to
+/// the bounds with Home and End. The handles can not be moved past each other, so the
+/// returned range is always ordered.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::RangeSlider;
+///
+/// let confidence = RangeSlider::<u8>::new("Confidence interval:", 0, 100)
+///     .with_step(5)
+///     .with_starting_range(25, 75)
+///     .with_unit("%")
+///     .prompt();
+///
+/// match confidence {
+///     Ok((low, high)) => println!("Between {}% and {}%", low, high),
+///     Err(_) => println!("An error happened, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RangeSlider<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Value at the start of the bar.
+    pub min: T,
+
+    /// Value at the end of the bar.
+    pub max: T,
+
+    /// Amount the focused handle is moved by the Left and Right arrows.
+    pub step: T,
+
+    /// Initial values of the handles, the bounds when not set.
+    pub starting_range: Option<(T, T)>,
+
+    /// Labels displayed under the bar at their values.
+    ///
+    /// Labels that would overlap the previous one are not displayed.
+    pub ticks: Vec<(T, &'a str)>,
+
+    /// Unit of the values, displayed after them.
+    pub unit: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats each value of the range, displayed next to the bar and as the final answer.
+    ///
+    /// The unit, if any, is appended to the formatted range.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> RangeSlider<'a, T>
+where
+    T: Numeric,
+{
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move, tab to switch handles, home/end to jump to the bounds");
+
+    /// Creates a [RangeSlider] with the provided message and bounds, and default configuration values.
+    ///
+    /// The minimum is expected to be lower than the maximum.
+    pub fn new(message: &'a str, min: T, max: T) -> Self {
+        Self {
+            message,
+            min,
+            max,
+            step: T::DEFAULT_STEP,
+            starting_range: None,
+            ticks: vec![],
+            unit: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|val| val.to_string(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the amount the focused handle is moved by the Left and Right arrows.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the initial values of the handles.
+    pub fn with_starting_range(mut self, start: T, end: T) -> Self {
+        self.starting_range = Some((start, end));
+        self
+    }
+
+    /// Adds a label displayed under the bar at the given value.
+    pub fn with_tick(mut self, value: T, label: &'a str) -> Self {
+        self.ticks.push((value, label));
+        self
+    }
+
+    /// Sets the unit of the values, displayed after them.
+    pub fn with_unit(mut self, unit: &'a str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<(T, T)>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<(T, T)> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SliderBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(T, T)> {
+        let values = SliderPrompt::from(self).prompt(backend)?;
+        Ok((values[0], values[1]))
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers, SliderBar,
+    },
+    RangeSlider, Slider,
+};
+
+fn bars(backend: &FakeBackend) -> Vec<&SliderBar> {
+    backend
+        .frames()
+        .iter()
+        .filter_map(|frame| {
+            frame.tokens().iter().find_map(|token| match token {
+                Token::Slider(bar) => Some(bar),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn arrows_move_the_handle_by_the_step() {
+    let keys = vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Slider::<i32>::new("", 0, 100)
+        .with_step(5)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(5, ans);
+}
+
+#[test]
+fn home_and_end_jump_to_the_bounds() {
+    let keys = vec![Key::End, Key::Right(KeyModifiers::NONE), Key::Enter];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Slider::<u8>::new("", 1, 20)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(20, ans);
+
+    let keys = vec![Key::Home, Key::Left(KeyModifiers::NONE), Key::Enter];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Slider::<u8>::new("", 1, 20)
+        .with_starting_value(12)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans);
+}
+
+#[test]
+fn float_steps_are_not_affected_by_rounding_errors() {
+    let keys = vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::prompts::test::fake_backend(keys);
+
+    let ans = Slider::<f64>::new("", 0.0, 1.0)
+        .with_step(0.1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0.3, ans);
+}
+
+#[test]
+fn bar_contains_positions_value_and_ticks() {
+    let keys = vec![Key::Right(KeyModifiers::NONE), Key::Enter];
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Slider::<u8>::new("Confidence:", 0, 100)
+        .with_step(25)
+        .with_starting_value(200)
+        .with_tick(100, "high")
+        .with_tick(0, "low")
+        .with_unit("%")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(100, ans);
+
+    let bars = bars(&backend);
+    assert_eq!(
+        &SliderBar {
+            handles: vec![1.0],
+            active: 0,
+            label: String::from("100 %"),
+            ticks: vec![(0.0, String::from("low")), (1.0, String::from("high"))],
+        },
+        bars[0]
+    );
+
+    let last = backend.frames().last().unwrap();
+    assert!(last.has_token(&Token::AnsweredPrompt("Confidence:".into(), "100 %".into())));
+}
+
+#[test]
+fn tab_is_ignored_by_single_handle_sliders() {
+    let keys = vec![Key::Tab, Key::Right(KeyModifiers::NONE), Key::Enter];
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Slider::<i64>::new("", -5, 5)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(-4, ans);
+    assert_eq!(2, bars(&backend).len());
+}
+
+#[test]
+fn range_handles_do_not_cross() {
+    let keys = vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Tab,
+        Key::Home,
+        Key::Tab,
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = RangeSlider::<u32>::new("", 0, 10)
+        .with_starting_range(2, 4)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!((3, 4), ans);
+
+    let bars = bars(&backend);
+    assert_eq!(vec![0.2, 0.4], bars[0].handles);
+    assert_eq!(0, bars[0].active);
+    assert_eq!(1, bars[3].active);
+    assert_eq!(String::from("4 – 4"), bars[3].label);
+}
+
+#[test]
+fn range_answer_is_formatted_with_the_unit() {
+    let keys = vec![Key::Tab, Key::Left(KeyModifiers::NONE), Key::Enter];
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = RangeSlider::<u16>::new("Size:", 100, 500)
+        .with_step(100)
+        .with_unit("MB")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!((100, 400), ans);
+
+    let last = backend.frames().last().unwrap();
+    assert!(last.has_token(&Token::AnsweredPrompt(
+        "Size:".into(),
+        "100 – 400 MB".into()
+    )));
+}
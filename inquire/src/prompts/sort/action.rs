@@ -0,0 +1,58 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::SortConfig;
+
+/// Set of actions for a SortPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortPromptAction {
+    /// Moves the cursor, or the grabbed option, up.
+    MoveUp,
+    /// Moves the cursor, or the grabbed option, down.
+    MoveDown,
+    /// Moves the cursor, or the grabbed option, one page up.
+    PageUp,
+    /// Moves the cursor, or the grabbed option, one page down.
+    PageDown,
+    /// Moves the cursor, or the grabbed option, to the start of the list.
+    MoveToStart,
+    /// Moves the cursor, or the grabbed option, to the end of the list.
+    MoveToEnd,
+    /// Grabs the current option, or drops it if already grabbed.
+    ToggleGrab,
+}
+
+impl InnerAction for SortPromptAction {
+    type Config = SortConfig;
+
+    fn from_key(key: Key, config: &SortConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleGrab,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
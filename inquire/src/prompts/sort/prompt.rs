@@ -0,0 +1,284 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SortBackend,
+    utils::paginate,
+    InquireError, Sort,
+};
+
+use super::{action::SortPromptAction, config::SortConfig};
+
+pub struct SortPrompt<'a, T> {
+    message: &'a str,
+    config: SortConfig,
+    options: Vec<T>,
+    order: Vec<usize>,
+    pinned: BTreeSet<usize>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    order_before_grab: Option<Vec<usize>>,
+    formatter: MultiOptionFormatter<'a, T>,
+}
+
+impl<'a, T> SortPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(so: Sort<'a, T>) -> InquireResult<Self> {
+        if so.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if so.starting_cursor >= so.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                so.starting_cursor,
+                &so.options.len()
+            )));
+        }
+
+        for i in &so.pinned {
+            if i >= &so.options.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Index {} is out-of-bounds for length {} of options",
+                    i,
+                    &so.options.len()
+                )));
+            }
+        }
+
+        Ok(Self {
+            message: so.message,
+            config: (&so).into(),
+            order: (0..so.options.len()).collect(),
+            options: so.options,
+            pinned: so.pinned.into_iter().collect(),
+            help_message: so.help_message,
+            cursor_index: so.starting_cursor,
+            order_before_grab: None,
+            formatter: so.formatter,
+        })
+    }
+
+    fn is_grabbed(&self) -> bool {
+        self.order_before_grab.is_some()
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.order.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.order.len() {
+            new_position = if wrap {
+                new_position % self.order.len()
+            } else {
+                self.order.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Moves the grabbed option up to `qty` positions, swapping it with the
+    /// closest non-pinned options so that pinned ones stay in place.
+    fn move_grabbed_up(&mut self, qty: usize) -> ActionResult {
+        let mut result = ActionResult::Clean;
+
+        for _ in 0..qty {
+            let target = (0..self.cursor_index)
+                .rev()
+                .find(|position| !self.pinned.contains(&self.order[*position]));
+
+            match target {
+                Some(target) => {
+                    self.order.swap(self.cursor_index, target);
+                    self.cursor_index = target;
+                    result = ActionResult::NeedsRedraw;
+                }
+                None => break,
+            }
+        }
+
+        result
+    }
+
+    /// Moves the grabbed option down to `qty` positions, swapping it with the
+    /// closest non-pinned options so that pinned ones stay in place.
+    fn move_grabbed_down(&mut self, qty: usize) -> ActionResult {
+        let mut result = ActionResult::Clean;
+
+        for _ in 0..qty {
+            let target = (self.cursor_index + 1..self.order.len())
+                .find(|position| !self.pinned.contains(&self.order[*position]));
+
+            match target {
+                Some(target) => {
+                    self.order.swap(self.cursor_index, target);
+                    self.cursor_index = target;
+                    result = ActionResult::NeedsRedraw;
+                }
+                None => break,
+            }
+        }
+
+        result
+    }
+
+    fn move_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        match self.is_grabbed() {
+            true => self.move_grabbed_up(qty),
+            false => self.move_cursor_up(qty, wrap),
+        }
+    }
+
+    fn move_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        match self.is_grabbed() {
+            true => self.move_grabbed_down(qty),
+            false => self.move_cursor_down(qty, wrap),
+        }
+    }
+
+    fn toggle_grab(&mut self) -> ActionResult {
+        if self.is_grabbed() {
+            self.order_before_grab = None;
+            return ActionResult::NeedsRedraw;
+        }
+
+        match self.order.get(self.cursor_index) {
+            Some(idx) if !self.pinned.contains(idx) => {
+                self.order_before_grab = Some(self.order.clone());
+                ActionResult::NeedsRedraw
+            }
+            _ => ActionResult::Clean,
+        }
+    }
+
+    /// Puts the grabbed option back where it was before being grabbed.
+    fn restore_grabbed(&mut self) {
+        let order = match self.order_before_grab.take() {
+            Some(order) => order,
+            None => return,
+        };
+
+        let grabbed = self.order[self.cursor_index];
+        self.cursor_index = order
+            .iter()
+            .position(|idx| *idx == grabbed)
+            .unwrap_or_default();
+        self.order = order;
+    }
+
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let mut positions = vec![0; self.order.len()];
+        for (position, idx) in self.order.iter().enumerate() {
+            positions[*idx] = position;
+        }
+
+        let mut answer = std::mem::take(&mut self.options)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, value)| ListOption::new(idx, value))
+            .collect::<Vec<_>>();
+        answer.sort_by_key(|option| positions[option.index]);
+
+        answer
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for SortPrompt<'a, T>
+where
+    Backend: SortBackend,
+    T: Display,
+{
+    type Config = SortConfig;
+    type InnerAction = SortPromptAction;
+    type Output = Vec<ListOption<T>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &SortConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.is_grabbed() {
+            self.restore_grabbed();
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        self.order_before_grab = None;
+        Ok(Some(self.get_final_answer()))
+    }
+
+    fn handle(&mut self, action: SortPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            SortPromptAction::MoveUp => self.move_up(1, true),
+            SortPromptAction::MoveDown => self.move_down(1, true),
+            SortPromptAction::PageUp => self.move_up(self.config.page_size, false),
+            SortPromptAction::PageDown => self.move_down(self.config.page_size, false),
+            SortPromptAction::MoveToStart => self.move_up(usize::MAX, false),
+            SortPromptAction::MoveToEnd => self.move_down(usize::MAX, false),
+            SortPromptAction::ToggleGrab => self.toggle_grab(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_sort_prompt(self.message)?;
+
+        let choices = self
+            .order
+            .iter()
+            .map(|i| ListOption::new(*i, &self.options[*i]))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page, self.is_grabbed(), &self.pinned)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
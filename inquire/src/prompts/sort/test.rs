@@ -0,0 +1,250 @@
+use crate::{
+    list_option::ListOption,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    InquireError, Sort,
+};
+
+fn options() -> Vec<&'static str> {
+    vec!["a", "b", "c", "d", "e"]
+}
+
+fn space() -> Key {
+    Key::Char(' ', KeyModifiers::NONE)
+}
+
+fn down() -> Key {
+    Key::Down(KeyModifiers::NONE)
+}
+
+fn up() -> Key {
+    Key::Up(KeyModifiers::NONE)
+}
+
+fn sort_options(options: &[&str], cursor: usize, grabbed: bool, pinned: &[&str]) -> Token {
+    Token::SortOptions {
+        options: options.iter().map(|o| (*o).to_owned()).collect(),
+        cursor: Some(cursor),
+        grabbed,
+        pinned: pinned.iter().map(|o| (*o).to_owned()).collect(),
+    }
+}
+
+#[test]
+fn submitting_without_moves_keeps_original_order() {
+    let mut backend = FakeBackend::new(vec![down(), down(), Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected = options()
+        .into_iter()
+        .enumerate()
+        .map(|(idx, value)| ListOption::new(idx, value))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, ans);
+}
+
+#[test]
+fn grabbed_option_is_moved_and_dropped() {
+    let mut backend = FakeBackend::new(vec![space(), down(), down(), space(), down(), Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+            ListOption::new(0, "a"),
+            ListOption::new(3, "d"),
+            ListOption::new(4, "e"),
+        ],
+        ans
+    );
+
+    assert!(backend.frames()[3].has_token(&sort_options(&["b", "c", "a", "d", "e"], 2, true, &[])));
+    assert!(backend.frames()[4].has_token(&sort_options(
+        &["b", "c", "a", "d", "e"],
+        2,
+        false,
+        &[]
+    )));
+    assert!(backend.frames()[5].has_token(&sort_options(
+        &["b", "c", "a", "d", "e"],
+        3,
+        false,
+        &[]
+    )));
+}
+
+#[test]
+fn grabbed_option_does_not_wrap_around() {
+    let mut backend = FakeBackend::new(vec![space(), up(), space(), up(), Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c", "d", "e"], values(ans));
+
+    // the cursor still wraps around once the option is dropped.
+    let last_frame = &backend.frames()[backend.frames().len() - 2];
+    assert!(last_frame.has_token(&sort_options(&options()[..], 4, false, &[])));
+}
+
+#[test]
+fn vim_mode_moves_grabbed_option() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        space(),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('k', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Sort::new("Question", options())
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "c", "b", "d", "e"], values(ans));
+}
+
+#[test]
+fn grabbed_option_skips_pinned_options() {
+    let mut backend = FakeBackend::new(vec![space(), down(), down(), Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .with_pinned(&[1, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["c", "b", "e", "d", "a"], values(ans));
+
+    assert!(backend.frames()[2].has_token(&sort_options(
+        &["c", "b", "a", "d", "e"],
+        2,
+        true,
+        &["b", "d"]
+    )));
+}
+
+#[test]
+fn pinned_option_can_not_be_grabbed() {
+    let mut backend = FakeBackend::new(vec![down(), space(), down(), Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .with_pinned(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c", "d", "e"], values(ans));
+    assert_eq!(4, backend.frames().len());
+}
+
+#[test]
+fn grabbed_option_can_not_move_past_pinned_edges() {
+    let mut backend = FakeBackend::new(vec![down(), space(), up(), Key::End, Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .with_pinned(&[0, 4])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "c", "d", "b", "e"], values(ans));
+}
+
+#[test]
+fn grabbed_option_is_moved_across_pages() {
+    let mut backend = FakeBackend::new(vec![
+        space(),
+        Key::PageDown(KeyModifiers::NONE),
+        Key::End,
+        Key::Enter,
+    ]);
+
+    let ans = Sort::new("Question", options())
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["b", "c", "d", "e", "a"], values(ans));
+
+    assert!(backend.frames()[2].has_token(&sort_options(&["c", "a"], 1, true, &[])));
+    assert!(backend.frames()[3].has_token(&sort_options(&["e", "a"], 1, true, &[])));
+}
+
+#[test]
+fn escape_puts_grabbed_option_back() {
+    let mut backend = FakeBackend::new(vec![space(), down(), down(), Key::Escape, Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c", "d", "e"], values(ans));
+    assert!(backend.frames()[4].has_token(&sort_options(&options()[..], 0, false, &[])));
+}
+
+#[test]
+fn escape_without_grabbed_option_cancels_prompt() {
+    let mut backend = FakeBackend::new(vec![space(), down(), space(), Key::Escape]);
+
+    let ans = Sort::new("Question", options()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::CanceledPrompt("Question".into())));
+}
+
+#[test]
+fn answer_is_formatted_in_chosen_order() {
+    let mut backend = FakeBackend::new(vec![Key::End, space(), Key::Home, Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["e", "a", "b", "c", "d"], values(ans));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question".into(),
+            "e, a, b, c, d".into()
+        )));
+}
+
+#[test]
+fn out_of_bounds_pinned_index_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Sort::new("Question", options())
+        .with_pinned(&[5])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn empty_options_are_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = Sort::<&str>::new("Question", vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+fn values<T>(answer: Vec<ListOption<T>>) -> Vec<T> {
+    answer.into_iter().map(|o| o.value).collect()
+}
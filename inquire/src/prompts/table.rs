@@ -0,0 +1,284 @@
+//! Tabular options shared by the table prompts.
+
+use std::collections::BTreeSet;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    prompts::scoring::ScoreCache,
+    type_aliases::{ColumnComparator, ColumnValue, Scorer},
+    ui::table::{TableLayout, TableRow},
+    InquireError,
+};
+
+/// Column of the tables displayed by [`TableSelect`](crate::TableSelect) and
+/// [`MultiTableSelect`](crate::MultiTableSelect) prompts.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::TableColumn;
+///
+/// struct Instance {
+///     name: String,
+///     age_in_days: u32,
+/// }
+///
+/// let columns = vec![
+///     TableColumn::new("NAME", &|i: &Instance| i.name.clone()),
+///     TableColumn::new("AGE", &|i: &Instance| format!("{}d", i.age_in_days))
+///         .with_comparator(&|a, b| a.age_in_days.cmp(&b.age_in_days))
+///         .without_filtering(),
+/// ];
+///
+/// let instance = Instance { name: "web-1".into(), age_in_days: 3 };
+/// assert_eq!("3d", (columns[1].value)(&instance));
+/// ```
+pub struct TableColumn<'a, T> {
+    /// Title displayed in the header row.
+    pub header: &'a str,
+
+    /// Function that extracts the content of the column from a row.
+    pub value: ColumnValue<'a, T>,
+
+    /// Whether the content of the column is matched against the filter input.
+    pub filterable: bool,
+
+    /// Function used to compare rows when sorting by this column. When not set,
+    /// rows are compared by the content of the column.
+    pub comparator: Option<ColumnComparator<'a, T>>,
+}
+
+impl<'a, T> Clone for TableColumn<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for TableColumn<'a, T> {}
+
+impl<'a, T> TableColumn<'a, T> {
+    /// Creates a filterable column with the provided header and content.
+    pub fn new(header: &'a str, value: ColumnValue<'a, T>) -> Self {
+        Self {
+            header,
+            value,
+            filterable: true,
+            comparator: None,
+        }
+    }
+
+    /// Excludes the content of the column from the filter.
+    pub fn without_filtering(mut self) -> Self {
+        self.filterable = false;
+        self
+    }
+
+    /// Sets the function used to compare rows when sorting by this column.
+    pub fn with_comparator(mut self, comparator: ColumnComparator<'a, T>) -> Self {
+        self.comparator = Some(comparator);
+        self
+    }
+}
+
+/// Direction in which the rows of a table are sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest values first.
+    Ascending,
+    /// Greatest values first.
+    Descending,
+}
+
+/// Rows of a table prompt along with the columns, filter and sorting
+/// state used to display them.
+pub(crate) struct Table<'a, T> {
+    options: Vec<T>,
+    columns: Vec<TableColumn<'a, T>>,
+    cells: Vec<Vec<String>>,
+    filter_values: Vec<String>,
+    widths: Vec<usize>,
+    sort: Option<(usize, SortOrder)>,
+    score_cache: ScoreCache,
+    scored: Vec<usize>,
+    visible: Vec<usize>,
+}
+
+impl<'a, T> Table<'a, T> {
+    pub fn new(
+        options: Vec<T>,
+        columns: Vec<TableColumn<'a, T>>,
+        sort: Option<(usize, SortOrder)>,
+        monotone_scorer: bool,
+    ) -> InquireResult<Self> {
+        if columns.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Table columns can not be empty".into(),
+            ));
+        }
+
+        if let Some((column, _)) = sort {
+            if column >= columns.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Sort column index {} is out-of-bounds for length {} of columns",
+                    column,
+                    columns.len()
+                )));
+            }
+        }
+
+        let cells: Vec<Vec<String>> = options
+            .iter()
+            .map(|option| columns.iter().map(|c| (c.value)(option)).collect())
+            .collect();
+
+        let filter_values = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&columns)
+                    .filter(|(_, column)| column.filterable)
+                    .map(|(cell, _)| cell.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect();
+
+        let widths = (0..columns.len())
+            .map(|i| {
+                cells
+                    .iter()
+                    .map(|row| row[i].width())
+                    .fold(columns[i].header.width(), usize::max)
+            })
+            .collect();
+
+        let mut table = Self {
+            scored: (0..options.len()).collect(),
+            visible: vec![],
+            options,
+            columns,
+            cells,
+            filter_values,
+            widths,
+            sort,
+            score_cache: ScoreCache::new(monotone_scorer),
+        };
+        table.apply_sort();
+
+        Ok(table)
+    }
+
+    pub fn visible_len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Index in the original list of the option displayed at `position`.
+    pub fn visible_index(&self, position: usize) -> Option<usize> {
+        self.visible.get(position).copied()
+    }
+
+    pub fn visible_indexes(&self) -> &[usize] {
+        &self.visible
+    }
+
+    pub fn position_of(&self, index: usize) -> Option<usize> {
+        self.visible.iter().position(|&i| i == index)
+    }
+
+    /// Content of the first column of the row, used to format answers by default.
+    pub fn label(&self, index: usize) -> &str {
+        &self.cells[index][0]
+    }
+
+    pub fn filter(&mut self, input: &str, scorer: Scorer<'_, T>) {
+        let options = &self.options;
+        let filter_values = &self.filter_values;
+
+        self.scored = self
+            .score_cache
+            .score(input, options.len(), |i| {
+                scorer(input, &options[i], &filter_values[i], i)
+            })
+            .to_vec();
+
+        self.apply_sort();
+    }
+
+    /// Moves the sorting to the next state, cycling through each column in
+    /// ascending then descending order before going back to the unsorted rows.
+    pub fn cycle_sort(&mut self) {
+        self.sort = match self.sort {
+            None => Some((0, SortOrder::Ascending)),
+            Some((column, SortOrder::Ascending)) => Some((column, SortOrder::Descending)),
+            Some((column, SortOrder::Descending)) if column + 1 < self.columns.len() => {
+                Some((column + 1, SortOrder::Ascending))
+            }
+            Some((_, SortOrder::Descending)) => None,
+        };
+
+        self.apply_sort();
+    }
+
+    fn apply_sort(&mut self) {
+        self.visible = self.scored.clone();
+
+        let (column, order) = match self.sort {
+            Some(sort) => sort,
+            None => return,
+        };
+
+        let comparator = self.columns[column].comparator;
+        let options = &self.options;
+        let cells = &self.cells;
+
+        self.visible.sort_by(|&a, &b| {
+            let ordering = match comparator {
+                Some(comparator) => comparator(&options[a], &options[b]),
+                None => cells[a][column].cmp(&cells[b][column]),
+            };
+
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    pub fn layout(&self) -> TableLayout<'_> {
+        TableLayout {
+            headers: self.columns.iter().map(|c| c.header).collect(),
+            widths: self.widths.clone(),
+            sort: self.sort,
+        }
+    }
+
+    pub fn rows(&self, checked: Option<&BTreeSet<usize>>) -> Vec<ListOption<TableRow<'_>>> {
+        self.visible
+            .iter()
+            .map(|&i| {
+                let row = TableRow {
+                    cells: &self.cells[i],
+                    checked: checked.map(|checked| checked.contains(&i)),
+                };
+                ListOption::new(i, row)
+            })
+            .collect()
+    }
+
+    /// Takes the options at the provided indexes, in the same order, leaving
+    /// the table empty.
+    pub fn take(&mut self, indexes: &[usize]) -> Vec<ListOption<T>> {
+        let mut options: Vec<Option<T>> = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        indexes
+            .iter()
+            .filter_map(|&i| options[i].take().map(|value| ListOption::new(i, value)))
+            .collect()
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        table::Table,
+    },
+    type_aliases::Scorer,
+    ui::table::TableSelectBackend,
+    utils::paginate,
+    InquireError, TableSelect,
+};
+
+use super::{action::TableSelectPromptAction, config::TableSelectConfig};
+
+pub struct TableSelectPrompt<'a, T> {
+    message: &'a str,
+    config: TableSelectConfig,
+    table: Table<'a, T>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Option<Input>,
+    scorer: Scorer<'a, T>,
+    formatter: Option<OptionFormatter<'a, T>>,
+}
+
+impl<'a, T> TableSelectPrompt<'a, T> {
+    pub fn new(tso: TableSelect<'a, T>) -> InquireResult<Self> {
+        if tso.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if tso.starting_cursor >= tso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                tso.starting_cursor,
+                &tso.options.len()
+            )));
+        }
+
+        let config = (&tso).into();
+        let table = Table::new(tso.options, tso.columns, tso.sort, tso.monotone_scorer)?;
+
+        let input = match tso.filter_input_enabled {
+            true => Some(Input::new()),
+            false => None,
+        };
+
+        Ok(Self {
+            message: tso.message,
+            config,
+            table,
+            help_message: tso.help_message,
+            cursor_index: tso.starting_cursor,
+            input,
+            scorer: tso.scorer,
+            formatter: tso.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.table.visible_len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let len = self.table.visible_len();
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= len {
+            new_position = if len == 0 {
+                0
+            } else if wrap {
+                new_position % len
+            } else {
+                len.saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn cycle_sort(&mut self) -> ActionResult {
+        let highlighted = self.table.visible_index(self.cursor_index);
+        self.table.cycle_sort();
+
+        self.cursor_index = highlighted
+            .and_then(|index| self.table.position_of(index))
+            .unwrap_or(0);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn run_scorer(&mut self) {
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => return,
+        };
+
+        self.table.filter(content, self.scorer);
+        self.cursor_index = 0;
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TableSelectPrompt<'a, T>
+where
+    Backend: TableSelectBackend,
+{
+    type Config = TableSelectConfig;
+    type InnerAction = TableSelectPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TableSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(answer.as_ref()),
+            None => self.table.label(answer.index).to_owned(),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let answer = self
+            .table
+            .visible_index(self.cursor_index)
+            .and_then(|index| self.table.take(&[index]).pop());
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TableSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TableSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TableSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TableSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TableSelectPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size, false)
+            }
+            TableSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TableSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TableSelectPromptAction::CycleSort => self.cycle_sort(),
+            TableSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
+                Some(input) => {
+                    let result = input.handle(input_action);
+
+                    if let InputActionResult::ContentChanged = result {
+                        self.run_scorer();
+                    }
+
+                    result.into()
+                }
+                None => ActionResult::Clean,
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_table_select_prompt(self.message, self.input.as_ref())?;
+
+        let layout = self.table.layout();
+        let rows = self.table.rows(None);
+        let page = paginate(self.config.page_size, &rows, Some(self.cursor_index));
+
+        backend.render_table_options(&layout, page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
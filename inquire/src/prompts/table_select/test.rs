@@ -0,0 +1,260 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    InquireError, SortOrder, TableColumn, TableSelect,
+};
+
+#[derive(Debug, PartialEq)]
+struct Instance {
+    name: &'static str,
+    zone: &'static str,
+    status: &'static str,
+    age_in_days: u32,
+}
+
+fn instances() -> Vec<Instance> {
+    vec![
+        Instance {
+            name: "web-1",
+            zone: "us-east1-b",
+            status: "RUNNING",
+            age_in_days: 3,
+        },
+        Instance {
+            name: "db-1",
+            zone: "europe-west1-d",
+            status: "STOPPED",
+            age_in_days: 120,
+        },
+        Instance {
+            name: "cache-1",
+            zone: "asia-east1-a",
+            status: "RUNNING",
+            age_in_days: 45,
+        },
+    ]
+}
+
+fn columns() -> Vec<TableColumn<'static, Instance>> {
+    vec![
+        TableColumn::new("NAME", &|i: &Instance| i.name.to_owned()),
+        TableColumn::new("ZONE", &|i: &Instance| i.zone.to_owned()),
+        TableColumn::new("STATUS", &|i: &Instance| i.status.to_owned()).without_filtering(),
+        TableColumn::new("AGE", &|i: &Instance| format!("{}d", i.age_in_days))
+            .with_comparator(&|a, b| a.age_in_days.cmp(&b.age_in_days))
+            .without_filtering(),
+    ]
+}
+
+fn names(rows: &[Vec<String>]) -> Vec<&str> {
+    rows.iter().map(|row| row[0].as_str()).collect()
+}
+
+fn table_options(
+    frame: &crate::ui::test::Frame,
+) -> (Option<(usize, SortOrder)>, Vec<&str>, Option<usize>) {
+    frame
+        .tokens()
+        .iter()
+        .find_map(|token| match token {
+            Token::TableOptions {
+                sort, rows, cursor, ..
+            } => Some((*sort, names(rows), *cursor)),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn renders_header_and_cells_of_each_row() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0, ans.index);
+    assert_eq!("web-1", ans.value.name);
+
+    let row = |cells: [&str; 4]| cells.iter().map(|c| (*c).to_owned()).collect::<Vec<_>>();
+    assert!(backend.frames()[0].has_token(&Token::TableOptions {
+        headers: vec!["NAME".into(), "ZONE".into(), "STATUS".into(), "AGE".into()],
+        sort: None,
+        rows: vec![
+            row(["web-1", "us-east1-b", "RUNNING", "3d"]),
+            row(["db-1", "europe-west1-d", "STOPPED", "120d"]),
+            row(["cache-1", "asia-east1-a", "RUNNING", "45d"]),
+        ],
+        cursor: Some(0),
+        checked: vec![],
+    }));
+}
+
+#[test]
+fn tab_cycles_through_columns_and_orders() {
+    let mut backend = FakeBackend::new(vec![Key::Tab, Key::Tab, Key::Tab, Key::Enter]);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("web-1", ans.value.name);
+
+    let frames = backend.frames();
+    assert_eq!(
+        (
+            Some((0, SortOrder::Ascending)),
+            vec!["cache-1", "db-1", "web-1"],
+            Some(2)
+        ),
+        table_options(&frames[1])
+    );
+    assert_eq!(
+        (
+            Some((0, SortOrder::Descending)),
+            vec!["web-1", "db-1", "cache-1"],
+            Some(0)
+        ),
+        table_options(&frames[2])
+    );
+    assert_eq!(
+        (
+            Some((1, SortOrder::Ascending)),
+            vec!["cache-1", "db-1", "web-1"],
+            Some(2)
+        ),
+        table_options(&frames[3])
+    );
+}
+
+#[test]
+fn sorting_past_the_last_column_restores_original_order() {
+    let mut backend = FakeBackend::new(vec![Key::Tab, Key::Tab, Key::Enter]);
+
+    TableSelect::new("Instance", instances(), columns())
+        .with_sort(3, SortOrder::Descending)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert_eq!(
+        (
+            Some((3, SortOrder::Descending)),
+            vec!["db-1", "cache-1", "web-1"],
+            Some(0)
+        ),
+        table_options(&frames[0])
+    );
+    assert_eq!(
+        (None, vec!["web-1", "db-1", "cache-1"], Some(1)),
+        table_options(&frames[1])
+    );
+    assert_eq!(
+        (
+            Some((0, SortOrder::Ascending)),
+            vec!["cache-1", "db-1", "web-1"],
+            Some(1)
+        ),
+        table_options(&frames[2])
+    );
+}
+
+#[test]
+fn comparator_is_used_instead_of_cell_content() {
+    let mut backend = FakeBackend::new(vec![Key::End, Key::Enter]);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .with_sort(3, SortOrder::Ascending)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("db-1", ans.value.name);
+    assert_eq!(
+        (
+            Some((3, SortOrder::Ascending)),
+            vec!["web-1", "cache-1", "db-1"],
+            Some(0)
+        ),
+        table_options(&backend.frames()[0])
+    );
+}
+
+#[test]
+fn filter_only_matches_filterable_columns() {
+    let mut keys = Key::char_keys_from_str("running");
+    keys.push(Key::Enter);
+    keys.extend(vec![Key::Backspace; 7]);
+    keys.extend(Key::char_keys_from_str("asia"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("cache-1", ans.value.name);
+    assert_eq!((None, vec![], Some(0)), table_options(&backend.frames()[7]));
+}
+
+#[test]
+fn sorting_applies_to_filtered_rows() {
+    let mut keys = Key::char_keys_from_str("east");
+    keys.push(Key::Tab);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the highlighted row is kept highlighted when sorting.
+    assert_eq!("web-1", ans.value.name);
+
+    let frames = backend.frames();
+    assert_eq!(
+        (
+            Some((0, SortOrder::Ascending)),
+            vec!["cache-1", "web-1"],
+            Some(1)
+        ),
+        table_options(&frames[frames.len() - 2])
+    );
+}
+
+#[test]
+fn answer_is_formatted_with_first_column_by_default() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    TableSelect::new("Instance", instances(), columns())
+        .with_starting_cursor(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Instance".into(), "db-1".into())));
+}
+
+#[test]
+fn empty_columns_are_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = TableSelect::new("Instance", instances(), vec![]).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn out_of_bounds_sort_column_is_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = TableSelect::new("Instance", instances(), columns())
+        .with_sort(4, SortOrder::Ascending)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
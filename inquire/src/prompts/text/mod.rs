@@ -7,6 +7,8 @@ mod test;
 
 pub use action::*;
 
+use std::sync::Arc;
+
 use crate::{
     autocompletion::Autocomplete,
     config::get_configuration,
@@ -15,7 +17,7 @@ use crate::{
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
     ui::{Backend, RenderConfig, TextBackend},
-    validator::StringValidator,
+    validator::{BackgroundStringValidator, LiveValidation, StringValidator},
 };
 
 use self::prompt::TextPrompt;
@@ -35,6 +37,7 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Background validators**: Slow validators run on a background thread after the answer is submitted, displaying a spinner until they resolve.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
 ///
@@ -108,6 +111,18 @@ pub struct Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Options of live validation, in which validators run as the user types.
+    ///
+    /// Disabled by default, validating the input only when it is submitted.
+    pub live_validation: Option<LiveValidation>,
+
+    /// Collection of validators run on a background thread once the answer is submitted
+    /// and passes the other validators.
+    ///
+    /// They run in the order they are stored, like the other validators, while a spinner
+    /// is displayed in place of the error message.
+    pub background_validators: Vec<Arc<dyn BackgroundStringValidator>>,
+
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
@@ -144,6 +159,8 @@ impl<'a> Text<'a> {
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
+            live_validation: None,
+            background_validators: vec![],
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
             autocompleter: None,
@@ -232,6 +249,36 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Enables live validation, in which validators run as the user types and their
+    /// result is displayed right away. Submitting is still refused while the input is invalid.
+    pub fn with_live_validation(mut self, live_validation: LiveValidation) -> Self {
+        self.live_validation = Some(live_validation);
+        self
+    }
+
+    /// Adds a validator run on a background thread once the answer is submitted and passes
+    /// the other validators, for slow checks such as looking the answer up in a database.
+    ///
+    /// A spinner is displayed in place of the error message while it runs. The user can
+    /// still edit the input, which discards the pending result, or cancel the prompt.
+    /// The answer is submitted as soon as the result resolves, if it is valid.
+    ///
+    /// The validators run on a single thread, one answer at a time. Answers edited
+    /// before their validation starts are skipped, while a validation that already
+    /// started can not be interrupted and keeps running until it returns, its result
+    /// being discarded.
+    ///
+    /// Running in the background requires the crossterm or termion backend, except
+    /// termion on macOS. Other backends can not wait for the result without blocking
+    /// on the next key, so the validator runs on submit before the answer is accepted.
+    pub fn with_background_validator<V>(mut self, validator: V) -> Self
+    where
+        V: BackgroundStringValidator,
+    {
+        self.background_validators.push(Arc::new(validator));
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::{cmp::min, time::Duration};
 
 use crate::{
     autocompletion::{NoAutoCompletion, Replacement},
@@ -6,10 +6,14 @@ use crate::{
     formatter::StringFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
+    prompts::{
+        background_validation::BackgroundValidator,
+        live_validation::LiveValidator,
+        prompt::{ActionResult, Prompt},
+    },
     ui::TextBackend,
     utils::paginate,
-    validator::{ErrorMessage, StringValidator, Validation},
+    validator::{validate_all, ErrorMessage, StringValidator, Validation},
     Autocomplete, InquireError, Text,
 };
 
@@ -23,7 +27,10 @@ pub struct TextPrompt<'a> {
     input: Input,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    live_validator: Option<LiveValidator>,
+    background_validator: Option<BackgroundValidator>,
     error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
     suggested_options: Vec<String>,
     suggestion_cursor_index: Option<usize>,
@@ -49,9 +56,15 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
             input,
             error: None,
+            warning: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
             validators: so.validators,
+            live_validator: so.live_validation.map(LiveValidator::new),
+            background_validator: match so.background_validators.is_empty() {
+                true => None,
+                false => Some(BackgroundValidator::new(so.background_validators)),
+            },
         }
     }
 }
@@ -111,6 +124,7 @@ impl<'a> TextPrompt<'a> {
     fn update_suggestion_cursor_pos(&mut self, new_position: Option<usize>) -> ActionResult {
         if new_position != self.suggestion_cursor_index {
             self.suggestion_cursor_index = new_position;
+            self.answer_changed();
             ActionResult::NeedsRedraw
         } else {
             ActionResult::Clean
@@ -149,15 +163,62 @@ impl<'a> TextPrompt<'a> {
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
+        let answer = self.get_current_answer();
+
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
+    }
+
+    /// Validates the answer to submit, returning `None` while background validators
+    /// are still running.
+    fn validate_submission(&mut self) -> InquireResult<Option<Validation>> {
+        let validation = self.validate_current_answer()?;
+        let answer = self.get_current_answer().to_owned();
+
+        let background = match &mut self.background_validator {
+            Some(background) if !matches!(validation, Validation::Invalid(_)) => {
+                background.validate(&answer).map_err(InquireError::Custom)?
             }
+            _ => return Ok(Some(validation)),
+        };
+
+        match background {
+            Some(background) => validate_all([Ok(validation), Ok(background)])
+                .map(Some)
+                .map_err(InquireError::Custom),
+            None => Ok(None),
+        }
+    }
+
+    fn answer_changed(&mut self) {
+        self.warning = None;
+
+        if let Some(background) = &mut self.background_validator {
+            background.answer_changed();
+        }
+    }
+
+    fn input_changed(&mut self) -> InquireResult<()> {
+        self.answer_changed();
+        self.update_suggestions()?;
+
+        if let Some(live) = &mut self.live_validator {
+            if live.input_changed() {
+                self.validate_live()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_live(&mut self) -> InquireResult<()> {
+        let validation = self.validate_current_answer()?;
+
+        if let Some(live) = &mut self.live_validator {
+            (self.error, self.warning) = live.update(validation);
         }
 
-        Ok(Validation::Valid)
+        Ok(())
     }
 }
 
@@ -185,16 +246,62 @@ where
         self.update_suggestions()
     }
 
-    fn submit(&mut self) -> InquireResult<Option<String>> {
-        let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
-            Validation::Invalid(msg) => {
-                self.error = Some(msg);
-                None
+    fn disable_ticks(&mut self) {
+        if let Some(live) = &mut self.live_validator {
+            live.disable_debounce();
+        }
+
+        if let Some(background) = &mut self.background_validator {
+            background.run_in_foreground();
+        }
+    }
+
+    fn tick_timeout(&self) -> Option<Duration> {
+        let background = self
+            .background_validator
+            .as_ref()
+            .and_then(BackgroundValidator::timeout);
+
+        background.or_else(|| {
+            self.live_validator
+                .as_ref()
+                .and_then(LiveValidator::timeout)
+        })
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        // Pending debounced validations wait for the background validators, the answer
+        // being validated again when they resolve.
+        if let Some(background) = &mut self.background_validator {
+            if background.is_pending() {
+                return match background.poll().map_err(InquireError::Custom)? {
+                    true => Ok(ActionResult::Submit),
+                    false => Ok(ActionResult::NeedsRedraw),
+                };
             }
+        }
+
+        self.validate_live()?;
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let Some(validation) = self.validate_submission()? else {
+            // The answer is submitted again once the background validators resolve.
+            self.error = None;
+            return Ok(None);
         };
 
-        Ok(result)
+        if let Some(live) = &mut self.live_validator {
+            live.submit(&validation);
+        }
+
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(self.get_current_answer().to_owned()));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
     }
 
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
@@ -203,7 +310,7 @@ where
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
-                    self.update_suggestions()?;
+                    self.input_changed()?;
                 }
 
                 result.into()
@@ -218,7 +325,7 @@ where
                 let result = self.use_current_suggestion()?;
 
                 if let ActionResult::NeedsRedraw = result {
-                    self.update_suggestions()?;
+                    self.input_changed()?;
                 }
 
                 result
@@ -231,8 +338,19 @@ where
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        if let Some(err) = &self.error {
+        let spinner_frame = self
+            .background_validator
+            .as_ref()
+            .and_then(BackgroundValidator::spinner_frame);
+
+        if let Some(frame) = spinner_frame {
+            backend.render_spinner(frame)?;
+        } else if let Some(err) = &self.error {
             backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        } else if matches!(&self.live_validator, Some(live) if live.shows_valid()) {
+            backend.render_valid_input_indicator()?;
         }
 
         backend.render_prompt(prompt, self.default, &self.input)?;
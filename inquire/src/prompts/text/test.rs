@@ -1,7 +1,22 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
 use super::Text;
+use crate::error::{InquireError, InquireResult};
+use crate::required;
+use crate::ui::test::{FakeBackend, Token};
 use crate::ui::{Key, KeyModifiers};
 use crate::validator::{ErrorMessage, Validation};
 
+fn key(c: char) -> Key {
+    Key::Char(c, KeyModifiers::NONE)
+}
+
 fn default<'a>() -> Text<'a> {
     Text::new("Question?")
 }
@@ -111,3 +126,164 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+fn taken_names<'a>() -> Text<'a> {
+    Text::new("Username:").with_background_validator(|name: &str| match name {
+        "root" => Ok(Validation::Invalid("This name is already taken".into())),
+        _ => Ok(Validation::Valid),
+    })
+}
+
+fn taken_error() -> Token {
+    Token::ErrorMessage(ErrorMessage::Custom("This name is already taken".into()))
+}
+
+#[test]
+fn background_validation_displays_a_spinner_until_it_resolves() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(text_to_events!("ab\n")).with_pause_after(3);
+
+    let name = taken_names().prompt_with_backend(&mut backend)?;
+    assert_eq!("ab", name);
+
+    let frames = backend.frames();
+    assert!(!frames[2].has_token(&Token::Spinner));
+    assert!(frames[3].has_token(&Token::Spinner));
+    // The answer is submitted as soon as the result resolves.
+    assert!(frames[4].has_token(&Token::PromptEnd));
+
+    Ok(())
+}
+
+#[test]
+fn background_validation_errors_are_displayed() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(text_to_events!("root\ns\n"))
+        .with_pause_after(5)
+        .with_pause_after(7);
+
+    let name = taken_names().prompt_with_backend(&mut backend)?;
+    assert_eq!("roots", name);
+
+    let frames = backend.frames();
+    assert!(frames[5].has_token(&Token::Spinner));
+    assert!(frames[6].has_token(&taken_error()));
+    // The spinner replaces the error while the new answer is validated.
+    assert!(frames[8].has_token(&Token::Spinner));
+    assert!(!frames[8].has_token(&taken_error()));
+
+    Ok(())
+}
+
+#[test]
+fn editing_the_input_discards_the_pending_background_validation() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        key('r'),
+        key('o'),
+        key('o'),
+        key('t'),
+        Key::Enter,
+        Key::Backspace,
+        key('s'),
+        Key::Enter,
+    ])
+    .with_pause_after(8);
+
+    let name = taken_names().prompt_with_backend(&mut backend)?;
+    assert_eq!("roos", name);
+
+    let frames = backend.frames();
+    assert!(frames[5].has_token(&Token::Spinner));
+    assert!(!frames[6].has_token(&Token::Spinner));
+    assert!(!frames.iter().any(|frame| frame.has_token(&taken_error())));
+
+    Ok(())
+}
+
+#[test]
+fn prompt_can_be_canceled_while_validating_in_the_background() {
+    let mut backend = FakeBackend::new(vec![key('a'), Key::Enter, Key::Escape]);
+
+    let result = Text::new("Username:")
+        .with_background_validator(|_: &str| {
+            std::thread::sleep(Duration::from_millis(200));
+            Ok(Validation::Valid)
+        })
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+    assert!(backend.frames()[2].has_token(&Token::Spinner));
+}
+
+#[test]
+fn background_validators_run_on_submit_when_reads_can_not_time_out() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(text_to_events!("root\ns\n")).without_timeouts();
+
+    let name = taken_names().prompt_with_backend(&mut backend)?;
+    assert_eq!("roots", name);
+
+    let frames = backend.frames();
+    // Otherwise, the result would only be applied once the next key is pressed.
+    assert!(frames[5].has_token(&taken_error()));
+    assert!(!frames.iter().any(|frame| frame.has_token(&Token::Spinner)));
+
+    Ok(())
+}
+
+#[test]
+fn background_validators_only_run_on_answers_passing_the_other_validators() -> InquireResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let mut backend = FakeBackend::new(vec![Key::Enter, key('a'), Key::Enter]).with_pause_after(3);
+
+    let name = Text::new("Username:")
+        .with_validator(required!())
+        .with_background_validator(move |_: &str| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(Validation::Valid)
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!("a", name);
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[test]
+fn many_changes_in_a_row_run_one_background_validation_at_a_time() -> InquireResult<()> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let (call_counter, running_counter, max_running_counter) =
+        (calls.clone(), running.clone(), max_running.clone());
+
+    let mut keys = vec![];
+    for _ in 0..20 {
+        keys.push(key('a'));
+        keys.push(Key::Enter);
+    }
+    let mut backend = FakeBackend::new(keys).with_pause_after(40);
+
+    let name = Text::new("Username:")
+        .with_background_validator(move |input: &str| {
+            call_counter.fetch_add(1, Ordering::SeqCst);
+            let now_running = running_counter.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running_counter.fetch_max(now_running, Ordering::SeqCst);
+
+            std::thread::sleep(Duration::from_millis(10));
+            running_counter.fetch_sub(1, Ordering::SeqCst);
+
+            match input.len() {
+                20 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("Stale answer".into())),
+            }
+        })
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!("a".repeat(20), name);
+
+    assert_eq!(1, max_running.load(Ordering::SeqCst));
+    // The answers submitted while a validation was running are skipped.
+    assert!(calls.load(Ordering::SeqCst) < 20);
+    let stale = Token::ErrorMessage(ErrorMessage::Custom("Stale answer".into()));
+    assert!(!backend.frames().iter().any(|frame| frame.has_token(&stale)));
+
+    Ok(())
+}
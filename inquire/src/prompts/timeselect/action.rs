@@ -0,0 +1,52 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TimeSelectConfig;
+
+/// Set of actions for a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeSelectPromptAction {
+    /// Move the focus to the previous time field, e.g. from minutes to hours.
+    FocusPrevField,
+    /// Move the focus to the next time field, e.g. from hours to minutes.
+    FocusNextField,
+    /// Increase the value of the focused field.
+    Increment,
+    /// Decrease the value of the focused field.
+    Decrement,
+}
+
+impl InnerAction for TimeSelectPromptAction {
+    type Config = TimeSelectConfig;
+
+    fn from_key(key: Key, _: &TimeSelectConfig) -> Option<Self> {
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) // standard
+            | Key::Char('b', KeyModifiers::CONTROL) // emacs
+            | Key::Char('h', KeyModifiers::NONE) // vim
+            => Self::FocusPrevField,
+
+            Key::Right(KeyModifiers::NONE) // standard
+            | Key::Tab
+            | Key::Char('f', KeyModifiers::CONTROL) // emacs
+            | Key::Char('l', KeyModifiers::NONE) // vim
+            => Self::FocusNextField,
+
+            Key::Up(KeyModifiers::NONE) // standard
+            | Key::Char('p', KeyModifiers::CONTROL) // emacs
+            | Key::Char('k', KeyModifiers::NONE) // vim
+            => Self::Increment,
+
+            Key::Down(KeyModifiers::NONE) // standard
+            | Key::Char('n', KeyModifiers::CONTROL) // emacs
+            | Key::Char('j', KeyModifiers::NONE) // vim
+            => Self::Decrement,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::NaiveTime;
+
+use crate::{ClockFormat, TimeSelect};
+
+/// Configuration settings used in the execution of a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TimeSelectConfig {
+    /// Min time allowed to be selected.
+    pub min_time: Option<NaiveTime>,
+
+    /// Max time allowed to be selected.
+    pub max_time: Option<NaiveTime>,
+
+    /// Whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub clock_format: ClockFormat,
+
+    /// Whether the seconds can be adjusted.
+    pub show_seconds: bool,
+
+    /// Number of minutes moved at a time when adjusting the minutes.
+    pub minute_step: u32,
+}
+
+impl From<&TimeSelect<'_>> for TimeSelectConfig {
+    fn from(value: &TimeSelect<'_>) -> Self {
+        Self {
+            min_time: value.min_time,
+            max_time: value.max_time,
+            clock_format: value.clock_format,
+            show_seconds: value.show_seconds,
+            minute_step: value.minute_step,
+        }
+    }
+}
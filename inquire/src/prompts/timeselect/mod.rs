@@ -0,0 +1,299 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::NaiveTime;
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_time,
+    error::{InquireError, InquireResult},
+    formatter::TimeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::TimeSelectBackend, Backend, RenderConfig},
+    validator::TimeValidator,
+};
+
+use self::prompt::TimeSelectPrompt;
+
+/// Clock used to display the hours in [`TimeSelect`] and
+/// [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClockFormat {
+    /// Hours from 01 to 12, followed by an AM/PM field, e.g. "02:30 PM".
+    TwelveHour,
+    /// Hours from 00 to 23, e.g. "14:30".
+    TwentyFourHour,
+}
+
+/// Prompt that allows user to select a time of the day by adjusting the hours, minutes and, optionally, seconds. Available via the `date` feature.
+///
+/// By default, the initial selected time is the current time. Each part of the time is displayed as a spinner:
+/// - Left and right arrows move the focus between the hours, minutes, seconds and AM/PM fields.
+/// - Up and down arrows increase or decrease the focused field, wrapping around without changing the other fields.
+///
+/// Finally, the user selects a time by pressing the enter key.
+///
+/// `TimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Default value selected when the prompt is displayed and the one selected if the user submits without any previous actions. Current time by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats the time as displayed by the prompt by default, e.g. "14:30" or "02:30 PM".
+/// - **Validators**: Custom validators to the user's selected time, displaying an error message if the time does not pass the requirements.
+/// - **Clock format**: Whether hours are displayed in the 24-hour clock, the default, or in the 12-hour clock with an AM/PM field.
+/// - **Seconds**: Whether the seconds can be adjusted, hidden and set to zero by default.
+/// - **Minute step**: Number of minutes moved at a time when adjusting the minutes, 1 by default.
+/// - **Min and max time**: Inclusive boundaries of allowed times. If any boundary is set, the user will not be able to move past them.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveTime;
+/// use inquire::{ClockFormat, TimeSelect};
+///
+/// let time = TimeSelect::new("When should the meeting start?")
+///     .with_starting_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+///     .with_min_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+///     .with_max_time(NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+///     .with_clock_format(ClockFormat::TwelveHour)
+///     .with_minute_step(15)
+///     .prompt();
+///
+/// match time {
+///     Ok(time) => println!("The meeting was scheduled to {}.", time),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Starting time to be selected.
+    pub starting_time: NaiveTime,
+
+    /// Min time allowed to be selected.
+    pub min_time: Option<NaiveTime>,
+
+    /// Max time allowed to be selected.
+    pub max_time: Option<NaiveTime>,
+
+    /// Whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub clock_format: ClockFormat,
+
+    /// Whether the seconds can be adjusted. When false, seconds are always zero.
+    pub show_seconds: bool,
+
+    /// Number of minutes moved at a time when adjusting the minutes, between 1 and 59.
+    pub minute_step: u32,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When `None`, the time is formatted as displayed by the prompt.
+    pub formatter: Option<TimeFormatter<'a>>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn TimeValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TimeSelect<'a> {
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move between fields, ↑↓ to adjust, enter to select");
+
+    /// Default validators added to the [TimeSelect] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn TimeValidator>> = vec![];
+
+    /// Default clock format, the 24-hour clock.
+    pub const DEFAULT_CLOCK_FORMAT: ClockFormat = ClockFormat::TwentyFourHour;
+
+    /// Default value of whether seconds are displayed, false.
+    pub const DEFAULT_SHOW_SECONDS: bool = false;
+
+    /// Default minute step.
+    pub const DEFAULT_MINUTE_STEP: u32 = 1;
+
+    /// Default min time.
+    pub const DEFAULT_MIN_TIME: Option<NaiveTime> = None;
+
+    /// Default max time.
+    pub const DEFAULT_MAX_TIME: Option<NaiveTime> = None;
+
+    /// Creates a [TimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_time: get_current_time(Self::DEFAULT_SHOW_SECONDS),
+            min_time: Self::DEFAULT_MIN_TIME,
+            max_time: Self::DEFAULT_MAX_TIME,
+            clock_format: Self::DEFAULT_CLOCK_FORMAT,
+            show_seconds: Self::DEFAULT_SHOW_SECONDS,
+            minute_step: Self::DEFAULT_MINUTE_STEP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default time of the prompt. Equivalent to [TimeSelect::with_starting_time](TimeSelect::with_starting_time).
+    pub fn with_default(self, default: NaiveTime) -> Self {
+        self.with_starting_time(default)
+    }
+
+    /// Sets the starting time. Equivalent to [TimeSelect::with_default](TimeSelect::with_default).
+    pub fn with_starting_time(mut self, starting_time: NaiveTime) -> Self {
+        self.starting_time = starting_time;
+        self
+    }
+
+    /// Sets the min time.
+    pub fn with_min_time(mut self, min_time: NaiveTime) -> Self {
+        self.min_time = Some(min_time);
+        self
+    }
+
+    /// Sets the max time.
+    pub fn with_max_time(mut self, max_time: NaiveTime) -> Self {
+        self.max_time = Some(max_time);
+        self
+    }
+
+    /// Sets whether hours are displayed in the 12-hour or the 24-hour clock.
+    pub fn with_clock_format(mut self, clock_format: ClockFormat) -> Self {
+        self.clock_format = clock_format;
+        self
+    }
+
+    /// Sets whether the seconds can be adjusted.
+    pub fn with_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the number of minutes moved at a time when adjusting the minutes,
+    /// e.g. 15 to move between quarters of an hour.
+    pub fn with_minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as office hours.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: TimeValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as office hours.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn TimeValidator>]) -> Self {
+        for validator in validators {
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveTime> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TimeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveTime> {
+        TimeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,184 @@
+use std::cmp::{max, min};
+
+use chrono::NaiveTime;
+
+use crate::{
+    date_utils::{format_time, get_time_fields, spin_time, truncate_time, TimeField},
+    error::InquireResult,
+    formatter::TimeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::date::TimeSelectBackend,
+    validator::{validate_all, ErrorMessage, TimeValidator, Validation},
+    InquireError, TimeSelect,
+};
+
+use super::{action::TimeSelectPromptAction, config::TimeSelectConfig};
+
+pub struct TimeSelectPrompt<'a> {
+    message: &'a str,
+    config: TimeSelectConfig,
+    current_time: NaiveTime,
+    fields: Vec<TimeField>,
+    focused_field: usize,
+    help_message: Option<&'a str>,
+    formatter: Option<TimeFormatter<'a>>,
+    validators: Vec<Box<dyn TimeValidator>>,
+    error: Option<ErrorMessage>,
+    warning: Option<ErrorMessage>,
+}
+
+impl<'a> TimeSelectPrompt<'a> {
+    pub fn new(ts: TimeSelect<'a>) -> InquireResult<Self> {
+        if ts.minute_step == 0 || ts.minute_step >= 60 {
+            return Err(InquireError::InvalidConfiguration(
+                "Minute step must be between 1 and 59".into(),
+            ));
+        }
+
+        let starting_time = truncate_time(ts.starting_time, ts.show_seconds);
+
+        if let Some(min_time) = ts.min_time {
+            if min_time > starting_time {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min time can not be greater than starting time".into(),
+                ));
+            }
+        }
+        if let Some(max_time) = ts.max_time {
+            if max_time < starting_time {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max time can not be smaller than starting time".into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            message: ts.message,
+            current_time: starting_time,
+            fields: get_time_fields(ts.clock_format, ts.show_seconds),
+            focused_field: 0,
+            config: (&ts).into(),
+            help_message: ts.help_message,
+            formatter: ts.formatter,
+            validators: ts.validators,
+            error: None,
+            warning: None,
+        })
+    }
+
+    fn move_focus(&mut self, forward: bool) -> ActionResult {
+        let new_focus = if forward {
+            min(self.focused_field + 1, self.fields.len() - 1)
+        } else {
+            self.focused_field.saturating_sub(1)
+        };
+
+        if new_focus == self.focused_field {
+            return ActionResult::Clean;
+        }
+
+        self.focused_field = new_focus;
+        ActionResult::NeedsRedraw
+    }
+
+    fn spin(&mut self, forward: bool) -> ActionResult {
+        let field = self.fields[self.focused_field];
+        let mut new_time = spin_time(self.current_time, field, forward, self.config.minute_step);
+
+        if let Some(min_time) = self.config.min_time {
+            new_time = max(new_time, min_time);
+        }
+        if let Some(max_time) = self.config.max_time {
+            new_time = min(new_time, max_time);
+        }
+
+        if new_time == self.current_time {
+            return ActionResult::Clean;
+        }
+
+        self.current_time = new_time;
+        self.warning = None;
+        ActionResult::NeedsRedraw
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let answer = self.cur_answer();
+
+        validate_all(self.validators.iter().map(|v| v.validate(answer)))
+            .map_err(InquireError::Custom)
+    }
+
+    fn cur_answer(&self) -> NaiveTime {
+        self.current_time
+    }
+}
+
+impl<'a, B> Prompt<B> for TimeSelectPrompt<'a>
+where
+    B: TimeSelectBackend,
+{
+    type Config = TimeSelectConfig;
+    type InnerAction = TimeSelectPromptAction;
+    type Output = NaiveTime;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &NaiveTime) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(*answer),
+            None => format_time(*answer, self.config.clock_format, self.config.show_seconds),
+        }
+    }
+
+    fn config(&self) -> &TimeSelectConfig {
+        &self.config
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveTime>> {
+        let validation = self.validate_current_answer()?;
+        if validation.accepts(self.warning.as_ref()) {
+            return Ok(Some(self.cur_answer()));
+        }
+
+        (self.error, self.warning) = validation.into_messages();
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: TimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TimeSelectPromptAction::FocusPrevField => self.move_focus(false),
+            TimeSelectPromptAction::FocusNextField => self.move_focus(true),
+            TimeSelectPromptAction::Increment => self.spin(true),
+            TimeSelectPromptAction::Decrement => self.spin(false),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        } else if let Some(warning) = &self.warning {
+            backend.render_warning_message(warning)?;
+        }
+
+        backend.render_time_prompt(prompt)?;
+
+        backend.render_time(
+            self.current_time,
+            &self.fields,
+            Some(self.fields[self.focused_field]),
+            self.config.clock_format,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
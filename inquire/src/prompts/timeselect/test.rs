@@ -0,0 +1,201 @@
+use chrono::NaiveTime;
+
+use crate::{
+    date_utils::TimeField,
+    error::InquireResult,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    ClockFormat, InquireError, TimeSelect,
+};
+
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+fn default<'a>() -> TimeSelect<'a> {
+    TimeSelect::new("Question?").with_starting_time(time(14, 30, 0))
+}
+
+macro_rules! time_test {
+    ($name:ident,$input:expr,$output:expr) => {
+        time_test! {$name, $input, $output, default()}
+    };
+
+    ($name:ident,$input:expr,$output:expr,$prompt:expr) => {
+        #[test]
+        fn $name() -> InquireResult<()> {
+            let mut backend = FakeBackend::new($input);
+
+            let ans = $prompt.prompt_with_backend(&mut backend)?;
+
+            assert_eq!($output, ans);
+
+            Ok(())
+        }
+    };
+}
+
+const UP: Key = Key::Up(KeyModifiers::NONE);
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+const LEFT: Key = Key::Left(KeyModifiers::NONE);
+const RIGHT: Key = Key::Right(KeyModifiers::NONE);
+
+time_test!(starting_time, vec![Key::Enter], time(14, 30, 0));
+
+time_test!(
+    seconds_are_dropped_when_hidden,
+    vec![Key::Enter],
+    time(14, 30, 0),
+    TimeSelect::new("Question?").with_starting_time(time(14, 30, 45))
+);
+
+time_test!(
+    hours_wrap_around,
+    vec![UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, Key::Enter],
+    time(0, 30, 0)
+);
+
+time_test!(
+    minutes_wrap_without_changing_hours,
+    vec![RIGHT, DOWN, DOWN, Key::Enter],
+    time(14, 58, 0),
+    default().with_starting_time(time(14, 0, 0))
+);
+
+time_test!(
+    focus_does_not_move_past_the_last_field,
+    vec![RIGHT, RIGHT, RIGHT, UP, LEFT, LEFT, LEFT, UP, Key::Enter],
+    time(15, 31, 0)
+);
+
+time_test!(
+    minute_step_snaps_to_multiples,
+    vec![RIGHT, UP, UP, Key::Enter],
+    time(14, 30, 0),
+    default()
+        .with_starting_time(time(14, 7, 0))
+        .with_minute_step(15)
+);
+
+time_test!(
+    seconds_can_be_adjusted,
+    vec![RIGHT, RIGHT, DOWN, Key::Enter],
+    time(14, 30, 44),
+    default()
+        .with_starting_time(time(14, 30, 45))
+        .with_seconds(true)
+);
+
+time_test!(
+    period_field_switches_between_am_and_pm,
+    vec![RIGHT, RIGHT, UP, Key::Enter],
+    time(2, 30, 0),
+    default().with_clock_format(ClockFormat::TwelveHour)
+);
+
+time_test!(
+    bounds_are_respected,
+    vec![UP, UP, UP, RIGHT, UP, Key::Enter],
+    time(16, 0, 0),
+    default().with_max_time(time(16, 0, 0))
+);
+
+time_test!(
+    lower_bound_is_respected,
+    vec![DOWN, DOWN, DOWN, Key::Enter],
+    time(12, 15, 0),
+    default().with_min_time(time(12, 15, 0))
+);
+
+#[test]
+fn invalid_minute_step_is_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_minute_step(0)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn starting_time_out_of_bounds_is_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = default()
+        .with_min_time(time(15, 0, 0))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn validator_error_is_displayed_until_a_valid_time_is_submitted() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, DOWN, DOWN, DOWN, Key::Enter]);
+
+    let validator = |t: NaiveTime| {
+        if t < time(12, 0, 0) {
+            Ok(Validation::Valid)
+        } else {
+            Ok(Validation::Invalid("Pick a morning slot".into()))
+        }
+    };
+
+    let ans = default()
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(time(11, 30, 0), ans);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Pick a morning slot".into()));
+    assert!(backend.frames()[1].has_token(&error));
+    assert!(!backend.frames().last().unwrap().has_token(&error));
+
+    Ok(())
+}
+
+#[test]
+fn focused_field_is_rendered() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![RIGHT, RIGHT, Key::Enter]);
+
+    default()
+        .with_clock_format(ClockFormat::TwelveHour)
+        .prompt_with_backend(&mut backend)?;
+
+    let frames = backend.frames();
+    let expected = |focused| Token::Time {
+        time: time(14, 30, 0),
+        focused: Some(focused),
+        clock_format: ClockFormat::TwelveHour,
+        with_seconds: false,
+    };
+
+    assert!(frames[0].has_token(&expected(TimeField::Hour)));
+    assert!(frames[1].has_token(&expected(TimeField::Minute)));
+    assert!(frames[2].has_token(&expected(TimeField::Period)));
+
+    Ok(())
+}
+
+#[test]
+fn answer_is_formatted_as_displayed_by_default() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    default()
+        .with_clock_format(ClockFormat::TwelveHour)
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Question?".into(),
+            "02:30 PM".into()
+        )));
+
+    Ok(())
+}
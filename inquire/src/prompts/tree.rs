@@ -0,0 +1,622 @@
+//! Hierarchical options shared by the tree prompts.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    type_aliases::{ChildrenLoader, Scorer},
+    ui::TreeRow,
+};
+
+/// Node of the trees displayed by [`TreeSelect`](crate::TreeSelect) and
+/// [`MultiTreeSelect`](crate::MultiTreeSelect) prompts.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::TreeNode;
+///
+/// let tree = TreeNode::new("kube-system")
+///     .with_children(vec![
+///         TreeNode::new("deployments").with_lazy_children(),
+///         TreeNode::new("services").with_lazy_children(),
+///     ])
+///     .with_expanded(true);
+///
+/// assert!(tree.is_expandable());
+/// assert!(tree.children[0].is_expandable());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    /// Value of the node.
+    pub value: T,
+
+    /// Children of the node.
+    pub children: Vec<TreeNode<T>>,
+
+    /// Whether the children of this node are loaded through the children loader
+    /// of the prompt when the node is first expanded.
+    pub lazy: bool,
+
+    /// Whether the node is expanded when the prompt is first rendered.
+    pub expanded: bool,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a collapsed node without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: vec![],
+            lazy: false,
+            expanded: false,
+        }
+    }
+
+    /// Sets the children of the node.
+    pub fn with_children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Marks the children of the node to be loaded through the children loader
+    /// of the prompt when the node is first expanded.
+    pub fn with_lazy_children(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Sets whether the node is expanded when the prompt is first rendered.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Returns whether the node has children, loaded or not.
+    pub fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+struct NodeState {
+    parent: Option<usize>,
+    children: Vec<usize>,
+    lazy: bool,
+    expanded: bool,
+}
+
+#[derive(Default)]
+struct Filter {
+    matches: BTreeSet<usize>,
+    relevant: BTreeSet<usize>,
+    /// Ancestors of matches expanded by the filter, kept apart from the nodes
+    /// expanded by the user so that clearing the filter restores them.
+    expanded: BTreeSet<usize>,
+}
+
+struct VisibleNode {
+    id: usize,
+    guides: Vec<bool>,
+    last: bool,
+}
+
+/// Flattened view of the nodes of a tree, keeping track of which of them are
+/// expanded and displayed to the user.
+///
+/// Nodes are identified by their insertion order, children loaded lazily being
+/// appended at the end.
+pub(crate) struct Tree<T> {
+    values: Vec<T>,
+    string_values: Vec<String>,
+    nodes: Vec<NodeState>,
+    roots: Vec<usize>,
+    filter: Option<Filter>,
+    visible: Vec<VisibleNode>,
+}
+
+impl<T> Tree<T>
+where
+    T: Display,
+{
+    pub fn new(roots: Vec<TreeNode<T>>) -> Self {
+        let mut tree = Self {
+            values: vec![],
+            string_values: vec![],
+            nodes: vec![],
+            roots: vec![],
+            filter: None,
+            visible: vec![],
+        };
+
+        tree.roots = tree.insert_all(roots, None);
+        tree.refresh();
+        tree
+    }
+
+    fn insert_all(&mut self, nodes: Vec<TreeNode<T>>, parent: Option<usize>) -> Vec<usize> {
+        nodes
+            .into_iter()
+            .map(|node| self.insert(node, parent))
+            .collect()
+    }
+
+    fn insert(&mut self, node: TreeNode<T>, parent: Option<usize>) -> usize {
+        let id = self.nodes.len();
+
+        self.string_values.push(node.value.to_string());
+        self.values.push(node.value);
+        self.nodes.push(NodeState {
+            parent,
+            children: vec![],
+            lazy: node.lazy,
+            expanded: node.expanded,
+        });
+
+        let children = self.insert_all(node.children, Some(id));
+        self.nodes[id].children = children;
+
+        id
+    }
+
+    pub fn has_lazy_nodes(&self) -> bool {
+        self.nodes.iter().any(|node| node.lazy)
+    }
+
+    pub fn is_filtering(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    pub fn visible_len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Id of the node displayed at the given position.
+    pub fn visible_id(&self, position: usize) -> Option<usize> {
+        self.visible.get(position).map(|node| node.id)
+    }
+
+    /// Position of the given node in the displayed list, if displayed.
+    pub fn position_of(&self, id: usize) -> Option<usize> {
+        self.visible.iter().position(|node| node.id == id)
+    }
+
+    pub fn is_expandable(&self, id: usize) -> bool {
+        let node = &self.nodes[id];
+        node.lazy || !node.children.is_empty()
+    }
+
+    pub fn is_expanded(&self, id: usize) -> bool {
+        self.nodes[id].expanded
+            || matches!(&self.filter, Some(filter) if filter.expanded.contains(&id))
+    }
+
+    pub fn parent(&self, id: usize) -> Option<usize> {
+        self.nodes[id].parent
+    }
+
+    /// Ids of the nodes from the root of the tree down to the given node.
+    pub fn path(&self, id: usize) -> Vec<usize> {
+        let mut path = vec![id];
+        let mut cur = id;
+
+        while let Some(parent) = self.nodes[cur].parent {
+            path.push(parent);
+            cur = parent;
+        }
+
+        path.reverse();
+        path
+    }
+
+    pub fn values_of(&self, path: &[usize]) -> Vec<&T> {
+        path.iter().map(|id| &self.values[*id]).collect()
+    }
+
+    /// Ids of all loaded nodes, in the order they appear in the tree.
+    pub fn preorder(&self) -> Vec<usize> {
+        let mut ids = vec![];
+        let mut stack = self.roots.iter().rev().copied().collect::<Vec<usize>>();
+
+        while let Some(id) = stack.pop() {
+            ids.push(id);
+            stack.extend(self.nodes[id].children.iter().rev());
+        }
+
+        ids
+    }
+
+    /// Expands the given node, loading its children if needed.
+    ///
+    /// Returns whether the tree changed.
+    pub fn expand(
+        &mut self,
+        id: usize,
+        loader: Option<ChildrenLoader<'_, T>>,
+    ) -> InquireResult<bool> {
+        if !self.is_expandable(id) {
+            return Ok(false);
+        }
+
+        let loaded = self.load_children(id, loader)?;
+        let changed = loaded || !self.is_expanded(id);
+
+        self.nodes[id].expanded = true;
+        if changed {
+            self.refresh();
+        }
+
+        Ok(changed)
+    }
+
+    /// Loads the children of the given node if they are lazy and were not loaded
+    /// yet, returning whether they were.
+    fn load_children(
+        &mut self,
+        id: usize,
+        loader: Option<ChildrenLoader<'_, T>>,
+    ) -> InquireResult<bool> {
+        if !self.nodes[id].lazy {
+            return Ok(false);
+        }
+
+        if let Some(loader) = loader {
+            let path = self.path(id);
+            let children = loader(&self.values_of(&path))?;
+            let children = self.insert_all(children, Some(id));
+            self.nodes[id].children.extend(children);
+        }
+        self.nodes[id].lazy = false;
+
+        Ok(true)
+    }
+
+    /// Collapses the given node, returning whether the tree changed.
+    pub fn collapse(&mut self, id: usize) -> bool {
+        if !self.is_expanded(id) {
+            return false;
+        }
+
+        self.nodes[id].expanded = false;
+        if let Some(filter) = &mut self.filter {
+            filter.expanded.remove(&id);
+        }
+        self.refresh();
+
+        true
+    }
+
+    /// Expands the ancestors of the given node so that it is displayed.
+    pub fn reveal(&mut self, id: usize) {
+        let mut cur = id;
+        while let Some(parent) = self.nodes[cur].parent {
+            self.nodes[parent].expanded = true;
+            cur = parent;
+        }
+        self.refresh();
+    }
+
+    /// Filters the displayed nodes to the ones matched by the scorer and their
+    /// ancestors, which are expanded until the filter is cleared. Children of
+    /// matched nodes can still be browsed by expanding them.
+    ///
+    /// Only loaded nodes are searched: filtering never calls the children loader,
+    /// lazy children being loaded once their parent is expanded by the user.
+    pub fn filter(&mut self, input: &str, scorer: Scorer<'_, T>) {
+        self.filter = None;
+
+        if input.is_empty() {
+            self.refresh();
+            return;
+        }
+
+        let mut filter = Filter::default();
+
+        for id in 0..self.nodes.len() {
+            if scorer(input, &self.values[id], &self.string_values[id], id).is_some() {
+                filter.matches.insert(id);
+                filter.relevant.insert(id);
+
+                let mut cur = id;
+                while let Some(parent) = self.nodes[cur].parent {
+                    if !filter.relevant.insert(parent) {
+                        break;
+                    }
+                    if !self.nodes[parent].expanded {
+                        filter.expanded.insert(parent);
+                    }
+                    cur = parent;
+                }
+            }
+        }
+
+        self.filter = Some(filter);
+        self.refresh();
+    }
+
+    /// Position of the first displayed node matched by the current filter.
+    pub fn first_match_position(&self) -> Option<usize> {
+        let filter = self.filter.as_ref()?;
+        self.visible
+            .iter()
+            .position(|node| filter.matches.contains(&node.id))
+    }
+
+    fn refresh(&mut self) {
+        let mut visible = vec![];
+        let roots = self.displayed_children(&self.roots, false);
+        let len = roots.len();
+
+        for (i, id) in roots.into_iter().enumerate() {
+            self.visit(id, i + 1 == len, &mut vec![], false, &mut visible);
+        }
+
+        self.visible = visible;
+    }
+
+    fn visit(
+        &self,
+        id: usize,
+        last: bool,
+        guides: &mut Vec<bool>,
+        browsing: bool,
+        visible: &mut Vec<VisibleNode>,
+    ) {
+        visible.push(VisibleNode {
+            id,
+            guides: guides.clone(),
+            last,
+        });
+
+        let node = &self.nodes[id];
+        if !self.is_expanded(id) {
+            return;
+        }
+
+        let browsing = browsing
+            || match &self.filter {
+                Some(filter) => filter.matches.contains(&id),
+                None => true,
+            };
+
+        let children = self.displayed_children(&node.children, browsing);
+        let len = children.len();
+
+        if node.parent.is_some() {
+            guides.push(!last);
+        }
+
+        for (i, child) in children.into_iter().enumerate() {
+            self.visit(child, i + 1 == len, guides, browsing, visible);
+        }
+
+        if node.parent.is_some() {
+            guides.pop();
+        }
+    }
+
+    fn displayed_children(&self, children: &[usize], browsing: bool) -> Vec<usize> {
+        match &self.filter {
+            Some(filter) if !browsing => children
+                .iter()
+                .copied()
+                .filter(|id| filter.relevant.contains(id))
+                .collect(),
+            _ => children.to_vec(),
+        }
+    }
+
+    /// Rows displayed to the user, as options identified by the id of their node.
+    pub fn rows(&self, checked: Option<&BTreeSet<usize>>) -> Vec<ListOption<TreeRow<&T>>> {
+        self.visible
+            .iter()
+            .map(|node| {
+                let state = &self.nodes[node.id];
+                let row = TreeRow {
+                    value: &self.values[node.id],
+                    is_root: state.parent.is_none(),
+                    guides: node.guides.clone(),
+                    last: node.last,
+                    expanded: match self.is_expandable(node.id) {
+                        true => Some(self.is_expanded(node.id)),
+                        false => None,
+                    },
+                    checked: checked.map(|checked| checked.contains(&node.id)),
+                };
+
+                ListOption::new(node.id, row)
+            })
+            .collect()
+    }
+
+    /// Clones the values of the given paths.
+    pub fn clone_paths(&self, paths: &[Vec<usize>]) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        paths
+            .iter()
+            .map(|path| path.iter().map(|id| self.values[*id].clone()).collect())
+            .collect()
+    }
+
+    /// Moves the values of the given path out of the tree.
+    pub fn into_path(self, path: &[usize]) -> Vec<T> {
+        let mut values = self.values.into_iter().map(Some).collect::<Vec<_>>();
+        path.iter().filter_map(|id| values[*id].take()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Tree, TreeNode};
+    use crate::type_aliases::Scorer;
+
+    const CONTAINS: Scorer<'static, &str> = &|input, _, value, _| match value.contains(input) {
+        true => Some(0),
+        false => None,
+    };
+
+    fn tree() -> Tree<&'static str> {
+        Tree::new(vec![
+            TreeNode::new("apps").with_children(vec![
+                TreeNode::new("web")
+                    .with_children(vec![TreeNode::new("src"), TreeNode::new("tests")]),
+                TreeNode::new("api"),
+            ]),
+            TreeNode::new("libs").with_children(vec![TreeNode::new("ui")]),
+        ])
+    }
+
+    fn visible(tree: &Tree<&'static str>) -> Vec<&'static str> {
+        tree.rows(None)
+            .into_iter()
+            .map(|row| *row.value.value)
+            .collect()
+    }
+
+    #[test]
+    fn collapsed_tree_displays_roots() {
+        let tree = tree();
+
+        assert_eq!(vec!["apps", "libs"], visible(&tree));
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], tree.preorder());
+    }
+
+    #[test]
+    fn expanding_and_collapsing_nodes() {
+        let mut tree = tree();
+
+        assert!(tree.expand(0, None).unwrap());
+        assert!(tree.expand(1, None).unwrap());
+        assert!(!tree.expand(2, None).unwrap());
+        assert_eq!(
+            vec!["apps", "web", "src", "tests", "api", "libs"],
+            visible(&tree)
+        );
+
+        assert!(tree.collapse(0));
+        assert_eq!(vec!["apps", "libs"], visible(&tree));
+
+        // nested expansions are remembered.
+        tree.expand(0, None).unwrap();
+        assert_eq!(
+            vec!["apps", "web", "src", "tests", "api", "libs"],
+            visible(&tree)
+        );
+    }
+
+    #[test]
+    fn guides_track_remaining_siblings() {
+        let mut tree = tree();
+        tree.expand(0, None).unwrap();
+        tree.expand(1, None).unwrap();
+
+        let rows = tree.rows(None);
+        let src = &rows[2].value;
+        assert_eq!(vec![true], src.guides);
+        assert!(!src.last);
+
+        let tests = &rows[3].value;
+        assert!(tests.last);
+
+        let api = &rows[4].value;
+        assert!(api.guides.is_empty());
+        assert!(api.last);
+    }
+
+    #[test]
+    fn filter_displays_matches_and_expands_ancestors() {
+        let mut tree = tree();
+
+        tree.filter("test", CONTAINS);
+        assert_eq!(vec!["apps", "web", "tests"], visible(&tree));
+        assert_eq!(Some(2), tree.first_match_position());
+    }
+
+    #[test]
+    fn clearing_the_filter_restores_expanded_nodes() {
+        let mut tree = tree();
+        tree.expand(0, None).unwrap();
+
+        tree.filter("test", CONTAINS);
+        assert_eq!(vec!["apps", "web", "tests"], visible(&tree));
+
+        tree.filter("", CONTAINS);
+        assert_eq!(vec!["apps", "web", "api", "libs"], visible(&tree));
+    }
+
+    #[test]
+    fn nodes_collapsed_while_filtering_stay_collapsed() {
+        let mut tree = tree();
+        tree.expand(1, None).unwrap();
+
+        tree.filter("test", CONTAINS);
+        assert!(tree.collapse(1));
+        assert_eq!(vec!["apps", "web"], visible(&tree));
+
+        tree.filter("", CONTAINS);
+        tree.expand(0, None).unwrap();
+        assert_eq!(vec!["apps", "web", "api", "libs"], visible(&tree));
+    }
+
+    #[test]
+    fn children_of_matches_can_be_browsed() {
+        let mut tree = tree();
+
+        tree.filter("web", CONTAINS);
+        assert_eq!(vec!["apps", "web"], visible(&tree));
+
+        tree.expand(1, None).unwrap();
+        assert_eq!(vec!["apps", "web", "src", "tests"], visible(&tree));
+    }
+
+    #[test]
+    fn lazy_children_are_loaded_once() {
+        let mut tree = Tree::new(vec![TreeNode::new("root").with_lazy_children()]);
+        let calls = std::cell::Cell::new(0);
+        let loader = |path: &[&&str]| {
+            calls.set(calls.get() + 1);
+            assert_eq!(vec![&"root"], path);
+            Ok(vec![TreeNode::new("child")])
+        };
+
+        tree.expand(0, Some(&loader)).unwrap();
+        tree.collapse(0);
+        tree.expand(0, Some(&loader)).unwrap();
+
+        assert_eq!(vec!["root", "child"], visible(&tree));
+        assert_eq!(1, calls.get());
+        assert_eq!(vec![0, 1], tree.path(1));
+    }
+
+    #[test]
+    fn filtering_does_not_load_lazy_children() {
+        let mut tree = Tree::new(vec![TreeNode::new("root")
+            .with_children(vec![TreeNode::new("static")])
+            .with_lazy_children()]);
+        let calls = std::cell::Cell::new(0);
+        let loader = |_: &[&&str]| {
+            calls.set(calls.get() + 1);
+            Ok(vec![TreeNode::new("loaded")])
+        };
+
+        tree.filter("static", CONTAINS);
+        assert_eq!(vec!["root", "static"], visible(&tree));
+
+        tree.filter("loaded", CONTAINS);
+        assert!(visible(&tree).is_empty());
+        assert_eq!(0, calls.get());
+
+        // The filter expanded the root, but its lazy children are still loaded
+        // when the user expands it.
+        assert!(tree.expand(0, Some(&loader)).unwrap());
+        assert_eq!(1, calls.get());
+
+        tree.filter("loaded", CONTAINS);
+        assert_eq!(vec!["root", "loaded"], visible(&tree));
+
+        tree.filter("", CONTAINS);
+        assert_eq!(vec!["root", "static", "loaded"], visible(&tree));
+    }
+}
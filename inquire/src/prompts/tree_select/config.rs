@@ -0,0 +1,19 @@
+use crate::TreeSelect;
+
+/// Configuration settings used in the execution of a TreeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TreeSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of nodes.
+    pub page_size: usize,
+}
+
+impl<T> From<&TreeSelect<'_, T>> for TreeSelectConfig {
+    fn from(value: &TreeSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::TreePathFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::{ChildrenLoader, Scorer},
+    ui::{Backend, RenderConfig, TreeSelectBackend},
+    TreeNode,
+};
+
+use self::prompt::TreeSelectPrompt;
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "fuzzy")]
+static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Prompt suitable for when you need the user to select one node of a tree, such as
+/// a resource in a namespace or a package in a monorepo.
+///
+/// Nodes with children are displayed with an expansion indicator, and the user can
+/// expand them by pressing the right arrow and collapse them by pressing the left arrow.
+/// Children are displayed below their parent, indented with guides.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of root nodes. The values
+/// of the nodes can be of any type as long as they implement the `Display` trait. When the user
+/// submits, the prompt returns the values of the nodes from the root of the tree down to the
+/// selected node.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// The displayed nodes are paginated in order to provide a smooth experience to the user, with the default page size being 7.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Root nodes**: Trees displayed to the user. Must be **non-empty**.
+/// - **Children loader**: Function called to load the children of nodes marked with
+///   [`with_lazy_children`](crate::TreeNode::with_lazy_children) the first time they are expanded.
+///   It is never called by the filter, which only searches loaded nodes.
+///   Required if any node is lazy, otherwise the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the path to the selected node, with values separated by slashes, by default.
+/// - **Page size**: Number of nodes displayed at once, 7 by default.
+/// - **Scorer function**: Function that defines whether a node matches the filter input. Matching nodes are displayed
+///   along with their ancestors, which are expanded. Nodes whose children were not loaded yet are not searched.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{TreeNode, TreeSelect};
+///
+/// let packages = vec![
+///     TreeNode::new("apps").with_children(vec![TreeNode::new("web"), TreeNode::new("api")]),
+///     TreeNode::new("libs").with_children(vec![TreeNode::new("ui"), TreeNode::new("utils")]),
+/// ];
+///
+/// let ans = TreeSelect::new("Which package do you want to build?", packages).prompt();
+///
+/// match ans {
+///     Ok(path) => println!("Building {}", path.join("/")),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TreeSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Root nodes of the trees displayed to the user.
+    pub roots: Vec<TreeNode<T>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the nodes displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the nodes using hjkl.
+    pub vim_mode: bool,
+
+    /// Whether to allow the nodes to be filtered by user input or not.
+    ///
+    /// Defaults to true.
+    pub filter_input_enabled: bool,
+
+    /// Function called with the current user input to decide whether a node
+    /// matches the filter, when it returns `Some`.
+    pub scorer: Scorer<'a, T>,
+
+    /// Function called to load the children of lazy nodes when they are first expanded.
+    pub children_loader: Option<ChildrenLoader<'a, T>>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: TreePathFormatter<'a, T>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> TreeSelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [TreeSelect](crate::TreeSelect) prompts.
+    /// Prints the string values of the path to the selected node, separated by slashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TreeSelect;
+    ///
+    /// let formatter = TreeSelect::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("apps"), formatter(&[&"apps"]));
+    /// assert_eq!(String::from("apps/web/src"), formatter(&[&"apps", &"web", &"src"]));
+    /// ```
+    pub const DEFAULT_FORMATTER: TreePathFormatter<'a, T> = &|path| {
+        path.iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join("/")
+    };
+
+    /// Default scoring function, which will create a score for the current node using the input value.
+    /// Nodes are matched when the score is not None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TreeSelect;
+    ///
+    /// let scorer = TreeSelect::<&str>::DEFAULT_SCORER;
+    /// assert_eq!(None,     scorer("sa", &"New York",   "New York",   0));
+    /// assert_eq!(Some(49), scorer("sa", &"Sacramento", "Sacramento", 1));
+    /// assert_eq!(Some(35), scorer("sa", &"Kansas",     "Kansas",     2));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+        };
+
+    #[cfg(not(feature = "fuzzy"))]
+    pub const DEFAULT_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default filter input enabled behaviour.
+    /// Defaults to true.
+    pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, → to expand, ← to collapse, enter to select, type to filter");
+
+    /// Creates a [TreeSelect] with the provided message and root nodes, along with default configuration values.
+    pub fn new(message: &'a str, roots: Vec<TreeNode<T>>) -> Self {
+        Self {
+            message,
+            roots,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            scorer: Self::DEFAULT_SCORER,
+            children_loader: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the scoring function.
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Sets the function called to load the children of lazy nodes.
+    pub fn with_children_loader(mut self, children_loader: ChildrenLoader<'a, T>) -> Self {
+        self.children_loader = Some(children_loader);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TreePathFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Disables the filter input, which means the user will not be able to filter the nodes
+    /// by typing.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the values of the nodes from the root of the tree down to the selected node.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the values of the nodes from the root of the tree down to the selected node.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TreeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<T>> {
+        TreeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::TreePathFormatter,
+    input::{Input, InputActionResult},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        tree::Tree,
+    },
+    type_aliases::{ChildrenLoader, Scorer},
+    ui::TreeSelectBackend,
+    utils::paginate,
+    InquireError, TreeSelect,
+};
+
+use super::{action::TreeSelectPromptAction, config::TreeSelectConfig};
+
+pub struct TreeSelectPrompt<'a, T> {
+    message: &'a str,
+    config: TreeSelectConfig,
+    tree: Tree<T>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Option<Input>,
+    scorer: Scorer<'a, T>,
+    children_loader: Option<ChildrenLoader<'a, T>>,
+    formatter: TreePathFormatter<'a, T>,
+}
+
+impl<'a, T> TreeSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(tso: TreeSelect<'a, T>) -> InquireResult<Self> {
+        if tso.roots.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let config = (&tso).into();
+        let tree = Tree::new(tso.roots);
+
+        if tso.children_loader.is_none() && tree.has_lazy_nodes() {
+            return Err(InquireError::InvalidConfiguration(
+                "Nodes with lazy children require a children loader".into(),
+            ));
+        }
+
+        let input = match tso.filter_input_enabled {
+            true => Some(Input::new()),
+            false => None,
+        };
+
+        Ok(Self {
+            message: tso.message,
+            config,
+            tree,
+            help_message: tso.help_message,
+            cursor_index: 0,
+            input,
+            scorer: tso.scorer,
+            children_loader: tso.children_loader,
+            formatter: tso.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.tree.visible_len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let len = self.tree.visible_len();
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= len {
+            new_position = if len == 0 {
+                0
+            } else if wrap {
+                new_position % len
+            } else {
+                len.saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn expand(&mut self) -> InquireResult<ActionResult> {
+        let id = match self.tree.visible_id(self.cursor_index) {
+            Some(id) => id,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        // Expanded nodes may still have lazy children to load, e.g. when they
+        // were expanded by the filter.
+        if self.tree.expand(id, self.children_loader)? {
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        let next = self.cursor_index.saturating_add(1);
+        let result = match self.tree.visible_id(next) {
+            Some(child) if self.tree.parent(child) == Some(id) => self.update_cursor_position(next),
+            _ => ActionResult::Clean,
+        };
+
+        Ok(result)
+    }
+
+    fn collapse(&mut self) -> ActionResult {
+        let id = match self.tree.visible_id(self.cursor_index) {
+            Some(id) => id,
+            None => return ActionResult::Clean,
+        };
+
+        if self.tree.collapse(id) {
+            return ActionResult::NeedsRedraw;
+        }
+
+        match self
+            .tree
+            .parent(id)
+            .and_then(|parent| self.tree.position_of(parent))
+        {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn run_filter(&mut self) {
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => return,
+        };
+
+        let highlighted = self.tree.visible_id(self.cursor_index);
+        self.tree.filter(content, self.scorer);
+
+        // Keep the node highlighted while filtering displayed once the filter is cleared.
+        if let (false, Some(id)) = (self.tree.is_filtering(), highlighted) {
+            self.tree.reveal(id);
+        }
+
+        self.cursor_index = match self.tree.is_filtering() {
+            true => self.tree.first_match_position(),
+            false => highlighted.and_then(|id| self.tree.position_of(id)),
+        }
+        .unwrap_or(0);
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TreeSelectPrompt<'a, T>
+where
+    Backend: TreeSelectBackend,
+    T: Display,
+{
+    type Config = TreeSelectConfig;
+    type InnerAction = TreeSelectPromptAction;
+    type Output = Vec<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TreeSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<T>) -> String {
+        let refs: Vec<&T> = answer.iter().collect();
+        (self.formatter)(&refs)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<T>>> {
+        let id = match self.tree.visible_id(self.cursor_index) {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        let path = self.tree.path(id);
+        let tree = std::mem::replace(&mut self.tree, Tree::new(vec![]));
+
+        Ok(Some(tree.into_path(&path)))
+    }
+
+    fn handle(&mut self, action: TreeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TreeSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TreeSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TreeSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TreeSelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            TreeSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TreeSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TreeSelectPromptAction::Expand => self.expand()?,
+            TreeSelectPromptAction::Collapse => self.collapse(),
+            TreeSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
+                Some(input) => {
+                    let result = input.handle(input_action);
+
+                    if let InputActionResult::ContentChanged = result {
+                        self.run_filter();
+                    }
+
+                    result.into()
+                }
+                None => ActionResult::Clean,
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_tree_select_prompt(self.message, self.input.as_ref())?;
+
+        let rows = self.tree.rows(None);
+        let page = paginate(self.config.page_size, &rows, Some(self.cursor_index));
+
+        backend.render_tree_options(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
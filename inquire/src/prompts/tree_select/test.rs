@@ -0,0 +1,303 @@
+use crate::{
+    error::CustomUserError,
+    ui::{
+        test::{FakeBackend, Token, TreeRowToken},
+        Key, KeyModifiers,
+    },
+    InquireError, TreeNode, TreeSelect,
+};
+
+fn packages() -> Vec<TreeNode<&'static str>> {
+    vec![
+        TreeNode::new("apps").with_children(vec![
+            TreeNode::new("web")
+                .with_children(vec![TreeNode::new("components"), TreeNode::new("pages")]),
+            TreeNode::new("api"),
+        ]),
+        TreeNode::new("libs").with_children(vec![TreeNode::new("ui"), TreeNode::new("utils")]),
+        TreeNode::new("README.md"),
+    ]
+}
+
+fn row(value: &str, depth: usize, expanded: Option<bool>) -> TreeRowToken {
+    TreeRowToken {
+        value: value.to_owned(),
+        depth,
+        expanded,
+        checked: None,
+    }
+}
+
+fn right() -> Key {
+    Key::Right(KeyModifiers::NONE)
+}
+
+fn left() -> Key {
+    Key::Left(KeyModifiers::NONE)
+}
+
+fn down() -> Key {
+    Key::Down(KeyModifiers::NONE)
+}
+
+#[test]
+fn submitting_a_root_returns_its_path() {
+    let mut backend = FakeBackend::new(vec![down(), Key::Enter]);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["libs"], ans);
+    assert!(backend.frames()[0].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("apps", 0, Some(false)),
+            row("libs", 0, Some(false)),
+            row("README.md", 0, None),
+        ],
+        cursor: Some(0),
+    }));
+}
+
+#[test]
+fn right_arrow_expands_then_moves_to_first_child() {
+    let mut backend = FakeBackend::new(vec![right(), right(), right(), down(), Key::Enter]);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["apps", "web", "components"], ans);
+    assert!(backend.frames()[3].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("apps", 0, Some(true)),
+            row("web", 1, Some(true)),
+            row("components", 2, None),
+            row("pages", 2, None),
+            row("api", 1, None),
+            row("libs", 0, Some(false)),
+            row("README.md", 0, None),
+        ],
+        cursor: Some(1),
+    }));
+}
+
+#[test]
+fn left_arrow_collapses_then_moves_to_parent() {
+    let mut backend = FakeBackend::new(vec![
+        right(),
+        right(),
+        right(),
+        right(),
+        left(),
+        left(),
+        left(),
+        left(),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["apps"], ans);
+
+    let frames = backend.frames();
+    assert!(frames[frames.len() - 2].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("apps", 0, Some(false)),
+            row("libs", 0, Some(false)),
+            row("README.md", 0, None),
+        ],
+        cursor: Some(0),
+    }));
+}
+
+#[test]
+fn vim_mode_expands_and_collapses_with_h_and_l() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TreeSelect::new("Package", packages())
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["libs", "utils"], ans);
+}
+
+#[test]
+fn filter_matches_descendants_and_expands_ancestors() {
+    let mut keys = Key::char_keys_from_str("pag");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["apps", "web", "pages"], ans);
+
+    let frames = backend.frames();
+    assert!(frames[frames.len() - 2].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("apps", 0, Some(true)),
+            row("web", 1, Some(true)),
+            row("pages", 2, None),
+        ],
+        cursor: Some(2),
+    }));
+}
+
+#[test]
+fn clearing_filter_keeps_ancestors_expanded_and_highlighted_node() {
+    let mut keys = Key::char_keys_from_str("utils");
+    keys.extend(vec![Key::Backspace; 5]);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // "u" is the last filter applied, which highlights its first match.
+    assert_eq!(vec!["libs", "ui"], ans);
+
+    let frames = backend.frames();
+    assert!(frames[frames.len() - 2].has_token(&Token::TreeOptions {
+        rows: vec![
+            row("apps", 0, Some(false)),
+            row("libs", 0, Some(true)),
+            row("ui", 1, None),
+            row("utils", 1, None),
+            row("README.md", 0, None),
+        ],
+        cursor: Some(2),
+    }));
+}
+
+#[test]
+fn submitting_without_matches_is_ignored() {
+    let mut keys = Key::char_keys_from_str("zzz");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.push(Key::Backspace);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["apps"], ans);
+}
+
+#[test]
+fn lazy_children_are_loaded_on_expansion() {
+    let mut backend = FakeBackend::new(vec![right(), down(), Key::Enter]);
+
+    let roots = vec![TreeNode::new("default").with_lazy_children()];
+    let loader = &|path: &[&&str]| -> Result<Vec<TreeNode<&str>>, CustomUserError> {
+        assert_eq!(vec![&"default"], path);
+        Ok(vec![TreeNode::new("pods"), TreeNode::new("services")])
+    };
+
+    let ans = TreeSelect::new("Resource", roots)
+        .with_children_loader(loader)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["default", "pods"], ans);
+    assert!(backend.frames()[0].has_token(&Token::TreeOptions {
+        rows: vec![row("default", 0, Some(false))],
+        cursor: Some(0),
+    }));
+}
+
+#[test]
+fn children_loader_errors_are_returned() {
+    let mut backend = FakeBackend::new(vec![right(), Key::Enter]);
+
+    let roots = vec![TreeNode::new("default").with_lazy_children()];
+    let loader = &|_: &[&&str]| -> Result<Vec<TreeNode<&str>>, CustomUserError> {
+        Err("cluster unreachable".into())
+    };
+
+    let ans = TreeSelect::new("Resource", roots)
+        .with_children_loader(loader)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::Custom(_))));
+}
+
+#[test]
+fn filtering_does_not_call_the_children_loader() {
+    let mut keys = Key::char_keys_from_str("pod");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let roots = vec![TreeNode::new("default")
+        .with_children(vec![TreeNode::new("pods")])
+        .with_lazy_children()];
+    let loader = &|_: &[&&str]| -> Result<Vec<TreeNode<&str>>, CustomUserError> {
+        Err("cluster unreachable".into())
+    };
+
+    let ans = TreeSelect::new("Resource", roots)
+        .with_children_loader(loader)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["default", "pods"], ans);
+}
+
+#[test]
+fn pending_lazy_children_of_expanded_nodes_are_loaded_on_expansion() {
+    let mut backend = FakeBackend::new(vec![right(), down(), down(), Key::Enter]);
+
+    let roots = vec![TreeNode::new("default")
+        .with_children(vec![TreeNode::new("pods")])
+        .with_lazy_children()
+        .with_expanded(true)];
+    let loader = &|_: &[&&str]| -> Result<Vec<TreeNode<&str>>, CustomUserError> {
+        Ok(vec![TreeNode::new("services")])
+    };
+
+    let ans = TreeSelect::new("Resource", roots)
+        .with_children_loader(loader)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["default", "services"], ans);
+}
+
+#[test]
+fn lazy_nodes_without_loader_are_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let roots = vec![TreeNode::new("default").with_lazy_children()];
+    let ans = TreeSelect::new("Resource", roots).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn answer_is_formatted_as_path() {
+    let mut backend = FakeBackend::new(vec![right(), right(), Key::Enter]);
+
+    TreeSelect::new("Package", packages())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Package".into(), "apps/web".into())));
+}
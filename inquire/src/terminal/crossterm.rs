@@ -1,4 +1,7 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    io::{stderr, Result, Stderr, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
@@ -42,6 +45,25 @@ impl InputReader for CrosstermKeyReader {
             }
         }
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
+
+            if let event::Event::Key(key_event) = event::read()? {
+                return Ok(Some(key_event.into()));
+            }
+        }
+    }
+
+    fn supports_timeout(&self) -> bool {
+        true
+    }
 }
 
 impl CrosstermTerminal {
@@ -256,6 +278,13 @@ impl From<KeyEvent> for Key {
             KeyEvent {
                 code: KeyCode::Esc, ..
             } => Self::Escape,
+            KeyEvent {
+                code: KeyCode::Enter | KeyCode::Char('\n' | '\r'),
+                modifiers,
+                ..
+            } if modifiers.contains(KeyModifiers::ALT) => {
+                Self::Char('\n', crate::ui::KeyModifiers::ALT)
+            }
             KeyEvent {
                 code: KeyCode::Enter | KeyCode::Char('\n' | '\r'),
                 ..
@@ -428,4 +457,16 @@ mod test {
             std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
         );
     }
+
+    #[test]
+    fn alt_enter_is_mapped_to_newline_with_alt_modifier() {
+        use crate::ui::{Key, KeyModifiers};
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let alt_enter = KeyEvent::new(KeyCode::Enter, crossterm::event::KeyModifiers::ALT);
+        let enter = KeyEvent::new(KeyCode::Enter, crossterm::event::KeyModifiers::NONE);
+
+        assert_eq!(Key::Char('\n', KeyModifiers::ALT), Key::from(alt_enter));
+        assert_eq!(Key::Enter, Key::from(enter));
+    }
 }
@@ -2,6 +2,7 @@ use core::fmt;
 use std::{
     fs::File,
     io::{Result, Write},
+    time::Duration,
 };
 
 use termion::{
@@ -28,14 +29,20 @@ enum IO<'a> {
 }
 
 pub struct TermionKeyReader {
+    tty: File,
     keys: Keys<File>,
 }
 
 impl TermionKeyReader {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
+        Self::from_tty(termion::get_tty()?)
+    }
+
+    fn from_tty(tty: File) -> InquireResult<Self> {
         Ok(Self {
-            keys: termion::get_tty()?.keys(),
+            keys: tty.try_clone()?.keys(),
+            tty,
         })
     }
 }
@@ -49,6 +56,40 @@ impl InputReader for TermionKeyReader {
             }
         }
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<crate::ui::Key>> {
+        match wait_for_input(&self.tty, timeout)? {
+            true => self.read_key().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    fn supports_timeout(&self) -> bool {
+        cfg!(not(target_os = "macos"))
+    }
+}
+
+/// Waits up to `timeout` for the terminal to have input, returning whether it has.
+#[cfg(not(target_os = "macos"))]
+fn wait_for_input(tty: &File, timeout: Duration) -> Result<bool> {
+    use rustix::event::{poll, PollFd, PollFlags, Timespec};
+    use std::convert::TryFrom;
+
+    let timeout = Timespec::try_from(timeout).ok();
+    let mut fds = [PollFd::new(tty, PollFlags::IN)];
+
+    match poll(&mut fds, timeout.as_ref()) {
+        Ok(ready) => Ok(ready > 0),
+        // a signal arrived before any input, handled as a timeout.
+        Err(rustix::io::Errno::INTR) => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Terminals can not be polled on macOS, so reads block until a key is pressed.
+#[cfg(target_os = "macos")]
+fn wait_for_input(_tty: &File, _timeout: Duration) -> Result<bool> {
+    Ok(true)
 }
 
 pub struct TermionTerminal<'a> {
@@ -266,6 +307,7 @@ impl From<Key> for crate::ui::Key {
             Key::Right => Self::Right(KeyModifiers::empty()),
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
+            Key::Alt('\n' | '\r') => Self::Char('\n', KeyModifiers::ALT),
             Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
             #[allow(deprecated)]
             _ => Self::Any,
@@ -275,11 +317,68 @@ impl From<Key> for crate::ui::Key {
 
 #[cfg(test)]
 mod test {
-    use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use std::{
+        fs::File,
+        io::Write,
+        os::{fd::OwnedFd, unix::net::UnixStream},
+        time::{Duration, Instant},
+    };
+
+    use crate::terminal::{test::MockTerminal, Terminal};
+    use crate::ui::{Backend, Color, InputReader, Key, KeyModifiers, RenderConfig};
+    use crate::validator::Validation;
+    use crate::Text;
 
     use super::Attributes;
-    use super::TermionTerminal;
+    use super::{TermionKeyReader, TermionTerminal};
+
+    /// Key reader over one end of a socket, the other end standing for the user.
+    fn key_reader() -> (TermionKeyReader, UnixStream) {
+        let (tty, user) = UnixStream::pair().unwrap();
+        let reader = TermionKeyReader::from_tty(File::from(OwnedFd::from(tty))).unwrap();
+
+        (reader, user)
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn read_key_timeout_returns_once_the_timeout_elapses() {
+        let (mut reader, mut user) = key_reader();
+
+        let started_at = Instant::now();
+        let key = reader.read_key_timeout(Duration::from_millis(50)).unwrap();
+        assert_eq!(None, key);
+        assert!(started_at.elapsed() >= Duration::from_millis(50));
+
+        user.write_all(b"a").unwrap();
+        let key = reader.read_key_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(Some(Key::Char('a', KeyModifiers::NONE)), key);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn background_validation_resolves_without_further_input() {
+        let (reader, mut user) = key_reader();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            let mut backend =
+                Backend::new(reader, MockTerminal::new(), RenderConfig::default()).unwrap();
+            let answer = Text::new("Username:")
+                .with_background_validator(|_: &str| {
+                    std::thread::sleep(Duration::from_millis(50));
+                    Ok(Validation::Valid)
+                })
+                .prompt_with_backend(&mut backend);
+            let _ = sender.send(answer);
+        });
+
+        // Only the submission is typed, the answer being accepted on a tick.
+        user.write_all(b"ab\r").unwrap();
+
+        let answer = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!("ab", answer.unwrap());
+    }
 
     #[test]
     fn writer() {
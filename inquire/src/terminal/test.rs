@@ -1,12 +1,11 @@
 use std::{collections::VecDeque, fmt::Display};
 
-use crate::ui::{Key, Styled};
+use crate::ui::Styled;
 
 use super::{Terminal, TerminalSize};
 
 pub struct MockTerminal {
     pub size: TerminalSize,
-    pub input: VecDeque<Key>,
     pub output: VecDeque<MockTerminalToken>,
 }
 
@@ -37,7 +36,6 @@ impl MockTerminal {
     pub fn new() -> Self {
         Self {
             size: TerminalSize::new(80, 40),
-            input: VecDeque::new(),
             output: VecDeque::new(),
         }
     }
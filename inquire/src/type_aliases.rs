@@ -1,6 +1,8 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::cmp::Ordering;
+
+use crate::{error::CustomUserError, PasswordStrength, TreeNode};
 
 /// Type alias to represent the function used to Score and filter options.
 ///
@@ -13,6 +15,10 @@ use crate::error::CustomUserError;
 /// The return type should be a score determining the order options should be displayed to the user.
 /// The greater the score, the higher on the list it will be displayed.
 ///
+/// Scorers that never match an option for an input when they did not match it for a prefix
+/// of that input can be declared monotone with `with_monotone_scorer`, so that only the
+/// options that matched the previous input are scored again when the user extends it.
+///
 /// # Examples
 ///
 /// ```
@@ -73,6 +79,55 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent the function used to Score and filter options in parallel,
+/// through a rayon thread pool.
+///
+/// The function receives:
+/// - Current user input, filter value
+/// - String value of the current option
+/// - Index of the current option in the original list
+///
+/// Unlike [`Scorer`], the option itself is not provided, which means the type of the
+/// options does not need to be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::ParallelScorer;
+///
+/// let filter: ParallelScorer =
+///     &|input, string_value, _idx| -> Option<i64> {
+///        let filter = input.to_lowercase();
+///        match string_value.to_lowercase().contains(&filter) {
+///            true => Some(0),
+///            false => None,
+///        }
+///     };
+///
+/// assert_eq!(None,    filter("sa", "New York",    0));
+/// assert_eq!(Some(0), filter("sa", "San Antonio", 6));
+/// ```
+#[cfg(feature = "parallel")]
+pub type ParallelScorer<'a> = &'a (dyn Fn(&str, &str, usize) -> Option<i64> + Sync);
+
+/// Type alias to represent the function used to estimate the strength of the input of
+/// [`Password`](crate::Password) prompts, displayed by their strength meter.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::StrengthEstimator, PasswordStrength};
+///
+/// let by_length: StrengthEstimator = &|password| match password.chars().count() {
+///     0..=7 => PasswordStrength::VeryWeak,
+///     8..=11 => PasswordStrength::Medium,
+///     _ => PasswordStrength::VeryStrong,
+/// };
+///
+/// assert_eq!(PasswordStrength::Medium, by_length("hunter2024"));
+/// ```
+pub type StrengthEstimator<'a> = &'a dyn Fn(&str) -> PasswordStrength;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -82,3 +137,116 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to load the children of tree nodes
+/// marked with [`with_lazy_children`](crate::TreeNode::with_lazy_children).
+///
+/// The function receives the values of the nodes from the root of the tree down to
+/// the node being expanded, and should return the children of the latter.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::ChildrenLoader;
+/// use inquire::TreeNode;
+///
+/// let loader: ChildrenLoader<&str> = &|path| {
+///     let children = match path.last() {
+///         Some(&&"pods") => vec![TreeNode::new("api-7d9f"), TreeNode::new("web-5c2a")],
+///         _ => vec![],
+///     };
+///     Ok(children)
+/// };
+///
+/// let children = loader(&[&"default", &"pods"]).unwrap();
+/// assert_eq!(2, children.len());
+/// ```
+pub type ChildrenLoader<'a, T> = &'a dyn Fn(&[&T]) -> Result<Vec<TreeNode<T>>, CustomUserError>;
+
+/// Type alias to represent the function used to extract the content of a table
+/// column from a row.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::ColumnValue;
+///
+/// struct Instance {
+///     name: &'static str,
+///     zone: &'static str,
+/// }
+///
+/// let zone: ColumnValue<Instance> = &|instance| instance.zone.to_owned();
+///
+/// let instance = Instance { name: "web-1", zone: "us-east1-b" };
+/// assert_eq!("us-east1-b", zone(&instance));
+/// ```
+pub type ColumnValue<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to compare two table rows when
+/// sorting them by a column.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::ColumnComparator;
+///
+/// struct Instance {
+///     name: &'static str,
+///     age_in_days: u32,
+/// }
+///
+/// let by_age: ColumnComparator<Instance> = &|a, b| a.age_in_days.cmp(&b.age_in_days);
+///
+/// let old = Instance { name: "db-1", age_in_days: 120 };
+/// let new = Instance { name: "web-1", age_in_days: 3 };
+/// assert_eq!(std::cmp::Ordering::Greater, by_age(&old, &new));
+/// ```
+pub type ColumnComparator<'a, T> = &'a dyn Fn(&T, &T) -> Ordering;
+
+/// Type alias to represent the function used to disable dates in
+/// [`DateSelect`](crate::DateSelect) prompts, such as weekends or holidays.
+///
+/// The function receives a date and should return whether it is disabled.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use inquire::type_aliases::DatePredicate;
+///
+/// let weekends: DatePredicate = &|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+///
+/// assert!(weekends(NaiveDate::from_ymd_opt(2021, 7, 25).unwrap()));
+/// assert!(!weekends(NaiveDate::from_ymd_opt(2021, 7, 26).unwrap()));
+/// ```
+#[cfg(feature = "date")]
+pub type DatePredicate<'a> = &'a dyn Fn(chrono::NaiveDate) -> bool;
+
+/// Type alias to represent the function used to style specific dates in the
+/// calendar of [`DateSelect`](crate::DateSelect) prompts, such as dates with
+/// existing bookings.
+///
+/// The function receives a date and should return the style sheet applied to
+/// it, or `None` to keep the default style.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::{type_aliases::DateStyler, ui::{Color, StyleSheet}};
+///
+/// let booked = NaiveDate::from_ymd_opt(2021, 7, 25).unwrap();
+/// let styler: DateStyler = &|date| {
+///     if date == booked {
+///         Some(StyleSheet::new().with_fg(Color::LightRed))
+///     } else {
+///         None
+///     }
+/// };
+///
+/// assert!(styler(booked).is_some());
+/// assert!(styler(NaiveDate::from_ymd_opt(2021, 7, 26).unwrap()).is_none());
+/// ```
+#[cfg(feature = "date")]
+pub type DateStyler<'a> = &'a dyn Fn(chrono::NaiveDate) -> Option<crate::ui::StyleSheet>;
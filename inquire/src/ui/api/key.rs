@@ -3,19 +3,33 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held while a key was pressed.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifier.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Key event read from the terminal, independent of the terminal backend.
+///
+/// Control sequences are represented as characters with the [`KeyModifiers::CONTROL`]
+/// modifier, e.g. `Ctrl+D` is `Key::Char('d', KeyModifiers::CONTROL)`, and `Alt+Enter`
+/// is represented as `Key::Char('\n', KeyModifiers::ALT)`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(missing_docs)]
 pub enum Key {
     Escape,
     Enter,
@@ -40,7 +54,7 @@ pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
-        pub fn char_keys_from_str(s: &str) -> Vec<Self> {
+        pub(crate) fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
                 .collect()
@@ -4,6 +4,6 @@ mod render_config;
 mod style;
 
 pub use color::*;
-pub(crate) use key::*;
+pub use key::*;
 pub use render_config::*;
 pub use style::*;
@@ -1,6 +1,8 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use crate::PasswordStrength;
+
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -84,6 +86,18 @@ pub struct RenderConfig<'a> {
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig<'a>,
 
+    /// Render configuration for warnings of validators, displayed in place of
+    /// error messages until the user submits again to accept the input.
+    pub warning_message: ErrorMessageRenderConfig<'a>,
+
+    /// Indicator displayed in place of the error message when the input passes
+    /// the validators of a prompt with live validation.
+    pub valid_input_indicator: Styled<&'a str>,
+
+    /// Render configuration of the spinner displayed in place of the error message
+    /// while background validators run.
+    pub spinner: SpinnerRenderConfig<'a>,
+
     /// Prefix for the current highlighted option.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -118,6 +132,19 @@ pub struct RenderConfig<'a> {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'a str>,
 
+    /// Prefix for the highlighted option when it is grabbed to be moved
+    /// in sort prompts. Replaces `highlighted_option_prefix` while grabbed.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the option value.
+    pub grabbed_option_prefix: Styled<&'a str>,
+
+    /// Indicator displayed next to options that can not be moved in sort prompts.
+    ///
+    /// Note: a non-styled space character is added before the indicator as
+    /// a separator from the option value.
+    pub pinned_option_indicator: Styled<&'a str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -134,6 +161,24 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet for the counter of selected options, displayed in multi-select
+    /// prompts with a minimum or maximum number of selections.
+    ///
+    /// Note: the counter is displayed wrapped in parenthesis, e.g. (2/5 selected).
+    pub selection_counter: StyleSheet,
+
+    /// Render configuration for tree prompts.
+    pub tree: TreeRenderConfig<'a>,
+
+    /// Render configuration for table prompts.
+    pub table: TableRenderConfig<'a>,
+
+    /// Render configuration for the strength meter of password prompts.
+    pub password_strength: PasswordStrengthRenderConfig<'a>,
+
+    /// Render configuration for the bar of slider prompts.
+    pub slider: SliderRenderConfig<'a>,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -160,6 +205,9 @@ impl<'a> RenderConfig<'a> {
             help_message: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
+            warning_message: ErrorMessageRenderConfig::empty_warning(),
+            valid_input_indicator: Styled::new("✓"),
+            spinner: SpinnerRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
@@ -168,9 +216,16 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            grabbed_option_prefix: Styled::new("*"),
+            pinned_option_indicator: Styled::new("(pinned)"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            selection_counter: StyleSheet::empty(),
+            tree: TreeRenderConfig::empty(),
+            table: TableRenderConfig::empty(),
+            password_strength: PasswordStrengthRenderConfig::empty(),
+            slider: SliderRenderConfig::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -191,6 +246,9 @@ impl<'a> RenderConfig<'a> {
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::default_colored(),
+            warning_message: ErrorMessageRenderConfig::default_colored_warning(),
+            valid_input_indicator: Styled::new("✓").with_fg(Color::LightGreen),
+            spinner: SpinnerRenderConfig::default_colored(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
@@ -199,9 +257,16 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
+            grabbed_option_prefix: Styled::new("*").with_fg(Color::LightYellow),
+            pinned_option_indicator: Styled::new("(pinned)").with_fg(Color::DarkGrey),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            selection_counter: StyleSheet::new().with_fg(Color::DarkGrey),
+            tree: TreeRenderConfig::default_colored(),
+            table: TableRenderConfig::default_colored(),
+            password_strength: PasswordStrengthRenderConfig::default_colored(),
+            slider: SliderRenderConfig::default_colored(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -253,6 +318,25 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration for warnings of validators.
+    pub fn with_warning_message(mut self, warning_message: ErrorMessageRenderConfig<'a>) -> Self {
+        self.warning_message = warning_message;
+        self
+    }
+
+    /// Sets the indicator displayed when the input passes live validation.
+    pub fn with_valid_input_indicator(mut self, valid_input_indicator: Styled<&'a str>) -> Self {
+        self.valid_input_indicator = valid_input_indicator;
+        self
+    }
+
+    /// Sets the render configuration of the spinner displayed while background
+    /// validators run.
+    pub fn with_spinner_config(mut self, spinner: SpinnerRenderConfig<'a>) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
     /// Sets the styled component for prefixes in highlighted options.
     pub fn with_highlighted_option_prefix(
         mut self,
@@ -286,6 +370,21 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the styled component for prefixes in grabbed options.
+    pub fn with_grabbed_option_prefix(mut self, grabbed_option_prefix: Styled<&'a str>) -> Self {
+        self.grabbed_option_prefix = grabbed_option_prefix;
+        self
+    }
+
+    /// Sets the indicator for pinned options.
+    pub fn with_pinned_option_indicator(
+        mut self,
+        pinned_option_indicator: Styled<&'a str>,
+    ) -> Self {
+        self.pinned_option_indicator = pinned_option_indicator;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
@@ -304,6 +403,39 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the counter of selected options.
+    pub fn with_selection_counter(mut self, selection_counter: StyleSheet) -> Self {
+        self.selection_counter = selection_counter;
+        self
+    }
+
+    /// Sets the render configuration for tree prompts.
+    pub fn with_tree_config(mut self, tree: TreeRenderConfig<'a>) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Sets the render configuration for table prompts.
+    pub fn with_table_config(mut self, table: TableRenderConfig<'a>) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Sets the render configuration for the strength meter of password prompts.
+    pub fn with_password_strength_config(
+        mut self,
+        password_strength: PasswordStrengthRenderConfig<'a>,
+    ) -> Self {
+        self.password_strength = password_strength;
+        self
+    }
+
+    /// Sets the render configuration for the bar of slider prompts.
+    pub fn with_slider_config(mut self, slider: SliderRenderConfig<'a>) -> Self {
+        self.slider = slider;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -413,6 +545,26 @@ impl<'a> ErrorMessageRenderConfig<'a> {
         }
     }
 
+    /// Render configuration for warnings in which no colors or attributes are applied.
+    pub fn empty_warning() -> Self {
+        Self {
+            prefix: Styled::new("!"),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty(),
+            default_message: "Press enter again to confirm.",
+        }
+    }
+
+    /// Render configuration for warnings where default colors and attributes are applied.
+    pub fn default_colored_warning() -> Self {
+        Self {
+            prefix: Styled::new("!").with_fg(Color::LightYellow),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty().with_fg(Color::LightYellow),
+            default_message: "Press enter again to confirm.",
+        }
+    }
+
     /// Sets the prefix.
     pub fn with_prefix(mut self, prefix: Styled<&'a str>) -> Self {
         self.prefix = prefix;
@@ -435,6 +587,408 @@ impl<'a> ErrorMessageRenderConfig<'a> {
     }
 }
 
+/// Render configuration for tree prompts.
+///
+/// Each displayed node is prefixed by its indentation guides, followed by the
+/// expansion indicator and a space character.
+#[derive(Copy, Clone, Debug)]
+pub struct TreeRenderConfig<'a> {
+    /// Indicator for expanded nodes.
+    pub expanded_indicator: Styled<&'a str>,
+
+    /// Indicator for collapsed nodes.
+    pub collapsed_indicator: Styled<&'a str>,
+
+    /// Indicator for nodes without children.
+    pub leaf_indicator: Styled<&'a str>,
+
+    /// Guide displayed before nodes followed by siblings, e.g. `├─ `.
+    pub branch_guide: &'a str,
+
+    /// Guide displayed before the last node among its siblings, e.g. `└─ `.
+    pub last_branch_guide: &'a str,
+
+    /// Guide displayed for each ancestor followed by siblings, e.g. `│  `.
+    pub vertical_guide: &'a str,
+
+    /// Guide displayed for each ancestor that is the last among its siblings,
+    /// usually blank characters with the same width as the other guides.
+    pub blank_guide: &'a str,
+
+    /// Style sheet for indentation guides.
+    pub guides: StyleSheet,
+}
+
+impl<'a> TreeRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            expanded_indicator: Styled::new("▾"),
+            collapsed_indicator: Styled::new("▸"),
+            leaf_indicator: Styled::new(" "),
+            branch_guide: "├─ ",
+            last_branch_guide: "└─ ",
+            vertical_guide: "│  ",
+            blank_guide: "   ",
+            guides: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            expanded_indicator: Styled::new("▾").with_fg(Color::LightCyan),
+            collapsed_indicator: Styled::new("▸").with_fg(Color::LightCyan),
+            leaf_indicator: Styled::new(" "),
+            branch_guide: "├─ ",
+            last_branch_guide: "└─ ",
+            vertical_guide: "│  ",
+            blank_guide: "   ",
+            guides: StyleSheet::new().with_fg(Color::DarkGrey),
+        }
+    }
+
+    /// Sets the indicator for expanded nodes.
+    pub fn with_expanded_indicator(mut self, expanded_indicator: Styled<&'a str>) -> Self {
+        self.expanded_indicator = expanded_indicator;
+        self
+    }
+
+    /// Sets the indicator for collapsed nodes.
+    pub fn with_collapsed_indicator(mut self, collapsed_indicator: Styled<&'a str>) -> Self {
+        self.collapsed_indicator = collapsed_indicator;
+        self
+    }
+
+    /// Sets the indicator for nodes without children.
+    pub fn with_leaf_indicator(mut self, leaf_indicator: Styled<&'a str>) -> Self {
+        self.leaf_indicator = leaf_indicator;
+        self
+    }
+
+    /// Sets the indentation guides, in order: branch, last branch, vertical and blank.
+    pub fn with_guides(
+        mut self,
+        branch_guide: &'a str,
+        last_branch_guide: &'a str,
+        vertical_guide: &'a str,
+        blank_guide: &'a str,
+    ) -> Self {
+        self.branch_guide = branch_guide;
+        self.last_branch_guide = last_branch_guide;
+        self.vertical_guide = vertical_guide;
+        self.blank_guide = blank_guide;
+        self
+    }
+
+    /// Sets the style sheet for indentation guides.
+    pub fn with_guides_style(mut self, guides: StyleSheet) -> Self {
+        self.guides = guides;
+        self
+    }
+}
+
+/// Render configuration for table prompts.
+///
+/// Note: the widths of the columns are computed from the widths of their contents,
+/// and cells that do not fit in the terminal are truncated with the ellipsis.
+#[derive(Copy, Clone, Debug)]
+pub struct TableRenderConfig<'a> {
+    /// Style sheet for the header row.
+    pub header: StyleSheet,
+
+    /// Separator displayed between columns.
+    pub column_separator: Styled<&'a str>,
+
+    /// Indicator displayed next to the header of the column rows are sorted by,
+    /// in ascending order.
+    pub ascending_indicator: Styled<&'a str>,
+
+    /// Indicator displayed next to the header of the column rows are sorted by,
+    /// in descending order.
+    pub descending_indicator: Styled<&'a str>,
+
+    /// Suffix of truncated cells.
+    pub ellipsis: &'a str,
+}
+
+impl<'a> TableRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            header: StyleSheet::empty(),
+            column_separator: Styled::new("  "),
+            ascending_indicator: Styled::new("▲"),
+            descending_indicator: Styled::new("▼"),
+            ellipsis: "…",
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            header: StyleSheet::new().with_attr(Attributes::BOLD),
+            column_separator: Styled::new("  "),
+            ascending_indicator: Styled::new("▲").with_fg(Color::LightCyan),
+            descending_indicator: Styled::new("▼").with_fg(Color::LightCyan),
+            ellipsis: "…",
+        }
+    }
+
+    /// Sets the style sheet for the header row.
+    pub fn with_header(mut self, header: StyleSheet) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the separator displayed between columns.
+    pub fn with_column_separator(mut self, column_separator: Styled<&'a str>) -> Self {
+        self.column_separator = column_separator;
+        self
+    }
+
+    /// Sets the indicators of the sorted column, for ascending and descending order.
+    pub fn with_sort_indicators(
+        mut self,
+        ascending_indicator: Styled<&'a str>,
+        descending_indicator: Styled<&'a str>,
+    ) -> Self {
+        self.ascending_indicator = ascending_indicator;
+        self.descending_indicator = descending_indicator;
+        self
+    }
+
+    /// Sets the suffix of truncated cells.
+    pub fn with_ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+}
+
+/// Render configuration for the strength meter of password prompts.
+///
+/// The meter is displayed as a bar with one filled segment per strength level,
+/// followed by the name of the level, e.g. `■■■□□ medium`. Both are styled with
+/// the style sheet of the level.
+#[derive(Copy, Clone, Debug)]
+pub struct PasswordStrengthRenderConfig<'a> {
+    /// Segment of the bar for the levels up to the current one.
+    pub filled_segment: &'a str,
+
+    /// Segment of the bar for the levels above the current one.
+    pub empty_segment: &'a str,
+
+    /// Style sheet for very weak passwords.
+    pub very_weak: StyleSheet,
+
+    /// Style sheet for weak passwords.
+    pub weak: StyleSheet,
+
+    /// Style sheet for medium passwords.
+    pub medium: StyleSheet,
+
+    /// Style sheet for strong passwords.
+    pub strong: StyleSheet,
+
+    /// Style sheet for very strong passwords.
+    pub very_strong: StyleSheet,
+}
+
+impl<'a> PasswordStrengthRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            filled_segment: "■",
+            empty_segment: "□",
+            very_weak: StyleSheet::empty(),
+            weak: StyleSheet::empty(),
+            medium: StyleSheet::empty(),
+            strong: StyleSheet::empty(),
+            very_strong: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            filled_segment: "■",
+            empty_segment: "□",
+            very_weak: StyleSheet::new().with_fg(Color::DarkRed),
+            weak: StyleSheet::new().with_fg(Color::LightRed),
+            medium: StyleSheet::new().with_fg(Color::LightYellow),
+            strong: StyleSheet::new().with_fg(Color::LightGreen),
+            very_strong: StyleSheet::new().with_fg(Color::DarkGreen),
+        }
+    }
+
+    /// Sets the segments of the bar, for the levels up to the current one and above it.
+    pub fn with_segments(mut self, filled_segment: &'a str, empty_segment: &'a str) -> Self {
+        self.filled_segment = filled_segment;
+        self.empty_segment = empty_segment;
+        self
+    }
+
+    /// Sets the style sheet for the given strength level.
+    pub fn with_style(mut self, strength: PasswordStrength, style: StyleSheet) -> Self {
+        match strength {
+            PasswordStrength::VeryWeak => self.very_weak = style,
+            PasswordStrength::Weak => self.weak = style,
+            PasswordStrength::Medium => self.medium = style,
+            PasswordStrength::Strong => self.strong = style,
+            PasswordStrength::VeryStrong => self.very_strong = style,
+        }
+        self
+    }
+
+    /// Style sheet for the given strength level.
+    pub fn style(&self, strength: PasswordStrength) -> StyleSheet {
+        match strength {
+            PasswordStrength::VeryWeak => self.very_weak,
+            PasswordStrength::Weak => self.weak,
+            PasswordStrength::Medium => self.medium,
+            PasswordStrength::Strong => self.strong,
+            PasswordStrength::VeryStrong => self.very_strong,
+        }
+    }
+}
+
+/// Render configuration for the bar of slider prompts.
+///
+/// The bar is drawn one column per segment, e.g. `━━━━━●────── 42`, so the
+/// segments and handles are expected to be one column wide.
+#[derive(Copy, Clone, Debug)]
+pub struct SliderRenderConfig<'a> {
+    /// Segment of the bar outside of the selected part.
+    pub track: Styled<&'a str>,
+
+    /// Segment of the bar in the selected part, from the start of the bar to
+    /// the handle, or between the two handles of range sliders.
+    pub filled_track: Styled<&'a str>,
+
+    /// Handle of the bar, or the focused handle of range sliders.
+    pub handle: Styled<&'a str>,
+
+    /// Handle of range sliders that is not focused.
+    pub inactive_handle: Styled<&'a str>,
+
+    /// Style sheet for the value displayed next to the bar.
+    pub value: StyleSheet,
+
+    /// Style sheet for the tick labels displayed under the bar.
+    pub tick_label: StyleSheet,
+}
+
+impl<'a> SliderRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            track: Styled::new("─"),
+            filled_track: Styled::new("━"),
+            handle: Styled::new("●"),
+            inactive_handle: Styled::new("○"),
+            value: StyleSheet::empty(),
+            tick_label: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            track: Styled::new("─").with_fg(Color::DarkGrey),
+            filled_track: Styled::new("━").with_fg(Color::LightCyan),
+            handle: Styled::new("●").with_fg(Color::LightCyan),
+            inactive_handle: Styled::new("○").with_fg(Color::LightCyan),
+            value: StyleSheet::new().with_attr(Attributes::BOLD),
+            tick_label: StyleSheet::new().with_fg(Color::DarkGrey),
+        }
+    }
+
+    /// Sets the segments of the bar, outside of and in the selected part.
+    pub fn with_track(mut self, track: Styled<&'a str>, filled_track: Styled<&'a str>) -> Self {
+        self.track = track;
+        self.filled_track = filled_track;
+        self
+    }
+
+    /// Sets the handles of the bar, focused and not focused.
+    pub fn with_handles(
+        mut self,
+        handle: Styled<&'a str>,
+        inactive_handle: Styled<&'a str>,
+    ) -> Self {
+        self.handle = handle;
+        self.inactive_handle = inactive_handle;
+        self
+    }
+
+    /// Sets the style sheet for the value displayed next to the bar.
+    pub fn with_value(mut self, value: StyleSheet) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the style sheet for the tick labels displayed under the bar.
+    pub fn with_tick_label(mut self, tick_label: StyleSheet) -> Self {
+        self.tick_label = tick_label;
+        self
+    }
+}
+
+/// Render configuration of the spinner displayed while background validators run,
+/// e.g. `⠹ validating…`.
+///
+/// The frames are displayed in turn, one per redraw, followed by a space and
+/// the message.
+#[derive(Copy, Clone, Debug)]
+pub struct SpinnerRenderConfig<'a> {
+    /// Frames of the spinner animation. The message is displayed alone when empty.
+    pub frames: &'a [&'a str],
+
+    /// Style sheet for the frames.
+    pub frame: StyleSheet,
+
+    /// Message displayed next to the spinner.
+    pub message: Styled<&'a str>,
+}
+
+impl<'a> SpinnerRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            frame: StyleSheet::empty(),
+            message: Styled::new("validating…"),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            frame: StyleSheet::new().with_fg(Color::LightCyan),
+            message: Styled::new("validating…").with_fg(Color::DarkGrey),
+        }
+    }
+
+    /// Sets the frames of the spinner animation.
+    pub fn with_frames(mut self, frames: &'a [&'a str]) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the style sheet for the frames.
+    pub fn with_frame(mut self, frame: StyleSheet) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Sets the message displayed next to the spinner.
+    pub fn with_message(mut self, message: Styled<&'a str>) -> Self {
+        self.message = message;
+        self
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
@@ -461,8 +1015,19 @@ pub mod calendar {
         /// When `Some(_)`, the style sheet will be applied to the two columns
         /// where the number is positioned, padded to spaces in the left if the
         /// number only has one digit. e.g. " 5" or "23".
+        ///
+        /// Time prompts apply the same style sheet to the time field currently
+        /// being adjusted, e.g. the hours in "09:30".
         pub selected_date: Option<StyleSheet>,
 
+        /// Style sheet for the dates between the start of a range and the
+        /// currently selected date, when selecting a range of dates.
+        pub range_date: StyleSheet,
+
+        /// Style sheet for the dates toggled by the user, when selecting
+        /// multiple dates.
+        pub toggled_date: StyleSheet,
+
         /// Style sheet for today's date, just for hinting purposes.
         pub today_date: StyleSheet,
 
@@ -473,6 +1038,10 @@ pub mod calendar {
         /// Style sheet for dates that can not be selected due to the
         /// min/max settings.
         pub unavailable_date: StyleSheet,
+
+        /// Style sheet for the ISO week numbers displayed to the left of
+        /// each week, when enabled.
+        pub week_number: StyleSheet,
     }
 
     impl<'a> CalendarRenderConfig<'a> {
@@ -483,9 +1052,12 @@ pub mod calendar {
                 header: StyleSheet::empty(),
                 week_header: StyleSheet::empty(),
                 selected_date: None,
+                range_date: StyleSheet::empty(),
+                toggled_date: StyleSheet::empty(),
                 today_date: StyleSheet::empty(),
                 different_month_date: StyleSheet::empty(),
                 unavailable_date: StyleSheet::empty(),
+                week_number: StyleSheet::empty(),
             }
         }
 
@@ -500,9 +1072,16 @@ pub mod calendar {
                         .with_fg(Color::Black)
                         .with_bg(Color::Grey),
                 ),
+                range_date: StyleSheet::empty()
+                    .with_fg(Color::Black)
+                    .with_bg(Color::DarkCyan),
+                toggled_date: StyleSheet::empty()
+                    .with_fg(Color::Black)
+                    .with_bg(Color::DarkGreen),
                 today_date: StyleSheet::empty().with_fg(Color::LightGreen),
                 different_month_date: StyleSheet::empty().with_fg(Color::DarkGrey),
                 unavailable_date: StyleSheet::empty().with_fg(Color::DarkGrey),
+                week_number: StyleSheet::empty().with_fg(Color::DarkGrey),
             }
         }
 
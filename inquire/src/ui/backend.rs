@@ -1,4 +1,4 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{collections::BTreeSet, fmt::Display, io::Result, time::Duration};
 
 use unicode_width::UnicodeWidthStr;
 
@@ -7,13 +7,18 @@ use crate::{
     input::Input,
     list_option::ListOption,
     terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
-    utils::{int_log10, Page},
+    ui::{ErrorMessageRenderConfig, IndexPrefix, Key, RenderConfig, StyleSheet, Styled},
+    utils::{int_log10, wrap_to_width, Page},
     validator::ErrorMessage,
+    PasswordStrength,
 };
 
 use super::{frame_renderer::FrameRenderer, InputReader};
 
+#[cfg(feature = "date")]
+pub mod date;
+pub mod table;
+
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
@@ -22,6 +27,9 @@ pub trait CommonBackend: InputReader {
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()>;
+    fn render_valid_input_indicator(&mut self) -> Result<()>;
+    fn render_spinner(&mut self, frame: usize) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
 }
 
@@ -35,6 +43,15 @@ pub trait TextBackend: CommonBackend {
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
 }
 
+pub trait MultiLineTextBackend: CommonBackend {
+    fn render_multi_line_prompt(&mut self, prompt: &str, default: Option<&str>) -> Result<()>;
+    fn render_multi_line_input(
+        &mut self,
+        page: Page<'_, Input>,
+        placeholder: Option<&str>,
+    ) -> Result<()>;
+}
+
 #[cfg(feature = "editor")]
 pub trait EditorBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()>;
@@ -46,7 +63,12 @@ pub trait SelectBackend: CommonBackend {
 }
 
 pub trait MultiSelectBackend: CommonBackend {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        selection_counter: Option<&str>,
+        cur_input: Option<&Input>,
+    ) -> Result<()>;
     fn render_options<D: Display>(
         &mut self,
         page: Page<'_, ListOption<D>>,
@@ -54,6 +76,24 @@ pub trait MultiSelectBackend: CommonBackend {
     ) -> Result<()>;
 }
 
+pub trait SortBackend: CommonBackend {
+    fn render_sort_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        grabbed: bool,
+        pinned: &BTreeSet<usize>,
+    ) -> Result<()>;
+}
+
+pub trait TreeSelectBackend: CommonBackend {
+    fn render_tree_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
+    fn render_tree_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<TreeRow<D>>>,
+    ) -> Result<()>;
+}
+
 pub trait CustomTypeBackend: CommonBackend {
     fn render_prompt(
         &mut self,
@@ -63,10 +103,68 @@ pub trait CustomTypeBackend: CommonBackend {
     ) -> Result<()>;
 }
 
+pub trait ExpandBackend: CommonBackend {
+    fn render_expand_prompt(&mut self, prompt: &str, keys: &str) -> Result<()>;
+    fn render_expand_choices(&mut self, choices: &[(char, &str)]) -> Result<()>;
+}
+
+pub trait NumberBackend: CommonBackend {
+    fn render_number_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        unit: Option<&str>,
+    ) -> Result<()>;
+}
+
+pub trait SliderBackend: CommonBackend {
+    fn render_slider_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_slider(&mut self, bar: &SliderBar) -> Result<()>;
+}
+
 pub trait PasswordBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_password_strength(&mut self, strength: PasswordStrength) -> Result<()>;
+}
+
+/// Node of a tree as displayed in a list of options.
+#[derive(Clone, Debug)]
+pub struct TreeRow<D> {
+    pub value: D,
+    pub is_root: bool,
+    /// For each ancestor below the root, whether it is followed by siblings.
+    pub guides: Vec<bool>,
+    /// Whether the node is the last among its displayed siblings.
+    pub last: bool,
+    /// Whether the node is expanded, `None` if it has no children.
+    pub expanded: Option<bool>,
+    /// Whether the node is checked, `None` if the prompt does not check nodes.
+    pub checked: Option<bool>,
+}
+
+impl<D> Display for TreeRow<D>
+where
+    D: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// Bar of a slider, with the positions of its values relative to its bounds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderBar {
+    /// Position of each handle, from 0 at the start of the bar to 1 at its end.
+    pub handles: Vec<f64>,
+    /// Index of the focused handle.
+    pub active: usize,
+    /// Formatted value displayed next to the bar.
+    pub label: String,
+    /// Labels displayed under the bar, sorted by their positions.
+    pub ticks: Vec<(f64, String)>,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -127,17 +225,46 @@ where
         option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
     ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
-            match page.cursor {
+        let stylesheet = self.option_style_sheet(option_relative_index, page.cursor);
+
+        self.frame_renderer
+            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
+    }
+
+    fn option_style_sheet(
+        &self,
+        option_relative_index: usize,
+        cursor: Option<usize>,
+    ) -> StyleSheet {
+        if let Some(selected_option_style) = self.render_config.selected_option {
+            match cursor {
                 Some(cursor) if cursor == option_relative_index => selected_option_style,
                 _ => self.render_config.option,
             }
         } else {
             self.render_config.option
+        }
+    }
+
+    fn print_checkbox(
+        &mut self,
+        checked: bool,
+        option_relative_index: usize,
+        cursor: Option<usize>,
+    ) -> Result<()> {
+        let mut checkbox = match checked {
+            true => self.render_config.selected_checkbox,
+            false => self.render_config.unselected_checkbox,
         };
 
-        self.frame_renderer
-            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
+        match (self.render_config.selected_option, cursor) {
+            (Some(stylesheet), Some(cursor)) if cursor == option_relative_index => {
+                checkbox.style = stylesheet;
+            }
+            _ => {}
+        }
+
+        self.frame_renderer.write_styled(checkbox)
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
@@ -180,6 +307,29 @@ where
         Ok(())
     }
 
+    fn print_message(
+        &mut self,
+        config: ErrorMessageRenderConfig<'_>,
+        message: &ErrorMessage,
+    ) -> Result<()> {
+        self.frame_renderer.write_styled(config.prefix)?;
+
+        self.frame_renderer
+            .write_styled(Styled::new(" ").with_style_sheet(config.separator))?;
+
+        let message = match message {
+            ErrorMessage::Default => config.default_message,
+            ErrorMessage::Custom(msg) => msg,
+        };
+
+        self.frame_renderer
+            .write_styled(Styled::new(message).with_style_sheet(config.message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
     fn print_prompt(&mut self, prompt: &str) -> Result<()> {
         self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
     }
@@ -283,22 +433,32 @@ where
     }
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.print_message(self.render_config.error_message, error)
+    }
+
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()> {
+        self.print_message(self.render_config.warning_message, warning)
+    }
+
+    fn render_valid_input_indicator(&mut self) -> Result<()> {
         self.frame_renderer
-            .write_styled(self.render_config.error_message.prefix)?;
+            .write_styled(self.render_config.valid_input_indicator)?;
+        self.new_line()?;
 
-        self.frame_renderer.write_styled(
-            Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
-        )?;
+        Ok(())
+    }
 
-        let message = match error {
-            ErrorMessage::Default => self.render_config.error_message.default_message,
-            ErrorMessage::Custom(msg) => msg,
-        };
+    fn render_spinner(&mut self, frame: usize) -> Result<()> {
+        let spinner = self.render_config.spinner;
 
-        self.frame_renderer.write_styled(
-            Styled::new(message).with_style_sheet(self.render_config.error_message.message),
-        )?;
+        if !spinner.frames.is_empty() {
+            let frame = spinner.frames[frame % spinner.frames.len()];
+            self.frame_renderer
+                .write_styled(Styled::new(frame).with_style_sheet(spinner.frame))?;
+            self.frame_renderer.write(" ")?;
+        }
 
+        self.frame_renderer.write_styled(spinner.message)?;
         self.new_line()?;
 
         Ok(())
@@ -348,6 +508,105 @@ where
     }
 }
 
+impl<'a, I, T> MultiLineTextBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_multi_line_prompt(&mut self, prompt: &str, default: Option<&str>) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.new_line()
+    }
+
+    fn render_multi_line_input(
+        &mut self,
+        page: Page<'_, Input>,
+        placeholder: Option<&str>,
+    ) -> Result<()> {
+        let gutter_width = self
+            .render_config
+            .scroll_up_prefix
+            .content
+            .width()
+            .max(self.render_config.scroll_down_prefix.content.width())
+            + 1;
+
+        // one column is kept free for the cursor when it is at the end of a row.
+        let text_width = self
+            .frame_renderer
+            .terminal_width()
+            .map(|width| (width as usize).saturating_sub(gutter_width + 1).max(1))
+            .unwrap_or(usize::MAX);
+
+        let is_empty = page.total == 1 && page.content.iter().all(Input::is_empty);
+
+        for (idx, line) in page.content.iter().enumerate() {
+            let prefix = if idx == 0 && !page.first {
+                self.render_config.scroll_up_prefix
+            } else if idx + 1 == page.content.len() && !page.last {
+                self.render_config.scroll_down_prefix
+            } else {
+                Styled::new("")
+            };
+            let padding = " ".repeat(gutter_width - prefix.content.width());
+
+            self.frame_renderer.write_styled(prefix)?;
+            self.frame_renderer.write(&padding)?;
+
+            let has_cursor = page.cursor == Some(idx);
+
+            if is_empty {
+                self.frame_renderer.mark_cursor_position(0);
+
+                if let Some(placeholder) = placeholder {
+                    self.frame_renderer.write_styled(
+                        Styled::new(placeholder).with_style_sheet(self.render_config.placeholder),
+                    )?;
+                }
+
+                self.new_line()?;
+                continue;
+            }
+
+            let pre_cursor_len = line.pre_cursor().len();
+            let mut row_start = 0;
+
+            for (row_idx, row) in wrap_to_width(line.content(), text_width)
+                .into_iter()
+                .enumerate()
+            {
+                if row_idx > 0 {
+                    self.frame_renderer.write(" ".repeat(gutter_width))?;
+                }
+
+                let row_end = row_start + row.len();
+                let cursor_in_row = pre_cursor_len >= row_start
+                    && (pre_cursor_len < row_end || row_end == line.content().len());
+
+                if has_cursor && cursor_in_row {
+                    let offset = line.content()[row_start..pre_cursor_len].width();
+                    self.frame_renderer.mark_cursor_position(offset as isize);
+                }
+
+                self.frame_renderer.write_styled(
+                    Styled::new(row).with_style_sheet(self.render_config.text_input),
+                )?;
+                self.new_line()?;
+
+                row_start = row_end;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "editor")]
 impl<'a, I, T> EditorBackend for Backend<'a, I, T>
 where
@@ -407,12 +666,27 @@ where
     I: InputReader,
     T: Terminal,
 {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        selection_counter: Option<&str>,
+        cur_input: Option<&Input>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(counter) = selection_counter {
+            self.frame_renderer.write(" ")?;
+            let content = format!("({counter})");
+            let token = Styled::new(content).with_style_sheet(self.render_config.selection_counter);
+            self.frame_renderer.write_styled(token)?;
+        }
+
         if let Some(input) = cur_input {
-            self.print_prompt_with_input(prompt, None, input)
-        } else {
-            self.print_prompt(prompt)
+            self.print_input(input)?;
+            self.new_line()?;
         }
+
+        Ok(())
     }
 
     fn render_options<D: Display>(
@@ -453,161 +727,115 @@ where
     }
 }
 
-#[cfg(feature = "date")]
-pub mod date {
-    use std::{io::Result, ops::Sub};
-
-    use chrono::{Datelike, Duration};
-
-    use crate::{
-        date_utils::get_start_date,
-        terminal::Terminal,
-        ui::{InputReader, Styled},
-    };
-
-    use super::{Backend, CommonBackend};
-
-    pub trait DateSelectBackend: CommonBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
-
-        #[allow(clippy::too_many_arguments)]
-        fn render_calendar(
-            &mut self,
-            month: chrono::Month,
-            year: i32,
-            week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
-        ) -> Result<()>;
-    }
-
-    impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
-    where
-        I: InputReader,
-        T: Terminal,
-    {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
-            self.print_prompt(prompt)?;
-            self.new_line()?;
-            Ok(())
-        }
+impl<'a, I, T> SortBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_sort_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
 
-        fn render_calendar(
-            &mut self,
-            month: chrono::Month,
-            year: i32,
-            week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
-        ) -> Result<()> {
-            macro_rules! write_prefix {
-                () => {{
-                    self.frame_renderer
-                        .write_styled(self.render_config.calendar.prefix)?;
-                    self.frame_renderer.write(" ")
-                }};
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        grabbed: bool,
+        pinned: &BTreeSet<usize>,
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            match page.cursor {
+                Some(cursor) if cursor == idx && grabbed => self
+                    .frame_renderer
+                    .write_styled(self.render_config.grabbed_option_prefix)?,
+                _ => self.print_option_prefix(idx, &page)?,
             }
 
-            // print header (month year)
-            let header = format!("{} {}", month.name().to_lowercase(), year);
-            let header = format!("{header:^20}");
-            let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
-
-            write_prefix!()?;
-
-            self.frame_renderer.write_styled(header)?;
-
-            self.new_line()?;
-
-            // print week header
-            let mut current_weekday = week_start;
-            let mut week_days: Vec<String> = vec![];
-            for _ in 0..7 {
-                let mut formatted = format!("{current_weekday}");
-                formatted.make_ascii_lowercase();
-                formatted.pop();
-                week_days.push(formatted);
+            self.frame_renderer.write(" ")?;
 
-                current_weekday = current_weekday.succ();
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.frame_renderer.write(" ")?;
             }
 
-            let week_days = Styled::new(week_days.join(" "))
-                .with_style_sheet(self.render_config.calendar.week_header);
-
-            write_prefix!()?;
-
-            self.frame_renderer.write_styled(week_days)?;
-            self.new_line()?;
+            self.print_option_value(idx, option, &page)?;
 
-            // print dates
-            let mut date_it = get_start_date(month, year);
-            // first date of week-line is possibly in the previous month
-            if date_it.weekday() == week_start {
-                date_it = date_it.sub(
-                    Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"),
-                );
-            } else {
-                while date_it.weekday() != week_start {
-                    date_it = match date_it.pred_opt() {
-                        Some(date) => date,
-                        None => break,
-                    };
-                }
+            if pinned.contains(&option.index) {
+                self.frame_renderer.write(" ")?;
+                self.frame_renderer
+                    .write_styled(self.render_config.pinned_option_indicator)?;
             }
 
-            for _ in 0..6 {
-                write_prefix!()?;
+            self.new_line()?;
+        }
 
-                for i in 0..7 {
-                    if i > 0 {
-                        self.frame_renderer.write(" ")?;
-                    }
+        Ok(())
+    }
+}
 
-                    let date = format!("{:2}", date_it.day());
+impl<'a, I, T> TreeSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_tree_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+        if let Some(input) = cur_input {
+            self.print_prompt_with_input(prompt, None, input)
+        } else {
+            self.print_prompt(prompt)?;
+            self.new_line()
+        }
+    }
 
-                    let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
+    fn render_tree_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<TreeRow<D>>>,
+    ) -> Result<()> {
+        let config = self.render_config.tree;
 
-                    let mut style_sheet = crate::ui::StyleSheet::empty();
+        for (idx, option) in page.content.iter().enumerate() {
+            let row = &option.value;
 
-                    if date_it == selected_date {
-                        self.frame_renderer.mark_cursor_position(cursor_offset);
-                        if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
-                        {
-                            style_sheet = custom_style_sheet;
-                        }
-                    } else if date_it == today {
-                        style_sheet = self.render_config.calendar.today_date;
-                    } else if date_it.month() != month.number_from_month() {
-                        style_sheet = self.render_config.calendar.different_month_date;
-                    }
+            self.print_option_prefix(idx, &page)?;
 
-                    if let Some(min_date) = min_date {
-                        if date_it < min_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
-                        }
-                    }
+            self.frame_renderer.write(" ")?;
 
-                    if let Some(max_date) = max_date {
-                        if date_it > max_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
-                        }
-                    }
+            if !row.is_root {
+                let mut guides = String::new();
+                for continues in &row.guides {
+                    guides.push_str(match continues {
+                        true => config.vertical_guide,
+                        false => config.blank_guide,
+                    });
+                }
+                guides.push_str(match row.last {
+                    true => config.last_branch_guide,
+                    false => config.branch_guide,
+                });
 
-                    let token = Styled::new(date).with_style_sheet(style_sheet);
-                    self.frame_renderer.write_styled(token)?;
+                self.frame_renderer
+                    .write_styled(Styled::new(guides).with_style_sheet(config.guides))?;
+            }
 
-                    date_it = date_it.succ_opt().unwrap_or(date_it);
-                }
+            let indicator = match row.expanded {
+                Some(true) => config.expanded_indicator,
+                Some(false) => config.collapsed_indicator,
+                None => config.leaf_indicator,
+            };
+            self.frame_renderer.write_styled(indicator)?;
+            self.frame_renderer.write(" ")?;
 
-                self.new_line()?;
+            if let Some(checked) = row.checked {
+                self.print_checkbox(checked, idx, page.cursor)?;
+                self.frame_renderer.write(" ")?;
             }
 
-            Ok(())
+            self.print_option_value(idx, option, &page)?;
+
+            self.new_line()?;
         }
+
+        Ok(())
     }
 }
 
@@ -626,55 +854,283 @@ where
     }
 }
 
-impl<'a, I, T> PasswordBackend for Backend<'a, I, T>
+impl<'a, I, T> ExpandBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(&mut self, prompt: &str) -> Result<()> {
+    fn render_expand_prompt(&mut self, prompt: &str, keys: &str) -> Result<()> {
         self.print_prompt(prompt)?;
-        self.new_line()?;
-        Ok(())
-    }
-
-    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        let masked_string: String = (0..cur_input.length())
-            .map(|_| self.render_config.password_mask)
-            .collect();
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer.write_styled(
+            Styled::new(format!("[{keys}]")).with_style_sheet(self.render_config.default_value),
+        )?;
 
-        let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
+        // the cursor waits for the key after the choices, followed by a space so
+        // that it does not render on the next line.
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer.mark_cursor_position(0);
+        self.frame_renderer.write(" ")?;
 
-        self.print_prompt_with_input(prompt, None, &masked_input)
+        self.new_line()
     }
 
-    fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+    fn render_expand_choices(&mut self, choices: &[(char, &str)]) -> Result<()> {
+        for (key, label) in choices {
+            self.frame_renderer.write("  ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(format!("{key} - {label}")).with_style_sheet(self.render_config.option),
+            )?;
+            self.new_line()?;
+        }
+
+        Ok(())
     }
 }
 
-impl<'a, I, T> InputReader for Backend<'a, I, T>
+impl<'a, I, T> NumberBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn read_key(&mut self) -> InquireResult<Key> {
-        self.input_reader.read_key()
-    }
-}
+    fn render_number_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        unit: Option<&str>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.print_input(cur_input)?;
+
+        if let Some(unit) = unit {
+            // print_input already leaves a space after the input when the
+            // cursor is at its end.
+            if cur_input.cursor() != cur_input.length() {
+                self.frame_renderer.write(" ")?;
+            }
+            self.frame_renderer
+                .write_styled(Styled::new(unit).with_style_sheet(self.render_config.text_input))?;
+        }
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+/// Bounds of the width of slider bars, which otherwise fill the terminal.
+const SLIDER_MIN_WIDTH: usize = 10;
+const SLIDER_MAX_WIDTH: usize = 60;
+
+/// Column of the bar of the given width at which the position, from 0 to 1, is displayed.
+fn slider_column(position: f64, width: usize) -> usize {
+    (position.clamp(0.0, 1.0) * (width - 1) as f64).round() as usize
+}
+
+impl<'a, I, T> SliderBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_slider_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_slider(&mut self, bar: &SliderBar) -> Result<()> {
+        let config = self.render_config.slider;
+
+        // the bar is indented by two columns and followed by a space, the label
+        // and one column kept free for the cursor.
+        let width = self
+            .frame_renderer
+            .terminal_width()
+            .map(|width| (width as usize).saturating_sub(bar.label.width() + 4))
+            .unwrap_or(SLIDER_MAX_WIDTH)
+            .clamp(SLIDER_MIN_WIDTH, SLIDER_MAX_WIDTH);
+
+        let handles: Vec<usize> = bar
+            .handles
+            .iter()
+            .map(|position| slider_column(*position, width))
+            .collect();
+        let (filled_start, filled_end) = match handles[..] {
+            [handle] => (0, handle),
+            [low, high] => (low, high),
+            _ => (0, 0),
+        };
+
+        self.frame_renderer.write("  ")?;
+
+        let mut run: Option<(Styled<&str>, String)> = None;
+        for column in 0..width {
+            let segment = match handles.iter().position(|handle| *handle == column) {
+                Some(idx) if idx == bar.active => {
+                    if let Some((segment, content)) = run.take() {
+                        self.frame_renderer
+                            .write_styled(Styled::new(content).with_style_sheet(segment.style))?;
+                    }
+                    self.frame_renderer.mark_cursor_position(0);
+                    config.handle
+                }
+                Some(_) => config.inactive_handle,
+                None if (filled_start..filled_end).contains(&column) => config.filled_track,
+                None => config.track,
+            };
+
+            match &mut run {
+                Some((current, content))
+                    if current.content == segment.content && current.style == segment.style =>
+                {
+                    content.push_str(segment.content);
+                }
+                _ => {
+                    if let Some((segment, content)) = run.take() {
+                        self.frame_renderer
+                            .write_styled(Styled::new(content).with_style_sheet(segment.style))?;
+                    }
+                    run = Some((segment, String::from(segment.content)));
+                }
+            }
+        }
+        if let Some((segment, content)) = run {
+            self.frame_renderer
+                .write_styled(Styled::new(content).with_style_sheet(segment.style))?;
+        }
+
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(bar.label.as_str()).with_style_sheet(config.value))?;
+        self.new_line()?;
+
+        if bar.ticks.is_empty() {
+            return Ok(());
+        }
+
+        // labels are centered under their position, skipping the ones that
+        // would overlap the previous label.
+        let mut line = String::new();
+        let mut line_width = 0;
+        for (position, label) in &bar.ticks {
+            let label_width = label.width();
+            let start = slider_column(*position, width)
+                .saturating_sub(label_width / 2)
+                .min(width.saturating_sub(label_width));
+
+            if line_width > 0 && start <= line_width {
+                continue;
+            }
+
+            line.push_str(&" ".repeat(start - line_width));
+            line.push_str(label);
+            line_width = start + label_width;
+        }
+
+        self.frame_renderer.write("  ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(line).with_style_sheet(config.tick_label))?;
+        self.new_line()
+    }
+}
+
+impl<'a, I, T> PasswordBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()?;
+        Ok(())
+    }
+
+    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        let masked_string: String = (0..cur_input.length())
+            .map(|_| self.render_config.password_mask)
+            .collect();
+
+        let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
+
+        self.print_prompt_with_input(prompt, None, &masked_input)
+    }
+
+    fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.print_prompt_with_input(prompt, None, cur_input)
+    }
+
+    fn render_password_strength(&mut self, strength: PasswordStrength) -> Result<()> {
+        let config = self.render_config.password_strength;
+        let filled = strength.level();
+
+        let mut meter = config.filled_segment.repeat(filled);
+        meter.push_str(
+            &config
+                .empty_segment
+                .repeat(PasswordStrength::LEVELS - filled),
+        );
+        meter.push_str(&format!(" {strength}"));
+
+        self.frame_renderer
+            .write_styled(Styled::new(meter).with_style_sheet(config.style(strength)))?;
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> InputReader for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn read_key(&mut self) -> InquireResult<Key> {
+        self.input_reader.read_key()
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        self.input_reader.read_key_timeout(timeout)
+    }
+
+    fn supports_timeout(&self) -> bool {
+        self.input_reader.supports_timeout()
+    }
+}
 
 #[cfg(test)]
 pub(crate) mod test {
-    use std::collections::VecDeque;
+    use std::{
+        collections::{BTreeSet, VecDeque},
+        fmt::Display,
+    };
 
     use chrono::{Month, NaiveDate, Weekday};
 
     use crate::{
         input::Input,
+        list_option::ListOption,
         ui::{InputReader, Key},
+        utils::Page,
         validator::ErrorMessage,
+        PasswordStrength, SortOrder,
     };
 
-    use super::{CommonBackend, CustomTypeBackend};
+    #[cfg(feature = "date")]
+    use crate::{date_utils::TimeField, ClockFormat};
+    #[cfg(feature = "date")]
+    use chrono::NaiveTime;
+
+    use super::{
+        CommonBackend, CustomTypeBackend, ExpandBackend, MultiLineTextBackend, NumberBackend,
+        PasswordBackend, SelectBackend, SliderBackend, SliderBar, SortBackend, TextBackend,
+        TreeRow, TreeSelectBackend,
+    };
 
     #[derive(Debug, Clone, PartialEq)]
     pub enum Token {
@@ -684,19 +1140,82 @@ pub(crate) mod test {
         CanceledPrompt(String),
         AnsweredPrompt(String, String),
         ErrorMessage(ErrorMessage),
+        WarningMessage(ErrorMessage),
+        ValidInputIndicator,
+        Spinner,
         HelpMessage(String),
+        #[cfg_attr(not(feature = "date"), allow(dead_code))]
         Calendar {
             month: Month,
+            /// Name of the month in the locale of the calendar.
+            month_name: String,
             year: i32,
             week_start: Weekday,
+            /// Whether the column of week numbers is displayed.
+            week_numbers: bool,
             today: NaiveDate,
             selected_date: NaiveDate,
+            range: Option<(NaiveDate, NaiveDate)>,
+            toggled_dates: Vec<NaiveDate>,
             min_date: Option<NaiveDate>,
             max_date: Option<NaiveDate>,
+            /// Disabled dates of the displayed month.
+            disabled_dates: Vec<NaiveDate>,
+        },
+        #[cfg(feature = "date")]
+        Time {
+            time: NaiveTime,
+            focused: Option<TimeField>,
+            clock_format: ClockFormat,
+            with_seconds: bool,
+        },
+        MultiLineInput {
+            lines: Vec<String>,
+            /// Line and grapheme column of the cursor, relative to the displayed lines.
+            cursor: Option<(usize, usize)>,
+            first: bool,
+            last: bool,
         },
+        Options {
+            options: Vec<String>,
+            cursor: Option<usize>,
+        },
+        SortOptions {
+            options: Vec<String>,
+            cursor: Option<usize>,
+            grabbed: bool,
+            pinned: Vec<String>,
+        },
+        TreeOptions {
+            rows: Vec<TreeRowToken>,
+            cursor: Option<usize>,
+        },
+        TableOptions {
+            headers: Vec<String>,
+            sort: Option<(usize, SortOrder)>,
+            rows: Vec<Vec<String>>,
+            cursor: Option<usize>,
+            /// Whether each row is checked, empty if the prompt does not check rows.
+            checked: Vec<bool>,
+        },
+        PasswordStrength(PasswordStrength),
+        Suggestions(Vec<String>),
+        Unit(String),
+        Slider(SliderBar),
+        ExpandKeys(String),
+        ExpandChoices(Vec<(char, String)>),
         PromptEnd,
     }
 
+    /// Displayed tree node, with its depth instead of its indentation guides.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TreeRowToken {
+        pub value: String,
+        pub depth: usize,
+        pub expanded: Option<bool>,
+        pub checked: Option<bool>,
+    }
+
     #[derive(Default, Debug, Clone)]
     pub struct Frame {
         content: Vec<Token>,
@@ -717,6 +1236,12 @@ pub(crate) mod test {
         pub input: VecDeque<Key>,
         pub frames: Vec<Frame>,
         pub cur_frame: Option<Frame>,
+        /// Amounts of read keys after which a read with timeout times out once.
+        pub pauses: BTreeSet<usize>,
+        pub keys_read: usize,
+        /// Whether reads with timeout block until a key is pressed, like readers
+        /// that can not wait for a limited amount of time.
+        pub blocking: bool,
     }
 
     impl FakeBackend {
@@ -725,9 +1250,25 @@ pub(crate) mod test {
                 input: input.into(),
                 frames: vec![],
                 cur_frame: None,
+                pauses: BTreeSet::new(),
+                keys_read: 0,
+                blocking: false,
             }
         }
 
+        /// Makes reads with timeout block until a key is pressed.
+        pub fn without_timeouts(mut self) -> Self {
+            self.blocking = true;
+            self
+        }
+
+        /// Makes the next read with timeout after the first `keys` keys time out,
+        /// as if the user stopped typing.
+        pub fn with_pause_after(mut self, keys: usize) -> Self {
+            self.pauses.insert(keys);
+            self
+        }
+
         fn push_token(&mut self, token: Token) {
             if let Some(frame) = self.cur_frame.as_mut() {
                 frame.content.push(token);
@@ -742,12 +1283,34 @@ pub(crate) mod test {
 
     impl InputReader for FakeBackend {
         fn read_key(&mut self) -> crate::error::InquireResult<Key> {
-            self.input
+            let key = self
+                .input
                 .pop_front()
                 .ok_or(crate::error::InquireError::IO(std::io::Error::new(
                     std::io::ErrorKind::UnexpectedEof,
                     "No more keys in input",
-                )))
+                )))?;
+            self.keys_read += 1;
+
+            Ok(key)
+        }
+
+        fn read_key_timeout(
+            &mut self,
+            timeout: std::time::Duration,
+        ) -> crate::error::InquireResult<Option<Key>> {
+            if !self.blocking && self.pauses.remove(&self.keys_read) {
+                // Gives work running in the background, such as background validators,
+                // time to complete, without waiting for long timeouts.
+                std::thread::sleep(timeout.min(std::time::Duration::from_millis(100)));
+                return Ok(None);
+            }
+
+            self.read_key().map(Some)
+        }
+
+        fn supports_timeout(&self) -> bool {
+            !self.blocking
         }
     }
 
@@ -788,6 +1351,21 @@ pub(crate) mod test {
             Ok(())
         }
 
+        fn render_warning_message(&mut self, warning: &ErrorMessage) -> std::io::Result<()> {
+            self.push_token(Token::WarningMessage(warning.clone()));
+            Ok(())
+        }
+
+        fn render_valid_input_indicator(&mut self) -> std::io::Result<()> {
+            self.push_token(Token::ValidInputIndicator);
+            Ok(())
+        }
+
+        fn render_spinner(&mut self, _frame: usize) -> std::io::Result<()> {
+            self.push_token(Token::Spinner);
+            Ok(())
+        }
+
         fn render_help_message(&mut self, help: &str) -> std::io::Result<()> {
             self.push_token(Token::HelpMessage(help.to_string()));
             Ok(())
@@ -796,34 +1374,175 @@ pub(crate) mod test {
 
     #[cfg(feature = "date")]
     impl crate::ui::date::DateSelectBackend for FakeBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+        fn render_calendar_prompt(
+            &mut self,
+            prompt: &str,
+            input: Option<&Input>,
+        ) -> std::io::Result<()> {
             self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = input {
+                self.push_token(Token::Input(input.clone()));
+            }
             Ok(())
         }
 
         fn render_calendar(
             &mut self,
-            month: Month,
-            year: i32,
-            week_start: Weekday,
-            today: NaiveDate,
-            selected_date: NaiveDate,
-            min_date: Option<NaiveDate>,
-            max_date: Option<NaiveDate>,
+            view: crate::ui::date::CalendarView<'_>,
         ) -> std::io::Result<()> {
+            let month = view.month;
+            let year = view.year;
+            let disabled_dates = match view.disabled_dates {
+                Some(is_disabled) => (1..=31)
+                    .filter_map(|day| NaiveDate::from_ymd_opt(year, month.number_from_month(), day))
+                    .filter(|date| is_disabled(*date))
+                    .collect(),
+                None => vec![],
+            };
+
             self.push_token(Token::Calendar {
                 month,
+                month_name: view.locale.month_name(month).to_string(),
                 year,
-                week_start,
-                today,
-                selected_date,
-                min_date,
-                max_date,
+                week_start: view.week_start,
+                week_numbers: view.show_week_numbers,
+                today: view.today,
+                selected_date: view.selected_date,
+                range: view.range,
+                toggled_dates: view.toggled_dates.to_vec(),
+                min_date: view.min_date,
+                max_date: view.max_date,
+                disabled_dates,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl crate::ui::date::TimeSelectBackend for FakeBackend {
+        fn render_time_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_time(
+            &mut self,
+            time: NaiveTime,
+            fields: &[TimeField],
+            focused: Option<TimeField>,
+            clock_format: ClockFormat,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Time {
+                time,
+                focused,
+                clock_format,
+                with_seconds: fields.contains(&TimeField::Second),
+            });
+            Ok(())
+        }
+    }
+
+    impl PasswordBackend for FakeBackend {
+        fn render_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_prompt_with_masked_input(
+            &mut self,
+            prompt: &str,
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            let masked = Input::new_with("*".repeat(cur_input.length()));
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Input(masked));
+            Ok(())
+        }
+
+        fn render_prompt_with_full_input(
+            &mut self,
+            prompt: &str,
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
+
+        fn render_password_strength(&mut self, strength: PasswordStrength) -> std::io::Result<()> {
+            self.push_token(Token::PasswordStrength(strength));
+            Ok(())
+        }
+    }
+
+    impl MultiLineTextBackend for FakeBackend {
+        fn render_multi_line_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            Ok(())
+        }
+
+        fn render_multi_line_input(
+            &mut self,
+            page: Page<'_, Input>,
+            _placeholder: Option<&str>,
+        ) -> std::io::Result<()> {
+            let lines = page
+                .content
+                .iter()
+                .map(|line| line.content().to_string())
+                .collect();
+            let cursor = page.cursor.map(|idx| (idx, page.content[idx].cursor()));
+
+            self.push_token(Token::MultiLineInput {
+                lines,
+                cursor,
+                first: page.first,
+                last: page.last,
             });
             Ok(())
         }
     }
 
+    #[cfg(feature = "editor")]
+    impl super::EditorBackend for FakeBackend {
+        fn render_prompt(&mut self, prompt: &str, _editor_command: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+    }
+
+    impl TextBackend for FakeBackend {
+        fn render_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            self.push_token(Token::Input(cur_input.clone()));
+            Ok(())
+        }
+
+        fn render_suggestions<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+        ) -> std::io::Result<()> {
+            let suggestions = page.content.iter().map(ToString::to_string).collect();
+            self.push_token(Token::Suggestions(suggestions));
+            Ok(())
+        }
+    }
+
     impl CustomTypeBackend for FakeBackend {
         fn render_prompt(
             &mut self,
@@ -839,4 +1558,189 @@ pub(crate) mod test {
             Ok(())
         }
     }
+
+    impl ExpandBackend for FakeBackend {
+        fn render_expand_prompt(&mut self, prompt: &str, keys: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            self.push_token(Token::ExpandKeys(keys.to_string()));
+            Ok(())
+        }
+
+        fn render_expand_choices(&mut self, choices: &[(char, &str)]) -> std::io::Result<()> {
+            let choices = choices
+                .iter()
+                .map(|(key, label)| (*key, String::from(*label)))
+                .collect();
+            self.push_token(Token::ExpandChoices(choices));
+            Ok(())
+        }
+    }
+
+    impl NumberBackend for FakeBackend {
+        fn render_number_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+            unit: Option<&str>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(default) = default {
+                self.push_token(Token::DefaultValue(default.to_string()));
+            }
+            self.push_token(Token::Input(cur_input.clone()));
+            if let Some(unit) = unit {
+                self.push_token(Token::Unit(unit.to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    impl SliderBackend for FakeBackend {
+        fn render_slider_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_slider(&mut self, bar: &SliderBar) -> std::io::Result<()> {
+            self.push_token(Token::Slider(bar.clone()));
+            Ok(())
+        }
+    }
+
+    impl SelectBackend for FakeBackend {
+        fn render_select_prompt(
+            &mut self,
+            prompt: &str,
+            cur_input: Option<&Input>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = cur_input {
+                self.push_token(Token::Input(input.clone()));
+            }
+            Ok(())
+        }
+
+        fn render_options<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+        ) -> std::io::Result<()> {
+            let options = page.content.iter().map(|o| o.value.to_string()).collect();
+            self.push_token(Token::Options {
+                options,
+                cursor: page.cursor,
+            });
+            Ok(())
+        }
+    }
+
+    impl SortBackend for FakeBackend {
+        fn render_sort_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_options<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<D>>,
+            grabbed: bool,
+            pinned: &BTreeSet<usize>,
+        ) -> std::io::Result<()> {
+            let options = page.content.iter().map(|o| o.value.to_string()).collect();
+            let pinned = page
+                .content
+                .iter()
+                .filter(|o| pinned.contains(&o.index))
+                .map(|o| o.value.to_string())
+                .collect();
+
+            self.push_token(Token::SortOptions {
+                options,
+                cursor: page.cursor,
+                grabbed,
+                pinned,
+            });
+            Ok(())
+        }
+    }
+
+    impl TreeSelectBackend for FakeBackend {
+        fn render_tree_select_prompt(
+            &mut self,
+            prompt: &str,
+            cur_input: Option<&Input>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = cur_input {
+                self.push_token(Token::Input(input.clone()));
+            }
+            Ok(())
+        }
+
+        fn render_tree_options<D: Display>(
+            &mut self,
+            page: Page<'_, ListOption<TreeRow<D>>>,
+        ) -> std::io::Result<()> {
+            let rows = page
+                .content
+                .iter()
+                .map(|o| TreeRowToken {
+                    value: o.value.to_string(),
+                    depth: match o.value.is_root {
+                        true => 0,
+                        false => o.value.guides.len() + 1,
+                    },
+                    expanded: o.value.expanded,
+                    checked: o.value.checked,
+                })
+                .collect();
+
+            self.push_token(Token::TreeOptions {
+                rows,
+                cursor: page.cursor,
+            });
+            Ok(())
+        }
+    }
+
+    impl crate::ui::table::TableSelectBackend for FakeBackend {
+        fn render_table_select_prompt(
+            &mut self,
+            prompt: &str,
+            cur_input: Option<&Input>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = cur_input {
+                self.push_token(Token::Input(input.clone()));
+            }
+            Ok(())
+        }
+
+        fn render_table_options(
+            &mut self,
+            layout: &crate::ui::table::TableLayout<'_>,
+            page: Page<'_, ListOption<crate::ui::table::TableRow<'_>>>,
+        ) -> std::io::Result<()> {
+            let headers = layout.headers.iter().map(|h| (*h).to_owned()).collect();
+            let rows = page
+                .content
+                .iter()
+                .map(|o| o.value.cells.to_vec())
+                .collect();
+            let checked = page
+                .content
+                .iter()
+                .filter_map(|o| o.value.checked)
+                .collect();
+
+            self.push_token(Token::TableOptions {
+                headers,
+                sort: layout.sort,
+                rows,
+                cursor: page.cursor,
+                checked,
+            });
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,284 @@
+//! Rendering of the calendar and time fields displayed by the
+//! [`DateSelect`](crate::DateSelect), [`TimeSelect`](crate::TimeSelect) and
+//! [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+
+use std::{io::Result, ops::Sub};
+
+use chrono::{Datelike, Days, Duration};
+
+use crate::{
+    date_utils::{format_time_field, get_start_date, TimeField},
+    input::Input,
+    terminal::Terminal,
+    type_aliases::{DatePredicate, DateStyler},
+    ui::{InputReader, StyleSheet, Styled},
+    CalendarLocale, ClockFormat,
+};
+
+use super::{Backend, CommonBackend};
+
+pub trait DateSelectBackend: CommonBackend {
+    fn render_calendar_prompt(&mut self, prompt: &str, input: Option<&Input>) -> Result<()>;
+
+    fn render_calendar(&mut self, view: CalendarView<'_>) -> Result<()>;
+}
+
+/// Month displayed by a calendar and the dates highlighted in it.
+pub struct CalendarView<'a> {
+    pub month: chrono::Month,
+    pub year: i32,
+    pub week_start: chrono::Weekday,
+    pub locale: &'a CalendarLocale<'a>,
+    pub show_week_numbers: bool,
+    pub today: chrono::NaiveDate,
+    pub selected_date: chrono::NaiveDate,
+    /// Range being selected, highlighted from its start to its end.
+    pub range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    pub toggled_dates: &'a [chrono::NaiveDate],
+    pub min_date: Option<chrono::NaiveDate>,
+    pub max_date: Option<chrono::NaiveDate>,
+    pub disabled_dates: Option<DatePredicate<'a>>,
+    pub date_styler: Option<DateStyler<'a>>,
+}
+
+pub trait TimeSelectBackend: CommonBackend {
+    fn render_time_prompt(&mut self, prompt: &str) -> Result<()>;
+
+    /// Renders the given fields of the time in a single line, highlighting
+    /// the focused one, if any.
+    fn render_time(
+        &mut self,
+        time: chrono::NaiveTime,
+        fields: &[TimeField],
+        focused: Option<TimeField>,
+        clock_format: ClockFormat,
+    ) -> Result<()>;
+}
+
+impl<'a, I, T> TimeSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_time_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()?;
+        Ok(())
+    }
+
+    fn render_time(
+        &mut self,
+        time: chrono::NaiveTime,
+        fields: &[TimeField],
+        focused: Option<TimeField>,
+        clock_format: ClockFormat,
+    ) -> Result<()> {
+        self.frame_renderer
+            .write_styled(self.render_config.calendar.prefix)?;
+        self.frame_renderer.write(" ")?;
+
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                let separator = match field {
+                    TimeField::Period => " ",
+                    _ => ":",
+                };
+                self.frame_renderer.write(separator)?;
+            }
+
+            let mut style_sheet = StyleSheet::empty();
+            if focused == Some(*field) {
+                self.frame_renderer.mark_cursor_position(0);
+                if let Some(custom_style_sheet) = self.render_config.calendar.selected_date {
+                    style_sheet = custom_style_sheet;
+                }
+            }
+
+            let value = format_time_field(time, *field, clock_format);
+            self.frame_renderer
+                .write_styled(Styled::new(value).with_style_sheet(style_sheet))?;
+        }
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_calendar_prompt(&mut self, prompt: &str, input: Option<&Input>) -> Result<()> {
+        if let Some(input) = input {
+            return self.print_prompt_with_input(prompt, None, input);
+        }
+
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_calendar(&mut self, view: CalendarView<'_>) -> Result<()> {
+        let CalendarView {
+            month,
+            year,
+            week_start,
+            locale,
+            show_week_numbers,
+            today,
+            selected_date,
+            range,
+            toggled_dates,
+            min_date,
+            max_date,
+            disabled_dates,
+            date_styler,
+        } = view;
+
+        macro_rules! write_prefix {
+            () => {{
+                self.frame_renderer
+                    .write_styled(self.render_config.calendar.prefix)?;
+                self.frame_renderer.write(" ")
+            }};
+        }
+
+        // blank space above the week numbers column
+        macro_rules! write_week_number_padding {
+            () => {{
+                if show_week_numbers {
+                    self.frame_renderer.write("   ")?;
+                }
+            }};
+        }
+
+        // print header (month year)
+        let header = format!("{} {}", locale.month_name(month), year);
+        let header = format!("{header:^20}");
+        let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
+
+        write_prefix!()?;
+        write_week_number_padding!();
+
+        self.frame_renderer.write_styled(header)?;
+
+        self.new_line()?;
+
+        // print week header
+        let mut current_weekday = week_start;
+        let mut week_days: Vec<&str> = vec![];
+        for _ in 0..7 {
+            week_days.push(locale.weekday_abbreviation(current_weekday));
+            current_weekday = current_weekday.succ();
+        }
+
+        let week_days = Styled::new(week_days.join(" "))
+            .with_style_sheet(self.render_config.calendar.week_header);
+
+        write_prefix!()?;
+        write_week_number_padding!();
+
+        self.frame_renderer.write_styled(week_days)?;
+        self.new_line()?;
+
+        // print dates
+        let mut date_it = get_start_date(month, year);
+        // first date of week-line is possibly in the previous month
+        if date_it.weekday() == week_start {
+            date_it = date_it
+                .sub(Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"));
+        } else {
+            while date_it.weekday() != week_start {
+                date_it = match date_it.pred_opt() {
+                    Some(date) => date,
+                    None => break,
+                };
+            }
+        }
+
+        for _ in 0..6 {
+            write_prefix!()?;
+
+            if show_week_numbers {
+                // the middle day of the row holds the week most of its days belong to
+                let week = date_it
+                    .checked_add_days(Days::new(3))
+                    .unwrap_or(date_it)
+                    .iso_week()
+                    .week();
+                let week = Styled::new(format!("{week:2}"))
+                    .with_style_sheet(self.render_config.calendar.week_number);
+                self.frame_renderer.write_styled(week)?;
+                self.frame_renderer.write(" ")?;
+            }
+
+            for i in 0..7 {
+                if i > 0 {
+                    self.frame_renderer.write(" ")?;
+                }
+
+                let date = format!("{:2}", date_it.day());
+
+                let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
+
+                let mut style_sheet = StyleSheet::empty();
+
+                if date_it == selected_date {
+                    self.frame_renderer.mark_cursor_position(cursor_offset);
+                    if let Some(custom_style_sheet) = self.render_config.calendar.selected_date {
+                        style_sheet = custom_style_sheet;
+                    }
+                } else if range
+                    .map(|(start, end)| start <= date_it && date_it <= end)
+                    .unwrap_or(false)
+                {
+                    style_sheet = self.render_config.calendar.range_date;
+                } else if date_it == today {
+                    style_sheet = self.render_config.calendar.today_date;
+                } else if date_it.month() != month.number_from_month() {
+                    style_sheet = self.render_config.calendar.different_month_date;
+                }
+
+                if date_it != selected_date {
+                    if let Some(custom_style_sheet) = date_styler.and_then(|styler| styler(date_it))
+                    {
+                        style_sheet = custom_style_sheet;
+                    }
+
+                    if disabled_dates
+                        .map(|is_disabled| is_disabled(date_it))
+                        .unwrap_or(false)
+                    {
+                        style_sheet = self.render_config.calendar.unavailable_date;
+                    }
+
+                    if toggled_dates.contains(&date_it) {
+                        style_sheet = self.render_config.calendar.toggled_date;
+                    }
+                }
+
+                if let Some(min_date) = min_date {
+                    if date_it < min_date {
+                        style_sheet = self.render_config.calendar.unavailable_date;
+                    }
+                }
+
+                if let Some(max_date) = max_date {
+                    if date_it > max_date {
+                        style_sheet = self.render_config.calendar.unavailable_date;
+                    }
+                }
+
+                let token = Styled::new(date).with_style_sheet(style_sheet);
+                self.frame_renderer.write_styled(token)?;
+
+                date_it = date_it.succ_opt().unwrap_or(date_it);
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! Rendering of the tables displayed by the [`TableSelect`](crate::TableSelect)
+//! and [`MultiTableSelect`](crate::MultiTableSelect) prompts.
+
+use std::{fmt::Display, io::Result};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    input::Input,
+    list_option::ListOption,
+    terminal::Terminal,
+    ui::{InputReader, Styled},
+    utils::{fit_column_widths, fit_to_width, Page},
+    SortOrder,
+};
+
+use super::{Backend, CommonBackend};
+
+pub trait TableSelectBackend: CommonBackend {
+    fn render_table_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>)
+        -> Result<()>;
+    fn render_table_options(
+        &mut self,
+        layout: &TableLayout<'_>,
+        page: Page<'_, ListOption<TableRow<'_>>>,
+    ) -> Result<()>;
+}
+
+/// Columns of a table, shared by all of its rows.
+#[derive(Clone, Debug)]
+pub struct TableLayout<'a> {
+    pub headers: Vec<&'a str>,
+    /// Width of the widest cell of each column, header included.
+    pub widths: Vec<usize>,
+    /// Column the rows are sorted by, if any.
+    pub sort: Option<(usize, SortOrder)>,
+}
+
+/// Row of a table as displayed in a list of options.
+#[derive(Clone, Debug)]
+pub struct TableRow<'a> {
+    pub cells: &'a [String],
+    /// Whether the row is checked, `None` if the prompt does not check rows.
+    pub checked: Option<bool>,
+}
+
+impl<'a> Display for TableRow<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cells.join("  ").fmt(f)
+    }
+}
+
+impl<'a, I, T> TableSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_table_select_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+    ) -> Result<()> {
+        if let Some(input) = cur_input {
+            self.print_prompt_with_input(prompt, None, input)
+        } else {
+            self.print_prompt(prompt)?;
+            self.new_line()
+        }
+    }
+
+    fn render_table_options(
+        &mut self,
+        layout: &TableLayout<'_>,
+        page: Page<'_, ListOption<TableRow<'_>>>,
+    ) -> Result<()> {
+        let config = self.render_config.table;
+
+        let sort_indicator = layout.sort.map(|(column, order)| {
+            let indicator = match order {
+                SortOrder::Ascending => config.ascending_indicator,
+                SortOrder::Descending => config.descending_indicator,
+            };
+            (column, indicator)
+        });
+
+        let mut widths = layout.widths.clone();
+        if let Some((column, indicator)) = sort_indicator {
+            let header_width = layout.headers[column].width() + 1 + indicator.content.width();
+            widths[column] = widths[column].max(header_width);
+        }
+
+        let checkable = page
+            .content
+            .iter()
+            .any(|option| option.value.checked.is_some());
+        let mut prefix_width = self.render_config.highlighted_option_prefix.content.width() + 1;
+        if checkable {
+            prefix_width += self.render_config.selected_checkbox.content.width() + 1;
+        }
+
+        if let Some(terminal_width) = self.frame_renderer.terminal_width() {
+            let available = (terminal_width as usize).saturating_sub(prefix_width);
+            let separator_width = config.column_separator.content.width();
+            fit_column_widths(&mut widths, separator_width, available);
+        }
+
+        self.frame_renderer.write(" ".repeat(prefix_width))?;
+
+        for (column, header) in layout.headers.iter().enumerate() {
+            if column > 0 {
+                self.frame_renderer.write_styled(config.column_separator)?;
+            }
+
+            let width = widths[column];
+
+            match sort_indicator {
+                Some((sorted, indicator))
+                    if sorted == column && width > indicator.content.width() =>
+                {
+                    let title_width = width - indicator.content.width() - 1;
+                    let title = fit_to_width(header, title_width, config.ellipsis);
+                    self.frame_renderer
+                        .write_styled(Styled::new(title).with_style_sheet(config.header))?;
+                    self.frame_renderer.write(" ")?;
+                    self.frame_renderer.write_styled(indicator)?;
+                }
+                _ => {
+                    let title = fit_to_width(header, width, config.ellipsis);
+                    self.frame_renderer
+                        .write_styled(Styled::new(title).with_style_sheet(config.header))?;
+                }
+            }
+        }
+
+        self.new_line()?;
+
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(checked) = option.value.checked {
+                self.print_checkbox(checked, idx, page.cursor)?;
+                self.frame_renderer.write(" ")?;
+            }
+
+            let stylesheet = self.option_style_sheet(idx, page.cursor);
+
+            for (column, cell) in option.value.cells.iter().enumerate() {
+                if column > 0 {
+                    self.frame_renderer.write_styled(config.column_separator)?;
+                }
+
+                let content = fit_to_width(cell, widths[column], config.ellipsis);
+                self.frame_renderer
+                    .write_styled(Styled::new(content).with_style_sheet(stylesheet))?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
@@ -237,6 +237,16 @@ where
         }
     }
 
+    /// Width of the terminal the current frame is rendered on.
+    pub fn terminal_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.terminal_size.width())
+            }
+            RenderState::Rendered(_) | RenderState::Initial => None,
+        }
+    }
+
     pub fn start_frame(&mut self) -> io::Result<()> {
         let terminal_size = self.refresh_terminal_size();
 
@@ -1,7 +1,25 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
 pub trait InputReader: Sized {
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Waits up to `timeout` for a key, returning `None` if none was pressed.
+    ///
+    /// Readers that can not wait for a limited amount of time block until
+    /// a key is pressed, see [`supports_timeout`](Self::supports_timeout).
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let _ = timeout;
+        self.read_key().map(Some)
+    }
+
+    /// Whether [`read_key_timeout`](Self::read_key_timeout) returns once the
+    /// timeout elapses, which is the case for crossterm and termion, but not
+    /// for console.
+    fn supports_timeout(&self) -> bool {
+        false
+    }
 }
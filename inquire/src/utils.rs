@@ -2,6 +2,11 @@
 
 use std::fmt::Debug;
 
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::validator::ErrorMessage;
+
 pub struct Page<'a, T> {
     /// Whether this is the first page.
     pub first: bool,
@@ -84,6 +89,117 @@ where
     len
 }
 
+/// Shrinks the widest columns, one terminal column at a time, until the
+/// columns and their separators fit in `available` terminal columns.
+///
+/// Columns are never shrunk below a width of 1.
+pub fn fit_column_widths(widths: &mut [usize], separator_width: usize, available: usize) {
+    let separators = separator_width.saturating_mul(widths.len().saturating_sub(1));
+    let mut total = widths.iter().sum::<usize>().saturating_add(separators);
+
+    while total > available {
+        let widest = widths
+            .iter_mut()
+            .max_by_key(|width| **width)
+            .filter(|width| **width > 1);
+
+        match widest {
+            Some(width) => {
+                *width -= 1;
+                total -= 1;
+            }
+            None => break,
+        }
+    }
+}
+
+/// Pads `value` with spaces to `width` terminal columns, truncating it and
+/// appending `ellipsis` if it is wider.
+pub fn fit_to_width(value: &str, width: usize, ellipsis: &str) -> String {
+    let value_width = value.width();
+
+    if value_width <= width {
+        return format!("{}{}", value, " ".repeat(width - value_width));
+    }
+
+    let ellipsis = match ellipsis.width() <= width {
+        true => ellipsis,
+        false => "",
+    };
+    let target = width - ellipsis.width();
+
+    let mut fitted = String::new();
+    let mut fitted_width = 0;
+
+    for c in value.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if fitted_width + char_width > target {
+            break;
+        }
+
+        fitted.push(c);
+        fitted_width += char_width;
+    }
+
+    fitted.push_str(ellipsis);
+    fitted_width += ellipsis.width();
+    fitted.push_str(&" ".repeat(width - fitted_width));
+
+    fitted
+}
+
+/// Splits `value` into rows of at most `width` terminal columns, without
+/// breaking graphemes apart. Empty values result in a single empty row.
+pub fn wrap_to_width(value: &str, width: usize) -> Vec<&str> {
+    let mut rows = vec![];
+    let mut row_start = 0;
+    let mut row_width = 0;
+
+    for (index, grapheme) in value.grapheme_indices(true) {
+        let grapheme_width = grapheme.width();
+
+        if row_width + grapheme_width > width && index > row_start {
+            rows.push(&value[row_start..index]);
+            row_start = index;
+            row_width = 0;
+        }
+
+        row_width += grapheme_width;
+    }
+
+    rows.push(&value[row_start..]);
+    rows
+}
+
+/// Error message displayed when less than `min` items are selected, `noun`
+/// being the singular name of an item.
+pub fn min_selections_message(min: usize, noun: &str) -> ErrorMessage {
+    ErrorMessage::Custom(format!(
+        "Please select at least {} {}{}",
+        min,
+        noun,
+        plural_suffix(min)
+    ))
+}
+
+/// Error message displayed when more than `max` items would be selected, `noun`
+/// being the singular name of an item.
+pub fn max_selections_message(max: usize, noun: &str) -> ErrorMessage {
+    ErrorMessage::Custom(format!(
+        "You can select at most {} {}{}",
+        max,
+        noun,
+        plural_suffix(max)
+    ))
+}
+
+fn plural_suffix(count: usize) -> &'static str {
+    match count {
+        1 => "",
+        _ => "s",
+    }
+}
+
 impl<'a, T> Debug for Page<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Page")
@@ -102,7 +218,11 @@ mod test {
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{
+            fit_column_widths, fit_to_width, int_log10, max_selections_message,
+            min_selections_message, paginate, wrap_to_width,
+        },
+        validator::ErrorMessage,
     };
 
     impl<T> ListOption<T> {
@@ -260,4 +380,73 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn fit_column_widths_keeps_columns_that_fit() {
+        let mut widths = vec![5, 10, 3];
+        fit_column_widths(&mut widths, 2, 22);
+
+        assert_eq!(vec![5, 10, 3], widths);
+    }
+
+    #[test]
+    fn fit_column_widths_shrinks_widest_columns_first() {
+        let mut widths = vec![5, 12, 8];
+        fit_column_widths(&mut widths, 2, 20);
+
+        assert_eq!(vec![5, 6, 5], widths);
+    }
+
+    #[test]
+    fn fit_column_widths_stops_at_one() {
+        let mut widths = vec![3, 4];
+        fit_column_widths(&mut widths, 1, 0);
+
+        assert_eq!(vec![1, 1], widths);
+    }
+
+    #[test]
+    fn fit_to_width_pads_short_values() {
+        assert_eq!("web  ", fit_to_width("web", 5, "…"));
+        assert_eq!("web", fit_to_width("web", 3, "…"));
+    }
+
+    #[test]
+    fn fit_to_width_truncates_with_ellipsis() {
+        assert_eq!("us-ea…", fit_to_width("us-east1-b", 6, "…"));
+        assert_eq!("us...", fit_to_width("us-east1-b", 5, "..."));
+        assert_eq!("u", fit_to_width("us-east1-b", 1, "..."));
+    }
+
+    #[test]
+    fn fit_to_width_uses_display_width_of_wide_characters() {
+        assert_eq!("東京", fit_to_width("東京", 4, "…"));
+        assert_eq!("東… ", fit_to_width("東京都", 4, "…"));
+        assert_eq!("東…", fit_to_width("東京都", 3, "…"));
+    }
+
+    #[test]
+    fn wrap_to_width_splits_long_values() {
+        assert_eq!(vec![""], wrap_to_width("", 4));
+        assert_eq!(vec!["abcd"], wrap_to_width("abcd", 4));
+        assert_eq!(vec!["abcd", "efgh", "i"], wrap_to_width("abcdefghi", 4));
+    }
+
+    #[test]
+    fn wrap_to_width_keeps_wide_characters_whole() {
+        assert_eq!(vec!["東京", "都"], wrap_to_width("東京都", 5));
+        assert_eq!(vec!["東", "京"], wrap_to_width("東京", 1));
+    }
+
+    #[test]
+    fn selections_messages_use_the_plural_only_when_needed() {
+        assert_eq!(
+            ErrorMessage::Custom("Please select at least 1 date".into()),
+            min_selections_message(1, "date")
+        );
+        assert_eq!(
+            ErrorMessage::Custom("You can select at most 3 options".into()),
+            max_selections_message(3, "option")
+        );
+    }
 }
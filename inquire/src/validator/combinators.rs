@@ -0,0 +1,642 @@
+//! Validators built out of other validators, such as [`AndValidator`] and [`NotValidator`].
+//!
+//! Combinators implement every validator trait implemented by the validators they wrap,
+//! so they can be used with any prompt, and can be nested to build more complex rules.
+
+use crate::{error::CustomUserError, list_option::ListOption};
+
+use super::{
+    validate_all, CustomTypeValidator, ErrorMessage, MultiOptionValidator, StringValidator,
+    Validation,
+};
+
+#[cfg(feature = "date")]
+use super::{
+    DateRangeValidator, DateTimeValidator, DateValidator, MultiDateValidator, TimeValidator,
+};
+
+/// Combinator that requires the input to pass both validators.
+///
+/// The second validator is not called when the first one considers the input invalid.
+/// Otherwise, the first warning returned by them, if any, is kept.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{
+///     AndValidator, MaxLengthValidator, StringValidator, Validation, ValueRequiredValidator,
+/// };
+///
+/// let validator = AndValidator::new(ValueRequiredValidator::default(), MaxLengthValidator::new(5));
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("A response is required.".into()), validator.validate("")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be at most 5".into()),
+///     validator.validate("Terrible")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct AndValidator<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> AndValidator<A, B> {
+    /// Create a new instance of this combinator, applying the validators in the given order.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+/// Combinator that requires the input to pass at least one of the validators.
+///
+/// The second validator is not called when the first one considers the input valid. When both
+/// consider the input invalid, the error of the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{ExactLengthValidator, OrValidator, StringValidator, Validation};
+///
+/// let validator = OrValidator::new(ExactLengthValidator::new(2), ExactLengthValidator::new(3));
+/// assert_eq!(Validation::Valid, validator.validate("BR")?);
+/// assert_eq!(Validation::Valid, validator.validate("BRA")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be 2".into()),
+///     validator.validate("Brazil")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct OrValidator<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> OrValidator<A, B> {
+    /// Create a new instance of this combinator, applying the validators in the given order.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+/// Combinator that inverts a validator, considering the input invalid when it passes the
+/// validator and valid otherwise. Warnings of the wrapped validator count as passing.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{NotValidator, OneOfValidator, StringValidator, Validation};
+///
+/// let validator = NotValidator::new(OneOfValidator::new(["root", "admin"]))
+///     .with_message("This username is reserved");
+/// assert_eq!(Validation::Valid, validator.validate("mike")?);
+/// assert_eq!(
+///     Validation::Invalid("This username is reserved".into()),
+///     validator.validate("root")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct NotValidator<V> {
+    validator: V,
+    message: String,
+}
+
+impl<V> NotValidator<V> {
+    /// Create a new instance of this combinator, inverting the given validator, with the
+    /// default error message.
+    pub fn new(validator: V) -> Self {
+        Self {
+            validator,
+            message: "The response is not valid".to_owned(),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response is not valid`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn invert(&self, validation: Validation) -> Validation {
+        match validation {
+            Validation::Invalid(_) => Validation::Valid,
+            Validation::Valid | Validation::Warning(_) => {
+                Validation::Invalid(self.message.as_str().into())
+            }
+        }
+    }
+}
+
+/// Combinator that only applies a validator when the input passes a condition, itself a
+/// validator. Inputs that do not pass the condition are considered valid.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{
+///     MinLengthValidator, StringValidator, Validation, ValueRequiredValidator, WhenValidator,
+/// };
+///
+/// // Optional answer that must have at least 3 characters when given.
+/// let validator = WhenValidator::new(ValueRequiredValidator::default(), MinLengthValidator::new(3));
+/// assert_eq!(Validation::Valid, validator.validate("")?);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be at least 3".into()),
+///     validator.validate("No")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct WhenValidator<C, V> {
+    condition: C,
+    validator: V,
+}
+
+impl<C, V> WhenValidator<C, V> {
+    /// Create a new instance of this combinator, applying the validator only to inputs that
+    /// pass the condition.
+    pub fn new(condition: C, validator: V) -> Self {
+        Self {
+            condition,
+            validator,
+        }
+    }
+}
+
+/// Combinator that replaces the message of the errors and warnings returned by a validator.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{
+///     MinLengthValidator, StringValidator, Validation, WithMessageValidator,
+/// };
+///
+/// let validator = WithMessageValidator::new(MinLengthValidator::new(8), "Too short!");
+/// assert_eq!(Validation::Valid, validator.validate("hunter22")?);
+/// assert_eq!(Validation::Invalid("Too short!".into()), validator.validate("hunter2")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct WithMessageValidator<V> {
+    validator: V,
+    message: String,
+}
+
+impl<V> WithMessageValidator<V> {
+    /// Create a new instance of this combinator, returning the given message instead of the
+    /// ones of the wrapped validator.
+    pub fn new(validator: V, message: impl Into<String>) -> Self {
+        Self {
+            validator,
+            message: message.into(),
+        }
+    }
+
+    fn replace_message(&self, validation: Validation) -> Validation {
+        let message = ErrorMessage::Custom(self.message.clone());
+
+        match validation {
+            Validation::Valid => Validation::Valid,
+            Validation::Invalid(_) => Validation::Invalid(message),
+            Validation::Warning(_) => Validation::Warning(message),
+        }
+    }
+}
+
+fn both<F>(first: Validation, second: F) -> Result<Validation, CustomUserError>
+where
+    F: FnOnce() -> Result<Validation, CustomUserError>,
+{
+    if let Validation::Invalid(_) = first {
+        return Ok(first);
+    }
+
+    validate_all(vec![Ok(first), second()])
+}
+
+fn either<F>(first: Validation, second: F) -> Result<Validation, CustomUserError>
+where
+    F: FnOnce() -> Result<Validation, CustomUserError>,
+{
+    if let Validation::Valid = first {
+        return Ok(first);
+    }
+
+    let validation = match (first, second()?) {
+        (_, Validation::Valid) => Validation::Valid,
+        (warning @ Validation::Warning(_), _) | (_, warning @ Validation::Warning(_)) => warning,
+        (invalid, Validation::Invalid(_)) => invalid,
+    };
+
+    Ok(validation)
+}
+
+/// Implements the given validator trait for all combinators, whose validators
+/// must implement it as well.
+macro_rules! impl_combinators {
+    ($(#[$meta:meta])* [$($generics:tt)*] $trait:path, ($($arg:ident: $ty:ty),+)) => {
+        $(#[$meta])*
+        impl<A, B, $($generics)*> $trait for AndValidator<A, B>
+        where
+            A: $trait + Clone,
+            B: $trait + Clone,
+        {
+            fn validate(&self, $($arg: $ty),+) -> Result<Validation, CustomUserError> {
+                let first = <A as $trait>::validate(&self.first, $($arg),+)?;
+                both(first, || <B as $trait>::validate(&self.second, $($arg),+))
+            }
+        }
+
+        $(#[$meta])*
+        impl<A, B, $($generics)*> $trait for OrValidator<A, B>
+        where
+            A: $trait + Clone,
+            B: $trait + Clone,
+        {
+            fn validate(&self, $($arg: $ty),+) -> Result<Validation, CustomUserError> {
+                let first = <A as $trait>::validate(&self.first, $($arg),+)?;
+                either(first, || <B as $trait>::validate(&self.second, $($arg),+))
+            }
+        }
+
+        $(#[$meta])*
+        impl<V, $($generics)*> $trait for NotValidator<V>
+        where
+            V: $trait + Clone,
+        {
+            fn validate(&self, $($arg: $ty),+) -> Result<Validation, CustomUserError> {
+                let validation = <V as $trait>::validate(&self.validator, $($arg),+)?;
+                Ok(self.invert(validation))
+            }
+        }
+
+        $(#[$meta])*
+        impl<C, V, $($generics)*> $trait for WhenValidator<C, V>
+        where
+            C: $trait + Clone,
+            V: $trait + Clone,
+        {
+            fn validate(&self, $($arg: $ty),+) -> Result<Validation, CustomUserError> {
+                match <C as $trait>::validate(&self.condition, $($arg),+)? {
+                    Validation::Invalid(_) => Ok(Validation::Valid),
+                    Validation::Valid | Validation::Warning(_) => {
+                        <V as $trait>::validate(&self.validator, $($arg),+)
+                    }
+                }
+            }
+        }
+
+        $(#[$meta])*
+        impl<V, $($generics)*> $trait for WithMessageValidator<V>
+        where
+            V: $trait + Clone,
+        {
+            fn validate(&self, $($arg: $ty),+) -> Result<Validation, CustomUserError> {
+                let validation = <V as $trait>::validate(&self.validator, $($arg),+)?;
+                Ok(self.replace_message(validation))
+            }
+        }
+    };
+}
+
+impl_combinators!([] StringValidator, (input: &str));
+impl_combinators!([T: ?Sized] CustomTypeValidator<T>, (input: &T));
+impl_combinators!([T: ?Sized] MultiOptionValidator<T>, (input: &[ListOption<&T>]));
+impl_combinators!(#[cfg(feature = "date")] [] DateValidator, (input: chrono::NaiveDate));
+impl_combinators!(
+    #[cfg(feature = "date")]
+    []
+    DateRangeValidator,
+    (start: chrono::NaiveDate, end: chrono::NaiveDate)
+);
+impl_combinators!(#[cfg(feature = "date")] [] MultiDateValidator, (dates: &[chrono::NaiveDate]));
+impl_combinators!(#[cfg(feature = "date")] [] TimeValidator, (input: chrono::NaiveTime));
+impl_combinators!(#[cfg(feature = "date")] [] DateTimeValidator, (input: chrono::NaiveDateTime));
+
+/// Shorthand for the [`AndValidator`] combinator, requiring the input to pass all the given
+/// validators, which are applied in order.
+///
+/// # Arguments
+///
+/// * `$validator` - Two or more validators.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_length, required, validator::{and, StringValidator, Validation}};
+///
+/// let validator = and!(required!(), max_length!(5));
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("A response is required.".into()), validator.validate("")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be at most 5".into()), validator.validate("Terrible")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __validator_and {
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::validator::AndValidator::new($first, $second)
+    };
+
+    ($first:expr, $($rest:expr),+ $(,)?) => {
+        $crate::validator::AndValidator::new($first, $crate::validator::and!($($rest),+))
+    };
+}
+
+/// Shorthand for the [`OrValidator`] combinator, requiring the input to pass at least one of
+/// the given validators, which are applied in order.
+///
+/// # Arguments
+///
+/// * `$validator` - Two or more validators.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{length, validator::{or, StringValidator, Validation}};
+///
+/// let validator = or!(length!(2), length!(3));
+/// assert_eq!(Validation::Valid, validator.validate("BR")?);
+/// assert_eq!(Validation::Valid, validator.validate("BRA")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be 2".into()), validator.validate("Brazil")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __validator_or {
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::validator::OrValidator::new($first, $second)
+    };
+
+    ($first:expr, $($rest:expr),+ $(,)?) => {
+        $crate::validator::OrValidator::new($first, $crate::validator::or!($($rest),+))
+    };
+}
+
+/// Shorthand for the [`NotValidator`] combinator, considering the input invalid when it passes
+/// the given validator.
+///
+/// # Arguments
+///
+/// * `$validator` - Validator to invert.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response is not valid"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{one_of, validator::{not, StringValidator, Validation}};
+///
+/// let validator = not!(one_of!(["root", "admin"]), "This username is reserved");
+/// assert_eq!(Validation::Valid, validator.validate("mike")?);
+/// assert_eq!(Validation::Invalid("This username is reserved".into()), validator.validate("root")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __validator_not {
+    ($validator:expr) => {
+        $crate::validator::NotValidator::new($validator)
+    };
+
+    ($validator:expr, $message:expr) => {
+        $crate::validator::not!($validator).with_message($message)
+    };
+}
+
+/// Shorthand for the [`WhenValidator`] combinator, only applying a validator to inputs that
+/// pass the given condition.
+///
+/// # Arguments
+///
+/// * `$condition` - Validator the input must pass for the other one to be applied.
+/// * `$validator` - Validator applied to the inputs that pass the condition.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_length, required, validator::{when, StringValidator, Validation}};
+///
+/// let validator = when!(required!(), min_length!(3));
+/// assert_eq!(Validation::Valid, validator.validate("")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be at least 3".into()), validator.validate("No")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __validator_when {
+    ($condition:expr, $validator:expr) => {
+        $crate::validator::WhenValidator::new($condition, $validator)
+    };
+}
+
+/// Shorthand for the [`WithMessageValidator`] combinator, replacing the message of the errors
+/// and warnings returned by the given validator.
+///
+/// # Arguments
+///
+/// * `$validator` - Validator whose messages are replaced.
+/// * `$message` - Message returned instead.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{
+///     min_length, required,
+///     validator::{and, with_message, StringValidator, Validation},
+/// };
+///
+/// let validator = with_message!(and!(required!(), min_length!(8)), "At least 8 characters");
+/// assert_eq!(Validation::Invalid("At least 8 characters".into()), validator.validate("")?);
+/// assert_eq!(Validation::Invalid("At least 8 characters".into()), validator.validate("hunter2")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __validator_with_message {
+    ($validator:expr, $message:expr) => {
+        $crate::validator::WithMessageValidator::new($validator, $message)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::CustomUserError,
+        list_option::ListOption,
+        validator::{
+            AndValidator, CustomTypeValidator, MultiOptionValidator, NotValidator, OrValidator,
+            StringValidator, Validation, WhenValidator, WithMessageValidator,
+        },
+    };
+
+    fn warn_on_empty(input: &str) -> Result<Validation, CustomUserError> {
+        Ok(match input.is_empty() {
+            true => Validation::Warning("Empty".into()),
+            false => Validation::Valid,
+        })
+    }
+
+    fn invalid(_: &str) -> Result<Validation, CustomUserError> {
+        Ok(Validation::Invalid("Invalid".into()))
+    }
+
+    fn failing(_: &str) -> Result<Validation, CustomUserError> {
+        Err("should not be called".into())
+    }
+
+    #[test]
+    fn and_stops_at_the_first_error() -> Result<(), CustomUserError> {
+        let validator = AndValidator::new(invalid, failing);
+
+        assert_eq!(
+            Validation::Invalid("Invalid".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn and_keeps_warnings_unless_an_error_follows() -> Result<(), CustomUserError> {
+        let validator = AndValidator::new(warn_on_empty, warn_on_empty);
+        assert_eq!(
+            Validation::Warning("Empty".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        let validator = AndValidator::new(warn_on_empty, invalid);
+        assert_eq!(
+            Validation::Invalid("Invalid".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn or_prefers_valid_then_warning_results() -> Result<(), CustomUserError> {
+        let validator = OrValidator::new(warn_on_empty, failing);
+        assert_eq!(
+            Validation::Valid,
+            StringValidator::validate(&validator, "a")?
+        );
+
+        let validator = OrValidator::new(invalid, warn_on_empty);
+        assert_eq!(
+            Validation::Warning("Empty".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        let validator =
+            OrValidator::new(invalid, |_: &str| Ok(Validation::Invalid("Other".into())));
+        assert_eq!(
+            Validation::Invalid("Invalid".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_counts_warnings_as_passing() -> Result<(), CustomUserError> {
+        let validator = NotValidator::new(warn_on_empty);
+
+        assert_eq!(
+            Validation::Invalid("The response is not valid".into()),
+            StringValidator::validate(&validator, "")?
+        );
+        assert_eq!(
+            Validation::Valid,
+            StringValidator::validate(&NotValidator::new(invalid), "")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn when_skips_inputs_failing_the_condition() -> Result<(), CustomUserError> {
+        let validator = WhenValidator::new(
+            |input: &u16| match *input {
+                0..=1023 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("Not well-known".into())),
+            },
+            |input: &u16| match *input {
+                80 | 443 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("Only HTTP ports".into())),
+            },
+        );
+
+        assert_eq!(Validation::Valid, validator.validate(&8080)?);
+        assert_eq!(Validation::Valid, validator.validate(&443)?);
+        assert_eq!(
+            Validation::Invalid("Only HTTP ports".into()),
+            validator.validate(&22)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_message_replaces_errors_and_warnings() -> Result<(), CustomUserError> {
+        let validator = WithMessageValidator::new(warn_on_empty, "Replaced");
+        assert_eq!(
+            Validation::Warning("Replaced".into()),
+            StringValidator::validate(&validator, "")?
+        );
+        assert_eq!(
+            Validation::Valid,
+            StringValidator::validate(&validator, "a")?
+        );
+
+        let validator = WithMessageValidator::new(invalid, "Replaced");
+        assert_eq!(
+            Validation::Invalid("Replaced".into()),
+            StringValidator::validate(&validator, "")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn combinators_wrap_multi_option_validators() -> Result<(), CustomUserError> {
+        let validator = AndValidator::new(
+            crate::validator::MinLengthValidator::new(1),
+            crate::validator::MaxLengthValidator::new(2),
+        );
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert!(matches!(validator.validate(&[])?, Validation::Invalid(_)));
+        assert_eq!(
+            Validation::Valid,
+            validator.validate(&[ListOption::new(0, "a")])?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn combinators_can_be_used_as_custom_type_validators() -> Result<(), CustomUserError> {
+        let even = |input: &i32| match input % 2 {
+            0 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Odd".into())),
+        };
+        let validator: &dyn CustomTypeValidator<i32> = &NotValidator::new(even);
+
+        assert_eq!(Validation::Valid, validator.validate(&3)?);
+        assert!(matches!(validator.validate(&4)?, Validation::Invalid(_)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2250 @@
+//! Traits and structs used by prompts to validate user input before
+//! returning the values to their callers.
+//!
+//! Validators receive the user input to a given prompt and decide whether
+//! they are valid, returning `Ok(Validation::Valid)` in the process, or
+//! invalid, returning `Ok(Validation::Invalid(ErrorMessage))`, where the
+//! `ErrorMessage` content is an error message to be displayed to the end user.
+//!
+//! Validators can also return `Ok(Validation::Warning(ErrorMessage))` for values
+//! that are acceptable but unusual, which the user accepts by submitting twice.
+//!
+//! Validators can also return errors, which propagate to the caller prompt
+//! and cause the prompt to return the error.
+//!
+//! Slow checks, such as looking a name up in a database, can run on a background
+//! thread as a [`BackgroundStringValidator`] while the prompt displays a spinner.
+//!
+//! Validators can be composed with combinators such as [`AndValidator`] and
+//! [`NotValidator`], which work with every kind of validator.
+//!
+//! This module also provides several macros as shorthands to the struct
+//! constructor functions, exported with the `macros` feature. The macros of the
+//! combinators and text formats, such as [`and!`] and [`email!`], are only exported
+//! from this module to keep their short names out of the crate root.
+
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    fs, io,
+    ops::{Bound, RangeBounds},
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
+
+use bitflags::bitflags;
+use dyn_clone::DynClone;
+
+use crate::{error::CustomUserError, list_option::ListOption};
+
+mod combinators;
+mod format;
+
+pub use self::combinators::{
+    AndValidator, NotValidator, OrValidator, WhenValidator, WithMessageValidator,
+};
+pub use self::format::{FormatValidator, TextFormat};
+
+#[cfg(feature = "macros")]
+#[doc(inline)]
+pub use crate::{
+    __validator_and as and, __validator_email as email, __validator_hostname as hostname,
+    __validator_ip_address as ip_address, __validator_ipv4_address as ipv4_address,
+    __validator_ipv6_address as ipv6_address, __validator_not as not, __validator_or as or,
+    __validator_semver as semver, __validator_url as url, __validator_when as when,
+    __validator_with_message as with_message,
+};
+
+/// Error message that is displayed to the users when their input is considered not
+/// valid by registered validators.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub enum ErrorMessage {
+    /// No custom message is defined, a standard one defined in the set
+    /// [`RenderConfig`](crate::ui::RenderConfig) is used instead.
+    #[default]
+    Default,
+
+    /// Custom error message, used instead of the standard one.
+    Custom(String),
+}
+
+impl<T> From<T> for ErrorMessage
+where
+    T: ToString,
+{
+    fn from(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+/// The result type of validation operations when the execution of the validator
+/// function succeeds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Validation {
+    /// Variant that indicates that the input value is valid according to the validator.
+    Valid,
+
+    /// Variant that indicates that the input value is invalid according to the validator.
+    ///
+    /// The member represents a custom error message that will be displayed to the user when present.
+    /// When empty a standard error message, configured via the RenderConfig struct, will be shown
+    /// instead.
+    Invalid(ErrorMessage),
+
+    /// Variant that indicates that the input value is acceptable, but deserves the attention
+    /// of the user, e.g. a port that is usually reserved.
+    ///
+    /// The first submission displays the message with the style of
+    /// [`RenderConfig::warning_message`](crate::ui::RenderConfig::warning_message) and is refused.
+    /// Submitting again, without changing the input, accepts the value. When empty, a standard
+    /// message asking for this confirmation is shown instead.
+    Warning(ErrorMessage),
+}
+
+impl Validation {
+    /// Whether a submitted value with this validation is accepted, given the warning
+    /// currently displayed to the user. Warnings are accepted once they are displayed.
+    pub(crate) fn accepts(&self, displayed_warning: Option<&ErrorMessage>) -> bool {
+        match self {
+            Self::Valid => true,
+            Self::Warning(msg) => displayed_warning == Some(msg),
+            Self::Invalid(_) => false,
+        }
+    }
+
+    /// Splits the validation into the error and the warning to display, in this order.
+    pub(crate) fn into_messages(self) -> (Option<ErrorMessage>, Option<ErrorMessage>) {
+        match self {
+            Self::Valid => (None, None),
+            Self::Invalid(msg) => (Some(msg), None),
+            Self::Warning(msg) => (None, Some(msg)),
+        }
+    }
+}
+
+/// Runs validations in order, stopping at and returning the first invalid one.
+/// Otherwise, the first warning is returned, if any.
+pub(crate) fn validate_all<I>(validations: I) -> Result<Validation, CustomUserError>
+where
+    I: IntoIterator<Item = Result<Validation, CustomUserError>>,
+{
+    let mut result = Validation::Valid;
+
+    for validation in validations {
+        match validation? {
+            Validation::Valid => {}
+            Validation::Warning(msg) => {
+                if result == Validation::Valid {
+                    result = Validation::Warning(msg);
+                }
+            }
+            invalid @ Validation::Invalid(_) => return Ok(invalid),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Options of the live validation of text prompts, in which validators run as the
+/// user types instead of only when the answer is submitted.
+///
+/// The result is displayed right away: the error message of invalid inputs, or the
+/// [valid input indicator](crate::ui::RenderConfig::valid_input_indicator) otherwise.
+/// Submitting is still refused while the input is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::validator::LiveValidation;
+///
+/// let live = LiveValidation::new()
+///     .with_debounce(Duration::from_millis(300))
+///     .with_only_after_submit(true);
+///
+/// assert_eq!(Duration::from_millis(300), live.debounce);
+/// assert!(live.only_after_submit);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiveValidation {
+    /// Time the input has to stay unchanged before validators run.
+    ///
+    /// Zero by default, validating on every change.
+    ///
+    /// Only supported by the crossterm and termion backends, except termion on macOS.
+    /// Other backends can not wait for a limited amount of time, so they validate
+    /// on every change.
+    pub debounce: Duration,
+
+    /// Whether results are only displayed after the user first tried to submit,
+    /// to avoid flagging inputs that are still being typed.
+    ///
+    /// False by default.
+    pub only_after_submit: bool,
+}
+
+impl LiveValidation {
+    /// Creates live validation options that validate on every change and
+    /// display results right away.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the time the input has to stay unchanged before validators run.
+    ///
+    /// See [`debounce`](Self::debounce) for the backends supporting it.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets whether results are only displayed after the user first tried to submit.
+    pub fn with_only_after_submit(mut self, only_after_submit: bool) -> Self {
+        self.only_after_submit = only_after_submit;
+        self
+    }
+}
+
+/// Validator that receives a string slice as the input, such as [`Text`](crate::Text) and
+/// [`Password`](crate::Password).
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{StringValidator, Validation};
+///
+/// let validator = |input: &str| match input.chars().find(|c| c.is_numeric()) {
+///     Some(_) => Ok(Validation::Valid),
+///     None => Ok(Validation::Invalid(
+///         "Your password should contain at least 1 digit".into(),
+///     )),
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate("hunter2")?);
+/// assert_eq!(
+///     Validation::Invalid("Your password should contain at least 1 digit".into()),
+///     validator.validate("password")?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait StringValidator: DynClone {
+    /// Confirm the given input string is a valid value.
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError>;
+}
+
+impl Clone for Box<dyn StringValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F> StringValidator for F
+where
+    F: Fn(&str) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator of [`Text`](crate::Text) prompts run on a background thread after the
+/// answer is submitted, for checks that can take a while, such as querying a local
+/// database or a development server.
+///
+/// While it runs, the prompt displays the [spinner](crate::ui::RenderConfig::spinner)
+/// in place of the error message and keeps handling keys: editing the input discards
+/// the pending result and the prompt can still be canceled. The result is applied as
+/// soon as it resolves, submitting the answer if it is valid.
+///
+/// Async code can be run by blocking on it in the validator, e.g. with the
+/// `block_on` method of the handle of your runtime.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{BackgroundStringValidator, Validation};
+///
+/// let validator = |input: &str| {
+///     // Stands for a slow lookup.
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+///
+///     match input {
+///         "admin" | "root" => Ok(Validation::Invalid("This name is already taken".into())),
+///         _ => Ok(Validation::Valid),
+///     }
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate("ferris")?);
+/// assert_eq!(
+///     Validation::Invalid("This name is already taken".into()),
+///     validator.validate("root")?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait BackgroundStringValidator: Send + Sync + 'static {
+    /// Confirm the given input string is a valid value.
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError>;
+}
+
+impl<F> BackgroundStringValidator for F
+where
+    F: Fn(&str) -> Result<Validation, CustomUserError> + Send + Sync + 'static,
+{
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`DateSelect`](crate::DateSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use inquire::validator::{DateValidator, Validation};
+///
+/// let validator = |input: NaiveDate| {
+///     if input.weekday() == Weekday::Sat || input.weekday() == Weekday::Sun {
+///         Ok(Validation::Invalid("Weekends are not allowed".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate(NaiveDate::from_ymd(2021, 7, 26))?);
+/// assert_eq!(
+///     Validation::Invalid("Weekends are not allowed".into()),
+///     validator.validate(NaiveDate::from_ymd(2021, 7, 25))?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait DateValidator: DynClone {
+    /// Confirm the given input date is a valid value.
+    fn validate(&self, input: chrono::NaiveDate) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn DateValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> DateValidator for F
+where
+    F: Fn(chrono::NaiveDate) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: chrono::NaiveDate) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`DateSelect`](crate::DateSelect) prompts run in range mode,
+/// receiving the start and the end of the selected range.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::validator::{DateRangeValidator, Validation};
+///
+/// let validator = |start: NaiveDate, end: NaiveDate| {
+///     if start == end {
+///         Ok(Validation::Invalid("Stays must be at least one night long".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// let check_in = NaiveDate::from_ymd_opt(2021, 7, 25).unwrap();
+/// let check_out = NaiveDate::from_ymd_opt(2021, 7, 27).unwrap();
+///
+/// assert_eq!(Validation::Valid, validator.validate(check_in, check_out)?);
+/// assert_eq!(
+///     Validation::Invalid("Stays must be at least one night long".into()),
+///     validator.validate(check_in, check_in)?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait DateRangeValidator: DynClone {
+    /// Confirm the given range, with start not greater than end, is a valid value.
+    fn validate(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn DateRangeValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> DateRangeValidator for F
+where
+    F: Fn(chrono::NaiveDate, chrono::NaiveDate) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Validation, CustomUserError> {
+        (self)(start, end)
+    }
+}
+
+/// Validator used in [`DateSelect`](crate::DateSelect) prompts run in multiple mode,
+/// receiving the selected dates in ascending order.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate};
+/// use inquire::validator::{MultiDateValidator, Validation};
+///
+/// let validator = |dates: &[NaiveDate]| {
+///     if dates.windows(2).all(|w| w[0].month() == w[1].month()) {
+///         Ok(Validation::Valid)
+///     } else {
+///         Ok(Validation::Invalid("All shifts must be in the same month".into()))
+///     }
+/// };
+///
+/// let mut shifts = vec![
+///     NaiveDate::from_ymd_opt(2021, 7, 25).unwrap(),
+///     NaiveDate::from_ymd_opt(2021, 7, 27).unwrap(),
+/// ];
+///
+/// assert_eq!(Validation::Valid, validator.validate(&shifts)?);
+///
+/// shifts.push(NaiveDate::from_ymd_opt(2021, 8, 1).unwrap());
+/// assert_eq!(
+///     Validation::Invalid("All shifts must be in the same month".into()),
+///     validator.validate(&shifts)?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait MultiDateValidator: DynClone {
+    /// Confirm the given list of dates, in ascending order, is a valid value.
+    fn validate(&self, dates: &[chrono::NaiveDate]) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn MultiDateValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> MultiDateValidator for F
+where
+    F: Fn(&[chrono::NaiveDate]) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, dates: &[chrono::NaiveDate]) -> Result<Validation, CustomUserError> {
+        (self)(dates)
+    }
+}
+
+/// Validator used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveTime, Timelike};
+/// use inquire::validator::{TimeValidator, Validation};
+///
+/// let validator = |input: NaiveTime| {
+///     if input.hour() < 9 || input.hour() >= 18 {
+///         Ok(Validation::Invalid("Meetings must be within office hours".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate(NaiveTime::from_hms_opt(14, 30, 0).unwrap())?);
+/// assert_eq!(
+///     Validation::Invalid("Meetings must be within office hours".into()),
+///     validator.validate(NaiveTime::from_hms_opt(7, 45, 0).unwrap())?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait TimeValidator: DynClone {
+    /// Confirm the given input time is a valid value.
+    fn validate(&self, input: chrono::NaiveTime) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn TimeValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> TimeValidator for F
+where
+    F: Fn(chrono::NaiveTime) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: chrono::NaiveTime) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`DateTimeSelect`](crate::DateTimeSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use inquire::validator::{DateTimeValidator, Validation};
+///
+/// let validator = |input: chrono::NaiveDateTime| {
+///     if input.weekday() == Weekday::Sun {
+///         Ok(Validation::Invalid("We are closed on Sundays".into()))
+///     } else {
+///         Ok(Validation::Valid)
+///     }
+/// };
+///
+/// let saturday = NaiveDate::from_ymd_opt(2021, 7, 24).unwrap();
+/// let sunday = NaiveDate::from_ymd_opt(2021, 7, 25).unwrap();
+///
+/// assert_eq!(Validation::Valid, validator.validate(saturday.and_hms_opt(10, 0, 0).unwrap())?);
+/// assert_eq!(
+///     Validation::Invalid("We are closed on Sundays".into()),
+///     validator.validate(sunday.and_hms_opt(10, 0, 0).unwrap())?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "date")]
+pub trait DateTimeValidator: DynClone {
+    /// Confirm the given input date and time is a valid value.
+    fn validate(&self, input: chrono::NaiveDateTime) -> Result<Validation, CustomUserError>;
+}
+
+#[cfg(feature = "date")]
+impl Clone for Box<dyn DateTimeValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<F> DateTimeValidator for F
+where
+    F: Fn(chrono::NaiveDateTime) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: chrono::NaiveDateTime) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`MultiSelect`](crate::MultiSelect) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::validator::{MultiOptionValidator, Validation};
+///
+/// let validator = |input: &[ListOption<&&str>]| {
+///     if input.len() <= 2 {
+///         Ok(Validation::Valid)
+///     } else {
+///         Ok(Validation::Invalid("You should select at most two options".into()))
+///     }
+/// };
+///
+/// let mut ans = vec![ListOption::new(0, &"a"), ListOption::new(1, &"b")];
+///
+/// assert_eq!(Validation::Valid, validator.validate(&ans[..])?);
+///
+/// ans.push(ListOption::new(3, &"d"));
+/// assert_eq!(
+///     Validation::Invalid("You should select at most two options".into()),
+///     validator.validate(&ans[..])?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait MultiOptionValidator<T: ?Sized>: DynClone {
+    /// Confirm the given input list is a valid value.
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError>;
+}
+
+impl<T> Clone for Box<dyn MultiOptionValidator<T>> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F, T> MultiOptionValidator<T> for F
+where
+    F: Fn(&[ListOption<&T>]) -> Result<Validation, CustomUserError> + Clone,
+    T: ?Sized,
+{
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Validator used in [`CustomType`](crate::CustomType) prompts.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::validator::{MultiOptionValidator, Validation};
+///
+/// let validator = |input: &[ListOption<&&str>]| {
+///     if input.len() <= 2 {
+///         Ok(Validation::Valid)
+///     } else {
+///         Ok(Validation::Invalid("You should select at most two options".into()))
+///     }
+/// };
+///
+/// let mut ans = vec![ListOption::new(0, &"a"), ListOption::new(1, &"b")];
+///
+/// assert_eq!(Validation::Valid, validator.validate(&ans[..])?);
+///
+/// ans.push(ListOption::new(3, &"d"));
+/// assert_eq!(
+///     Validation::Invalid("You should select at most two options".into()),
+///     validator.validate(&ans[..])?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait CustomTypeValidator<T: ?Sized>: DynClone {
+    /// Confirm the given input list is a valid value.
+    fn validate(&self, input: &T) -> Result<Validation, CustomUserError>;
+}
+
+impl<T> Clone for Box<dyn CustomTypeValidator<T>> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F, T> CustomTypeValidator<T> for F
+where
+    F: Fn(&T) -> Result<Validation, CustomUserError> + Clone,
+    T: ?Sized,
+{
+    fn validate(&self, input: &T) -> Result<Validation, CustomUserError> {
+        (self)(input)
+    }
+}
+
+/// Custom trait to call correct method to retrieve input length.
+///
+/// The method can vary depending on the type of input.
+///
+/// String inputs should count the number of graphemes, via
+/// `.graphemes(true).count()`, instead of the number of bytes
+/// via `.len()`. While simple slices should keep using `.len()`
+pub trait InquireLength {
+    /// String inputs should count the number of graphemes, via
+    /// `.graphemes(true).count()`, instead of the number of bytes
+    /// via `.len()`. While simple slices keep using `.len()`
+    fn inquire_length(&self) -> usize;
+}
+
+impl InquireLength for &str {
+    fn inquire_length(&self) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        self.graphemes(true).count()
+    }
+}
+
+impl<T> InquireLength for &[T] {
+    fn inquire_length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Built-in validator that checks whether the answer is not empty.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{StringValidator, Validation, ValueRequiredValidator};
+///
+/// let validator = ValueRequiredValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("Generic input")?);
+/// assert_eq!(Validation::Invalid("A response is required.".into()), validator.validate("")?);
+///
+/// let validator = ValueRequiredValidator::new("No empty!");
+/// assert_eq!(Validation::Valid, validator.validate("Generic input")?);
+/// assert_eq!(Validation::Invalid("No empty!".into()), validator.validate("")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct ValueRequiredValidator {
+    message: String,
+}
+
+impl ValueRequiredValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for ValueRequiredValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `A response is required`.
+    fn default() -> Self {
+        Self {
+            message: "A response is required.".to_owned(),
+        }
+    }
+}
+
+impl StringValidator for ValueRequiredValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.is_empty() {
+            Validation::Invalid(self.message.as_str().into())
+        } else {
+            Validation::Valid
+        })
+    }
+}
+
+/// Shorthand for the built-in [`ValueRequiredValidator`] that checks whether the answer is not
+/// empty.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "A response is required."
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{required, validator::{StringValidator, Validation}};
+///
+/// let validator = required!();
+/// assert_eq!(Validation::Valid, validator.validate("Generic input")?);
+/// assert_eq!(Validation::Invalid("A response is required.".into()), validator.validate("")?);
+///
+/// let validator = required!("No empty!");
+/// assert_eq!(Validation::Valid, validator.validate("Generic input")?);
+/// assert_eq!(Validation::Invalid("No empty!".into()), validator.validate("")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! required {
+    () => {
+        $crate::validator::ValueRequiredValidator::default()
+    };
+
+    ($message:expr) => {
+        $crate::validator::ValueRequiredValidator::new($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer length is smaller than
+/// or equal to the specified threshold.
+///
+/// The validator uses a custom-built length function that
+/// has a special implementation for strings which counts the number of
+/// graphemes. See this [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MaxLengthValidator, StringValidator, Validation};
+///
+/// let validator = MaxLengthValidator::new(5);
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be at most 5".into()),
+///     validator.validate("Terrible")?,
+/// );
+///
+/// let validator = MaxLengthValidator::new(5).with_message("Not too large!");
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("Not too large!".into()), validator.validate("Terrible")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MaxLengthValidator {
+    limit: usize,
+    message: String,
+}
+
+impl MaxLengthValidator {
+    /// Create a new instance of this validator, requiring at most the given length, otherwise
+    /// returning an error with default message.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            message: format!("The length of the response should be at most {limit}"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The length of the response should be at most $length`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn validate_inquire_length<T: InquireLength>(
+        &self,
+        input: T,
+    ) -> Result<Validation, CustomUserError> {
+        Ok(if input.inquire_length() <= self.limit {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+impl StringValidator for MaxLengthValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+impl<T: ?Sized> MultiOptionValidator<T> for MaxLengthValidator {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+/// Shorthand for the built-in [`MaxLengthValidator`] that checks whether the answer length is
+/// smaller than or equal to the specified threshold.
+///
+/// # Arguments
+///
+/// * `$length` - Maximum length of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be at most $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_length, validator::{StringValidator, Validation}};
+///
+/// let validator = max_length!(5);
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be at most 5".into()), validator.validate("Terrible")?);
+///
+/// let validator = max_length!(5, "Not too large!");
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("Not too large!".into()), validator.validate("Terrible")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! max_length {
+    ($length:expr) => {
+        $crate::validator::MaxLengthValidator::new($length)
+    };
+
+    ($length:expr, $message:expr) => {
+        $crate::max_length!($length).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer length is larger than
+/// or equal to the specified threshold.
+///
+/// The validator uses a custom-built length function that
+/// has a special implementation for strings which counts the number of
+/// graphemes. See this [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MinLengthValidator, StringValidator, Validation};
+///
+/// let validator = MinLengthValidator::new(3);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be at least 3".into()),
+///     validator.validate("No")?,
+/// );
+///
+/// let validator = MinLengthValidator::new(3).with_message("You have to give me more than that!");
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(
+///     Validation::Invalid("You have to give me more than that!".into()),
+///     validator.validate("No")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MinLengthValidator {
+    limit: usize,
+    message: String,
+}
+
+impl MinLengthValidator {
+    /// Create a new instance of this validator, requiring at least the given length, otherwise
+    /// returning an error with default message.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            message: format!("The length of the response should be at least {limit}"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The length of the response should be at least $length`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn validate_inquire_length<T: InquireLength>(
+        &self,
+        input: T,
+    ) -> Result<Validation, CustomUserError> {
+        Ok(if input.inquire_length() >= self.limit {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+impl StringValidator for MinLengthValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+impl<T: ?Sized> MultiOptionValidator<T> for MinLengthValidator {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+/// Shorthand for the built-in [`MinLengthValidator`] that checks whether the answer length is
+/// larger than or equal to the specified threshold.
+///
+/// # Arguments
+///
+/// * `$length` - Minimum length of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be at least $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_length, validator::{StringValidator, Validation}};
+///
+/// let validator = min_length!(3);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be at least 3".into()), validator.validate("No")?);
+///
+/// let validator = min_length!(3, "You have to give me more than that!");
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("You have to give me more than that!".into()), validator.validate("No")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! min_length {
+    ($length:expr) => {
+        $crate::validator::MinLengthValidator::new($length)
+    };
+
+    ($length:expr, $message:expr) => {
+        $crate::min_length!($length).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer length is equal to
+/// the specified value.
+///
+/// The validator uses a custom-built length function that
+/// has a special implementation for strings which counts the number of
+/// graphemes. See this [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{ExactLengthValidator, StringValidator, Validation};
+///
+/// let validator = ExactLengthValidator::new(3);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be 3".into()),
+///     validator.validate("No")?,
+/// );
+///
+/// let validator = ExactLengthValidator::new(3).with_message("Three characters please.");
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("Three characters please.".into()), validator.validate("No")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct ExactLengthValidator {
+    length: usize,
+    message: String,
+}
+
+impl ExactLengthValidator {
+    /// Create a new instance of this validator, requiring exactly the given length, otherwise
+    /// returning an error with default message.
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            message: format!("The length of the response should be {length}"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The length of the response should be $length`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn validate_inquire_length<T: InquireLength>(
+        &self,
+        input: T,
+    ) -> Result<Validation, CustomUserError> {
+        Ok(if input.inquire_length() == self.length {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+impl StringValidator for ExactLengthValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+impl<T: ?Sized> MultiOptionValidator<T> for ExactLengthValidator {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        self.validate_inquire_length(input)
+    }
+}
+
+/// Shorthand for the built-in [`ExactLengthValidator`] that checks whether the answer length is
+/// equal to the specified value.
+///
+/// # Arguments
+///
+/// * `$length` - Expected length of the input.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The length of the response should be $length"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{length, validator::{StringValidator, Validation}};
+///
+/// let validator = length!(3);
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("The length of the response should be 3".into()), validator.validate("No")?);
+///
+/// let validator = length!(3, "Three characters please.");
+/// assert_eq!(Validation::Valid, validator.validate("Yes")?);
+/// assert_eq!(Validation::Invalid("Three characters please.".into()), validator.validate("No")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! length {
+    ($length:expr) => {
+        $crate::validator::ExactLengthValidator::new($length)
+    };
+
+    ($length:expr, $message:expr) => {
+        $crate::length!($length).with_message($message)
+    };
+}
+
+bitflags! {
+    /// Classes of characters, used by [`CharacterClassValidator`] to require
+    /// certain kinds of characters in the input.
+    #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+    pub struct CharacterClasses: u8 {
+        /// Lowercase letters.
+        const LOWERCASE = 0b0001;
+        /// Uppercase letters.
+        const UPPERCASE = 0b0010;
+        /// Digits.
+        const DIGIT = 0b0100;
+        /// Any other character that is not whitespace, such as punctuation.
+        const SYMBOL = 0b1000;
+    }
+}
+
+impl CharacterClasses {
+    /// Classes of the characters present in the input.
+    pub fn of(input: &str) -> Self {
+        let mut classes = Self::empty();
+        for c in input.chars() {
+            classes |= Self::of_char(c);
+        }
+        classes
+    }
+
+    fn of_char(c: char) -> Self {
+        if c.is_lowercase() {
+            Self::LOWERCASE
+        } else if c.is_uppercase() {
+            Self::UPPERCASE
+        } else if c.is_numeric() {
+            Self::DIGIT
+        } else if c.is_whitespace() {
+            Self::empty()
+        } else {
+            Self::SYMBOL
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::LOWERCASE => "one lowercase letter",
+            Self::UPPERCASE => "one uppercase letter",
+            Self::DIGIT => "one digit",
+            _ => "one symbol",
+        }
+    }
+}
+
+/// Built-in validator that checks whether the answer contains at least one
+/// character of each of the required classes.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{CharacterClassValidator, CharacterClasses, StringValidator, Validation};
+///
+/// let validator = CharacterClassValidator::new(CharacterClasses::UPPERCASE | CharacterClasses::DIGIT);
+/// assert_eq!(Validation::Valid, validator.validate("Hunter2")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should contain at least one uppercase letter and one digit".into()),
+///     validator.validate("hunter")?,
+/// );
+///
+/// let validator = CharacterClassValidator::new(CharacterClasses::SYMBOL).with_message("Add a symbol!");
+/// assert_eq!(Validation::Invalid("Add a symbol!".into()), validator.validate("hunter2")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct CharacterClassValidator {
+    required: CharacterClasses,
+    message: String,
+}
+
+impl CharacterClassValidator {
+    /// Create a new instance of this validator, requiring at least one character of each of
+    /// the given classes, otherwise returning an error with default message.
+    pub fn new(required: CharacterClasses) -> Self {
+        let descriptions: Vec<&str> = required.iter().map(CharacterClasses::description).collect();
+        let list = match descriptions.split_last() {
+            Some((last, [])) => (*last).to_owned(),
+            Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+            None => String::new(),
+        };
+
+        Self {
+            required,
+            message: format!("The response should contain at least {list}"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response should contain at least $classes`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for CharacterClassValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if CharacterClasses::of(input).contains(self.required) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer contains no whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{NoWhitespaceValidator, StringValidator, Validation};
+///
+/// let validator = NoWhitespaceValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("hunter2")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should not contain whitespace".into()),
+///     validator.validate("hunter 2")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct NoWhitespaceValidator {
+    message: String,
+}
+
+impl NoWhitespaceValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for NoWhitespaceValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The response should not contain whitespace`.
+    fn default() -> Self {
+        Self {
+            message: "The response should not contain whitespace".to_owned(),
+        }
+    }
+}
+
+impl StringValidator for NoWhitespaceValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.chars().any(char::is_whitespace) {
+            Validation::Invalid(self.message.as_str().into())
+        } else {
+            Validation::Valid
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer is not one of the entries of a denylist,
+/// such as a list of common passwords.
+///
+/// Entries are compared with the whole answer, case-sensitively. The list is shared between
+/// clones of the validator.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{DenylistValidator, StringValidator, Validation};
+///
+/// let validator = DenylistValidator::new(["password", "123456"]);
+/// assert_eq!(Validation::Valid, validator.validate("hunter2")?);
+/// assert_eq!(
+///     Validation::Invalid("This response is not allowed".into()),
+///     validator.validate("password")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct DenylistValidator {
+    entries: Arc<HashSet<String>>,
+    message: String,
+}
+
+impl DenylistValidator {
+    /// Create a new instance of this validator, refusing the given entries with the default
+    /// error message.
+    pub fn new<I, S>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            entries: Arc::new(entries.into_iter().map(Into::into).collect()),
+            message: "This response is not allowed".to_owned(),
+        }
+    }
+
+    /// Create a new instance of this validator, refusing the entries of the file at the given
+    /// path, one per line. Surrounding whitespace and empty lines are ignored.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+
+        Ok(Self::new(entries))
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `This response is not allowed`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for DenylistValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.entries.contains(input) {
+            Validation::Invalid(self.message.as_str().into())
+        } else {
+            Validation::Valid
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer is within a range, such as `1..=65535`.
+///
+/// It can be used with any answer that can be compared to the bounds of the range, such as
+/// the numbers returned by `CustomType` and `Number` prompts.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{CustomTypeValidator, RangeValidator, Validation};
+///
+/// let validator = RangeValidator::new(1..=10);
+/// assert_eq!(Validation::Valid, validator.validate(&10)?);
+/// assert_eq!(
+///     Validation::Invalid("The value should be between 1 and 10".into()),
+///     validator.validate(&11)?,
+/// );
+///
+/// let validator = RangeValidator::new(0.0..1.0).with_message("Should be a ratio");
+/// assert_eq!(Validation::Valid, validator.validate(&0.5)?);
+/// assert_eq!(Validation::Invalid("Should be a ratio".into()), validator.validate(&1.0)?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct RangeValidator<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+    message: String,
+}
+
+impl<T> RangeValidator<T>
+where
+    T: Clone + Display,
+{
+    /// Create a new instance of this validator, requiring the answer to be within the given
+    /// range, otherwise returning an error with default message.
+    pub fn new<R: RangeBounds<T>>(range: R) -> Self {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+
+        let message = if let (Bound::Included(min), Bound::Included(max)) = (&start, &end) {
+            format!("The value should be between {min} and {max}")
+        } else {
+            let lower = match &start {
+                Bound::Included(min) => Some(format!("at least {min}")),
+                Bound::Excluded(min) => Some(format!("greater than {min}")),
+                Bound::Unbounded => None,
+            };
+            let upper = match &end {
+                Bound::Included(max) => Some(format!("at most {max}")),
+                Bound::Excluded(max) => Some(format!("less than {max}")),
+                Bound::Unbounded => None,
+            };
+            let bounds: Vec<String> = lower.into_iter().chain(upper).collect();
+
+            format!("The value should be {}", bounds.join(" and "))
+        };
+
+        Self {
+            start,
+            end,
+            message,
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The value should be between $min and $max`, or a description of the
+    /// bounds of the range, e.g. `The value should be at least $min`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl<T> CustomTypeValidator<T> for RangeValidator<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn validate(&self, input: &T) -> Result<Validation, CustomUserError> {
+        Ok(
+            if (self.start.as_ref(), self.end.as_ref()).contains(input) {
+                Validation::Valid
+            } else {
+                Validation::Invalid(self.message.as_str().into())
+            },
+        )
+    }
+}
+
+/// Shorthand for the built-in [`RangeValidator`] that checks whether the answer is within a
+/// range.
+///
+/// # Arguments
+///
+/// * `$range` - Range of valid answers, such as `1..=10` or `..100`.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The value should be between $min and $max", or a description of the
+///   bounds of the range.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{in_range, validator::{CustomTypeValidator, Validation}};
+///
+/// let validator = in_range!(1..=10);
+/// assert_eq!(Validation::Valid, validator.validate(&1)?);
+/// assert_eq!(Validation::Invalid("The value should be between 1 and 10".into()), validator.validate(&0)?);
+///
+/// let validator = in_range!(..100, "Too much!");
+/// assert_eq!(Validation::Valid, validator.validate(&99)?);
+/// assert_eq!(Validation::Invalid("Too much!".into()), validator.validate(&100)?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! in_range {
+    ($range:expr) => {
+        $crate::validator::RangeValidator::new($range)
+    };
+
+    ($range:expr, $message:expr) => {
+        $crate::in_range!($range).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer is one of the allowed entries.
+///
+/// Entries are compared with the whole answer, case-sensitively. The list is shared between
+/// clones of the validator.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{OneOfValidator, StringValidator, Validation};
+///
+/// let validator = OneOfValidator::new(["dev", "staging", "prod"]);
+/// assert_eq!(Validation::Valid, validator.validate("prod")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should be one of: dev, staging, prod".into()),
+///     validator.validate("production")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct OneOfValidator {
+    entries: Arc<Vec<String>>,
+    message: String,
+}
+
+impl OneOfValidator {
+    /// Create a new instance of this validator, allowing only the given entries, otherwise
+    /// returning an error with default message listing them.
+    pub fn new<I, S>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let entries: Vec<String> = entries.into_iter().map(Into::into).collect();
+        let message = format!("The response should be one of: {}", entries.join(", "));
+
+        Self {
+            entries: Arc::new(entries),
+            message,
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response should be one of: $entries`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for OneOfValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.entries.iter().any(|entry| entry == input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`OneOfValidator`] that checks whether the answer is one of the
+/// allowed entries.
+///
+/// # Arguments
+///
+/// * `$entries` - Allowed entries, such as an array of strings.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response should be one of: $entries"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{one_of, validator::{StringValidator, Validation}};
+///
+/// let validator = one_of!(["dev", "prod"]);
+/// assert_eq!(Validation::Valid, validator.validate("dev")?);
+/// assert_eq!(Validation::Invalid("The response should be one of: dev, prod".into()), validator.validate("qa")?);
+///
+/// let validator = one_of!(["dev", "prod"], "Unknown environment");
+/// assert_eq!(Validation::Invalid("Unknown environment".into()), validator.validate("qa")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! one_of {
+    ($entries:expr) => {
+        $crate::validator::OneOfValidator::new($entries)
+    };
+
+    ($entries:expr, $message:expr) => {
+        $crate::one_of!($entries).with_message($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer is the path of an existing file.
+///
+/// Relative paths are resolved from the current working directory, and symbolic links are
+/// followed.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{FileExistsValidator, StringValidator, Validation};
+///
+/// let validator = FileExistsValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("Cargo.toml")?);
+/// assert_eq!(
+///     Validation::Invalid("The file does not exist".into()),
+///     validator.validate("src")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct FileExistsValidator {
+    message: String,
+}
+
+impl FileExistsValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for FileExistsValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The file does not exist`.
+    fn default() -> Self {
+        Self {
+            message: "The file does not exist".to_owned(),
+        }
+    }
+}
+
+impl StringValidator for FileExistsValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if Path::new(input).is_file() {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`FileExistsValidator`] that checks whether the answer is the
+/// path of an existing file.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The file does not exist"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{file_exists, validator::{StringValidator, Validation}};
+///
+/// let validator = file_exists!();
+/// assert_eq!(Validation::Valid, validator.validate("Cargo.toml")?);
+///
+/// let validator = file_exists!("No such file!");
+/// assert_eq!(Validation::Invalid("No such file!".into()), validator.validate("missing.toml")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! file_exists {
+    () => {
+        $crate::validator::FileExistsValidator::default()
+    };
+
+    ($message:expr) => {
+        $crate::validator::FileExistsValidator::new($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer is the path of an existing directory.
+///
+/// Relative paths are resolved from the current working directory, and symbolic links are
+/// followed.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{DirectoryExistsValidator, StringValidator, Validation};
+///
+/// let validator = DirectoryExistsValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("src")?);
+/// assert_eq!(
+///     Validation::Invalid("The directory does not exist".into()),
+///     validator.validate("Cargo.toml")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct DirectoryExistsValidator {
+    message: String,
+}
+
+impl DirectoryExistsValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for DirectoryExistsValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The directory does not exist`.
+    fn default() -> Self {
+        Self {
+            message: "The directory does not exist".to_owned(),
+        }
+    }
+}
+
+impl StringValidator for DirectoryExistsValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if Path::new(input).is_dir() {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`DirectoryExistsValidator`] that checks whether the answer is
+/// the path of an existing directory.
+///
+/// # Arguments
+///
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The directory does not exist"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{dir_exists, validator::{StringValidator, Validation}};
+///
+/// let validator = dir_exists!();
+/// assert_eq!(Validation::Valid, validator.validate("src")?);
+///
+/// let validator = dir_exists!("No such directory!");
+/// assert_eq!(Validation::Invalid("No such directory!".into()), validator.validate("missing")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! dir_exists {
+    () => {
+        $crate::validator::DirectoryExistsValidator::default()
+    };
+
+    ($message:expr) => {
+        $crate::validator::DirectoryExistsValidator::new($message)
+    };
+}
+
+/// Built-in validator that checks whether the answer matches a regular expression.
+///
+/// The expression can match any part of the answer, anchor it with `^` and `$` to match
+/// the whole answer.
+///
+/// This validator is gated via the `regex` feature.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{RegexValidator, StringValidator, Validation};
+///
+/// let validator = RegexValidator::from_pattern(r"^[a-z][a-z0-9-]*$")?;
+/// assert_eq!(Validation::Valid, validator.validate("my-app2")?);
+/// assert_eq!(
+///     Validation::Invalid("The response does not match the expected format".into()),
+///     validator.validate("My App")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Clone)]
+pub struct RegexValidator {
+    regex: regex::Regex,
+    message: String,
+}
+
+#[cfg(feature = "regex")]
+impl RegexValidator {
+    /// Create a new instance of this validator, requiring the answer to match the given
+    /// expression, otherwise returning an error with default message.
+    pub fn new(regex: regex::Regex) -> Self {
+        Self {
+            regex,
+            message: "The response does not match the expected format".to_owned(),
+        }
+    }
+
+    /// Create a new instance of this validator from the given pattern, failing when the
+    /// pattern is not a valid regular expression.
+    pub fn from_pattern(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self::new)
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response does not match the expected format`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+#[cfg(feature = "regex")]
+impl StringValidator for RegexValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.regex.is_match(input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`RegexValidator`] that checks whether the answer matches a
+/// regular expression.
+///
+/// # Arguments
+///
+/// * `$pattern` - Regular expression the answer should match.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response does not match the expected format"
+///
+/// Returns a `Result` with the validator, failing when the pattern is not a valid
+/// regular expression, like [`RegexValidator::from_pattern`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{regex, validator::{StringValidator, Validation}};
+///
+/// let validator = regex!(r"^\d{5}$")?;
+/// assert_eq!(Validation::Valid, validator.validate("12345")?);
+/// assert_eq!(Validation::Invalid("The response does not match the expected format".into()), validator.validate("1234")?);
+///
+/// let validator = regex!(r"^\d{5}$", "Expected a 5-digit ZIP code")?;
+/// assert_eq!(Validation::Invalid("Expected a 5-digit ZIP code".into()), validator.validate("1234")?);
+///
+/// assert!(regex!(r"^\d{5").is_err());
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(all(feature = "macros", feature = "regex"))]
+macro_rules! regex {
+    ($pattern:expr) => {
+        $crate::validator::RegexValidator::from_pattern($pattern)
+    };
+
+    ($pattern:expr, $message:expr) => {
+        $crate::regex!($pattern).map(|validator| validator.with_message($message))
+    };
+}
+
+#[cfg(test)]
+mod validators_test {
+    use crate::{
+        error::CustomUserError,
+        list_option::ListOption,
+        validator::{
+            CharacterClassValidator, CharacterClasses, CustomTypeValidator, DenylistValidator,
+            DirectoryExistsValidator, ExactLengthValidator, FileExistsValidator,
+            MaxLengthValidator, MinLengthValidator, MultiOptionValidator, NoWhitespaceValidator,
+            OneOfValidator, RangeValidator, StringValidator, Validation,
+        },
+    };
+
+    fn build_option_vec(len: usize) -> Vec<ListOption<&'static str>> {
+        let mut options = Vec::new();
+
+        for i in 0..len {
+            options.push(ListOption::new(i, ""));
+        }
+
+        options
+    }
+
+    #[test]
+    fn string_length_counts_graphemes() -> Result<(), CustomUserError> {
+        let validator = ExactLengthValidator::new(5);
+        let validator: &dyn StringValidator = &validator;
+
+        assert!(matches!(validator.validate("five!")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Valid
+        ));
+
+        assert!(matches!(
+            validator.validate("five!!!")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_length() -> Result<(), CustomUserError> {
+        let validator = ExactLengthValidator::new(5);
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert!(matches!(
+            validator.validate(&build_option_vec(5))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(4))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(6))?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn string_max_length_counts_graphemes() -> Result<(), CustomUserError> {
+        let validator = MaxLengthValidator::new(5);
+        let validator: &dyn StringValidator = &validator;
+
+        assert!(matches!(validator.validate("")?, Validation::Valid));
+        assert!(matches!(validator.validate("five!")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Valid
+        ));
+
+        assert!(matches!(
+            validator.validate("five!!!")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️♥️")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_max_length() -> Result<(), CustomUserError> {
+        let validator = MaxLengthValidator::new(5);
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert!(matches!(
+            validator.validate(&build_option_vec(0))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(1))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(2))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(3))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(4))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(5))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(6))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(7))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(8))?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn string_min_length_counts_graphemes() -> Result<(), CustomUserError> {
+        let validator = MinLengthValidator::new(5);
+        let validator: &dyn StringValidator = &validator;
+
+        assert!(matches!(validator.validate("")?, Validation::Invalid(_)));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("mike")?,
+            Validation::Invalid(_)
+        ));
+
+        assert!(matches!(validator.validate("five!")?, Validation::Valid));
+        assert!(matches!(validator.validate("five!!!")?, Validation::Valid));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("♥️♥️♥️♥️♥️♥️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate("🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️🤦🏼‍♂️")?,
+            Validation::Valid
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_min_length() -> Result<(), CustomUserError> {
+        let validator = MinLengthValidator::new(5);
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert!(matches!(
+            validator.validate(&build_option_vec(0))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(1))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(2))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(3))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(4))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(5))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(6))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(7))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(8))?,
+            Validation::Valid
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn character_classes_are_detected() {
+        assert_eq!(CharacterClasses::empty(), CharacterClasses::of(" "));
+        assert_eq!(
+            CharacterClasses::LOWERCASE | CharacterClasses::DIGIT,
+            CharacterClasses::of("hunter2")
+        );
+        assert_eq!(
+            CharacterClasses::UPPERCASE | CharacterClasses::SYMBOL,
+            CharacterClasses::of("ÉÀ-!")
+        );
+    }
+
+    #[test]
+    fn character_class_message_lists_required_classes() -> Result<(), CustomUserError> {
+        let validator = CharacterClassValidator::new(CharacterClasses::all());
+
+        assert_eq!(
+            Validation::Invalid(
+                "The response should contain at least one lowercase letter, one uppercase letter, one digit and one symbol"
+                    .into()
+            ),
+            validator.validate("password")?
+        );
+        assert_eq!(Validation::Valid, validator.validate("Pa55word!")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_whitespace() -> Result<(), CustomUserError> {
+        let validator = NoWhitespaceValidator::new("No spaces");
+
+        assert_eq!(Validation::Valid, validator.validate("hunter2")?);
+        assert_eq!(
+            Validation::Invalid("No spaces".into()),
+            validator.validate("hunter\t2")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn denylist_is_read_from_file() -> Result<(), CustomUserError> {
+        let path = std::env::temp_dir().join(format!("inquire-denylist-{}", std::process::id()));
+        std::fs::write(&path, "password\n\n  123456  \r\nqwerty\n")?;
+
+        let validator = DenylistValidator::from_file(&path)?.with_message("Too common");
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(
+            Validation::Invalid("Too common".into()),
+            validator.validate("123456")?
+        );
+        assert_eq!(
+            Validation::Invalid("Too common".into()),
+            validator.validate("qwerty")?
+        );
+        assert_eq!(Validation::Valid, validator.validate("Password")?);
+        assert_eq!(Validation::Valid, validator.validate("")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn range_message_describes_the_bounds() -> Result<(), CustomUserError> {
+        let cases = [
+            (
+                RangeValidator::new(1..),
+                0,
+                "The value should be at least 1",
+            ),
+            (
+                RangeValidator::new(..=5),
+                6,
+                "The value should be at most 5",
+            ),
+            (
+                RangeValidator::new(1..10),
+                10,
+                "The value should be at least 1 and less than 10",
+            ),
+        ];
+
+        for (validator, invalid, message) in cases {
+            assert_eq!(
+                Validation::Invalid(message.into()),
+                validator.validate(&invalid)?
+            );
+            assert_eq!(Validation::Valid, validator.validate(&3)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn range_excludes_its_end() -> Result<(), CustomUserError> {
+        let validator = RangeValidator::new(1..10);
+
+        assert_eq!(Validation::Valid, validator.validate(&1)?);
+        assert_eq!(Validation::Valid, validator.validate(&9)?);
+        assert!(matches!(validator.validate(&10)?, Validation::Invalid(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_of_is_case_sensitive() -> Result<(), CustomUserError> {
+        let validator = OneOfValidator::new(vec![String::from("yes"), String::from("no")]);
+
+        assert_eq!(Validation::Valid, validator.validate("yes")?);
+        assert_eq!(
+            Validation::Invalid("The response should be one of: yes, no".into()),
+            validator.validate("Yes")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_validators_check_the_kind_of_entry() -> Result<(), CustomUserError> {
+        let dir = std::env::temp_dir().join(format!("inquire-paths-{}", std::process::id()));
+        let file = dir.join("file.txt");
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(&file, "")?;
+
+        let dir = dir.to_str().unwrap();
+        let file = file.to_str().unwrap();
+
+        let file_exists = FileExistsValidator::default();
+        let dir_exists = DirectoryExistsValidator::new("No such directory");
+        let results = (
+            file_exists.validate(file)?,
+            file_exists.validate(dir)?,
+            dir_exists.validate(dir)?,
+            dir_exists.validate(file)?,
+        );
+        std::fs::remove_dir_all(dir)?;
+
+        assert_eq!(Validation::Valid, results.0);
+        assert_eq!(
+            Validation::Invalid("The file does not exist".into()),
+            results.1
+        );
+        assert_eq!(Validation::Valid, results.2);
+        assert_eq!(Validation::Invalid("No such directory".into()), results.3);
+        assert!(matches!(
+            file_exists.validate(file)?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_matches_any_part_unless_anchored() -> Result<(), CustomUserError> {
+        use crate::validator::RegexValidator;
+
+        let validator = RegexValidator::from_pattern(r"\d+")?;
+        assert_eq!(Validation::Valid, validator.validate("abc123")?);
+
+        let validator = RegexValidator::from_pattern(r"^\d+$")?.with_message("Digits only");
+        assert_eq!(
+            Validation::Invalid("Digits only".into()),
+            validator.validate("abc123")?
+        );
+
+        assert!(RegexValidator::from_pattern("(").is_err());
+
+        Ok(())
+    }
+}